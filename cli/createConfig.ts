@@ -30,6 +30,10 @@ export function createConfig(program: Command) {
         ],
         clmmProgram.programId
       );
+      const [globalConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("global_config")], // GLOBAL_CONFIG_SEED
+        clmmProgram.programId
+      );
       const tx = await clmmProgram.methods.createAmmConfig(
         index,
         tickSpacing,
@@ -38,6 +42,7 @@ export function createConfig(program: Command) {
         fundFeeRate,
       ).accountsStrict({
         ammConfig,
+        globalConfig,
         systemProgram: SYSTEM_PROGRAM_ID,
         owner: clmmProgram.provider.publicKey,
       }).rpc();
@@ -14,12 +14,19 @@ export function createPool(program: Command) {
     .option("--token-program-a <string>", "Token Program", parsePublicKey)
     .option("--token-program-b <string>", "Token Program", parsePublicKey)
     .option("-c, --clmm-config <string>", "AMM/CLMM Config", parsePublicKey)
+    .option("--launch-fee-start-rate <number>", "Launch Fee Start Rate", Number, 0)
+    .option("--launch-fee-end-rate <number>", "Launch Fee End Rate", Number, 0)
+    .option("--launch-fee-duration <number>", "Launch Fee Duration in seconds", Number, 0)
     .description("create a pool with the given tokens")
     .action(async({
-      priceSqrt, openTime, tokenMintA, tokenMintB, tokenProgramA, tokenProgramB, ammConfig
+      priceSqrt, openTime, tokenMintA, tokenMintB, tokenProgramA, tokenProgramB, ammConfig,
+      launchFeeStartRate, launchFeeEndRate, launchFeeDuration
     }: {
       priceSqrt: BN,
       openTime: BN,
+      launchFeeStartRate: number,
+      launchFeeEndRate: number,
+      launchFeeDuration: number,
       tokenMintA: PublicKey,
       tokenMintB: PublicKey,
       tokenProgramA?: PublicKey,
@@ -27,17 +34,26 @@ export function createPool(program: Command) {
       ammConfig?: PublicKey
     }) => {
       const devnetAmmConfig = new PublicKey("5waGmTN1wazZMqTw1FBRabW2DoK6XRkHC1vqAQWJF8wd");
-      const tx = await clmmProgram.methods.createPool(
-        priceSqrt,
-        openTime,
-      ).accounts({
+      const accounts = {
         poolCreator: localKeypair.publicKey,
         ammConfig: ammConfig || devnetAmmConfig,
         tokenMint0: tokenMintA,
         tokenMint1: tokenMintB,
         tokenProgram0: tokenProgramA || TOKEN_PROGRAM_ID,
         tokenProgram1: tokenProgramB || TOKEN_PROGRAM_ID,
-      }).rpc();
+      };
+      const tx = launchFeeDuration > 0
+        ? await clmmProgram.methods.createPoolWithLaunchFee(
+          priceSqrt,
+          openTime,
+          launchFeeStartRate,
+          launchFeeEndRate,
+          launchFeeDuration,
+        ).accounts(accounts).rpc()
+        : await clmmProgram.methods.createPool(
+          priceSqrt,
+          openTime,
+        ).accounts(accounts).rpc();
       console.log(tx);
     });
 }
\ No newline at end of file
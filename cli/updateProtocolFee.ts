@@ -20,11 +20,16 @@ export function updateProtocolFee(program: Command) {
         ],
         clmmProgram.programId
       );
+      const [globalConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("global_config")], // GLOBAL_CONFIG_SEED
+        clmmProgram.programId
+      );
       const tx = await clmmProgram.methods.updateAmmConfig(
         1,
         protocolFeeRate,
       ).accountsStrict({
         ammConfig,
+        globalConfig,
         owner: clmmProgram.provider.publicKey,
       }).rpc();
       console.log(`Update Protocol Fees in tx: ${tx}`);
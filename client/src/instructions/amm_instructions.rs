@@ -2,6 +2,7 @@ use anchor_client::{Client, Cluster};
 use anchor_lang::prelude::AccountMeta;
 use anyhow::Result;
 use mpl_token_metadata::accounts::Metadata;
+use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     instruction::Instruction, pubkey::Pubkey, signature::Signer, system_program, sysvar,
 };
@@ -9,12 +10,14 @@ use solana_sdk::{
 use stabbleorg_clmm::accounts as raydium_accounts;
 use stabbleorg_clmm::instruction as raydium_instruction;
 use stabbleorg_clmm::states::{
-    AMM_CONFIG_SEED, OBSERVATION_SEED, OPERATION_SEED, POOL_SEED, POOL_VAULT_SEED, POSITION_SEED,
-    TICK_ARRAY_SEED,
+    AMM_CONFIG_PENDING_OWNER_SEED, AMM_CONFIG_PENDING_UPDATE_SEED, AMM_CONFIG_SEED,
+    GLOBAL_CONFIG_SEED, OBSERVATION_SEED, OPERATION_SEED, POOL_SEED, POOL_VAULT_SEED,
+    POSITION_SEED, TICK_ARRAY_SEED,
 };
 use std::rc::Rc;
 
 use super::super::{read_keypair_file, ClientConfig};
+use super::utils::append_transfer_hook_accounts;
 
 pub fn create_amm_config_instr(
     config: &ClientConfig,
@@ -33,10 +36,13 @@ pub fn create_amm_config_instr(
         &[AMM_CONFIG_SEED.as_bytes(), &config_index.to_be_bytes()],
         &program.id(),
     );
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::CreateAmmConfig {
             owner: program.payer(),
+            global_config: global_config_key,
             amm_config: amm_config_key,
             system_program: system_program::id(),
         })
@@ -54,7 +60,6 @@ pub fn create_amm_config_instr(
 pub fn update_amm_config_instr(
     config: &ClientConfig,
     amm_config: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
     param: u8,
     value: u32,
 ) -> Result<Vec<Instruction>> {
@@ -64,18 +69,266 @@ pub fn update_amm_config_instr(
     // Client.
     let client = Client::new(url, Rc::new(payer));
     let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::UpdateAmmConfig {
             owner: admin.pubkey(),
+            global_config: global_config_key,
             amm_config,
         })
-        .accounts(remaining_accounts)
         .args(raydium_instruction::UpdateAmmConfig { param, value })
         .instructions()?;
     Ok(instructions)
 }
 
+pub fn set_config_update_delay_instr(
+    config: &ClientConfig,
+    delay: u64,
+) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::SetConfigUpdateDelay {
+            admin: program.payer(),
+            global_config: global_config_key,
+        })
+        .args(raydium_instruction::SetConfigUpdateDelay { delay })
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn schedule_amm_config_update_instr(
+    config: &ClientConfig,
+    amm_config: Pubkey,
+    param: u8,
+    value: u32,
+) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let (amm_config_pending_update_key, __bump) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.as_ref(),
+            &[param],
+        ],
+        &program.id(),
+    );
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::ScheduleAmmConfigUpdate {
+            admin: program.payer(),
+            global_config: global_config_key,
+            amm_config,
+            amm_config_pending_update: amm_config_pending_update_key,
+            system_program: system_program::id(),
+        })
+        .args(raydium_instruction::ScheduleAmmConfigUpdate { param, value })
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn execute_amm_config_update_instr(
+    config: &ClientConfig,
+    amm_config: Pubkey,
+    param: u8,
+) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let (amm_config_pending_update_key, __bump) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.as_ref(),
+            &[param],
+        ],
+        &program.id(),
+    );
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::ExecuteAmmConfigUpdate {
+            admin: program.payer(),
+            global_config: global_config_key,
+            amm_config,
+            amm_config_pending_update: amm_config_pending_update_key,
+        })
+        .args(raydium_instruction::ExecuteAmmConfigUpdate)
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn cancel_amm_config_update_instr(
+    config: &ClientConfig,
+    amm_config: Pubkey,
+    param: u8,
+) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let (amm_config_pending_update_key, __bump) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.as_ref(),
+            &[param],
+        ],
+        &program.id(),
+    );
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::CancelAmmConfigUpdate {
+            admin: program.payer(),
+            global_config: global_config_key,
+            amm_config,
+            amm_config_pending_update: amm_config_pending_update_key,
+        })
+        .args(raydium_instruction::CancelAmmConfigUpdate)
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn propose_amm_config_owner_instr(
+    config: &ClientConfig,
+    amm_config: Pubkey,
+    new_owner: Option<Pubkey>,
+    new_fund_owner: Option<Pubkey>,
+) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let (amm_config_pending_owner_key, __bump) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_PENDING_OWNER_SEED.as_bytes(),
+            amm_config.as_ref(),
+        ],
+        &program.id(),
+    );
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::ProposeAmmConfigOwner {
+            admin: program.payer(),
+            global_config: global_config_key,
+            amm_config,
+            amm_config_pending_owner: amm_config_pending_owner_key,
+            system_program: system_program::id(),
+        })
+        .args(raydium_instruction::ProposeAmmConfigOwner {
+            new_owner,
+            new_fund_owner,
+        })
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn accept_amm_config_owner_instr(
+    config: &ClientConfig,
+    amm_config: Pubkey,
+) -> Result<Vec<Instruction>> {
+    let payer = read_keypair_file(&config.payer_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(payer));
+    let program = client.program(config.raydium_v3_program)?;
+    let (amm_config_pending_owner_key, __bump) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_PENDING_OWNER_SEED.as_bytes(),
+            amm_config.as_ref(),
+        ],
+        &program.id(),
+    );
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::AcceptAmmConfigOwner {
+            pending_owner: program.payer(),
+            amm_config,
+            amm_config_pending_owner: amm_config_pending_owner_key,
+        })
+        .args(raydium_instruction::AcceptAmmConfigOwner)
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn create_global_config_instr(config: &ClientConfig) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::CreateGlobalConfig {
+            owner: program.payer(),
+            global_config: global_config_key,
+            system_program: system_program::id(),
+        })
+        .args(raydium_instruction::CreateGlobalConfig)
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn propose_admin_instr(config: &ClientConfig, new_admin: Pubkey) -> Result<Vec<Instruction>> {
+    let admin = read_keypair_file(&config.admin_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(admin));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::ProposeAdmin {
+            admin: program.payer(),
+            global_config: global_config_key,
+        })
+        .args(raydium_instruction::ProposeAdmin { new_admin })
+        .instructions()?;
+    Ok(instructions)
+}
+
+pub fn accept_admin_instr(config: &ClientConfig) -> Result<Vec<Instruction>> {
+    let payer = read_keypair_file(&config.payer_path)?;
+    let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
+    // Client.
+    let client = Client::new(url, Rc::new(payer));
+    let program = client.program(config.raydium_v3_program)?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
+    let instructions = program
+        .request()
+        .accounts(raydium_accounts::AcceptAdmin {
+            pending_admin: program.payer(),
+            global_config: global_config_key,
+        })
+        .args(raydium_instruction::AcceptAdmin)
+        .instructions()?;
+    Ok(instructions)
+}
+
 pub fn create_operation_account_instr(config: &ClientConfig) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.admin_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
@@ -84,10 +337,13 @@ pub fn create_operation_account_instr(config: &ClientConfig) -> Result<Vec<Instr
     let program = client.program(config.raydium_v3_program)?;
     let (operation_account_key, __bump) =
         Pubkey::find_program_address(&[OPERATION_SEED.as_bytes()], &program.id());
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::CreateOperationAccount {
             owner: program.payer(),
+            global_config: global_config_key,
             operation_state: operation_account_key,
             system_program: system_program::id(),
         })
@@ -108,10 +364,13 @@ pub fn update_operation_account_instr(
     let program = client.program(config.raydium_v3_program)?;
     let (operation_account_key, __bump) =
         Pubkey::find_program_address(&[OPERATION_SEED.as_bytes()], &program.id());
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::UpdateOperationAccount {
             owner: program.payer(),
+            global_config: global_config_key,
             operation_state: operation_account_key,
             system_program: system_program::id(),
         })
@@ -130,6 +389,9 @@ pub fn create_pool_instr(
     tick_array_bitmap: Pubkey,
     sqrt_price_x64: u128,
     open_time: u64,
+    launch_fee_start_rate: u32,
+    launch_fee_end_rate: u32,
+    launch_fee_duration: u32,
 ) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.payer_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
@@ -168,28 +430,37 @@ pub fn create_pool_instr(
         ],
         &program.id(),
     );
-    let instructions = program
-        .request()
-        .accounts(raydium_accounts::CreatePool {
-            pool_creator: program.payer(),
-            amm_config,
-            pool_state: pool_account_key,
-            token_mint_0,
-            token_mint_1,
-            token_vault_0,
-            token_vault_1,
-            observation_state: observation_key,
-            tick_array_bitmap,
-            token_program_0,
-            token_program_1,
-            system_program: system_program::id(),
-            rent: sysvar::rent::id(),
+    let request = program.request().accounts(raydium_accounts::CreatePool {
+        pool_creator: program.payer(),
+        amm_config,
+        pool_state: pool_account_key,
+        token_mint_0,
+        token_mint_1,
+        token_vault_0,
+        token_vault_1,
+        observation_state: observation_key,
+        tick_array_bitmap,
+        token_program_0,
+        token_program_1,
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    });
+    // the launch fee is only taken by its own instruction, create_pool keeps its arguments
+    let instructions = if launch_fee_duration == 0 {
+        request.args(raydium_instruction::CreatePool {
+            sqrt_price_x64,
+            open_time,
         })
-        .args(raydium_instruction::CreatePool {
+    } else {
+        request.args(raydium_instruction::CreatePoolWithLaunchFee {
             sqrt_price_x64,
             open_time,
+            launch_fee_start_rate,
+            launch_fee_end_rate,
+            launch_fee_duration,
         })
-        .instructions()?;
+    }
+    .instructions()?;
     Ok(instructions)
 }
 
@@ -204,7 +475,7 @@ pub fn open_position_instr(
     nft_to_owner: Pubkey,
     user_token_account_0: Pubkey,
     user_token_account_1: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
+    mut remaining_accounts: Vec<AccountMeta>,
     liquidity: u128,
     amount_0_max: u64,
     amount_1_max: u64,
@@ -258,6 +529,25 @@ pub fn open_position_instr(
         &[POSITION_SEED.as_bytes(), nft_mint_key.to_bytes().as_ref()],
         &program.id(),
     );
+    let rpc_client = RpcClient::new(config.http_url.to_string());
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_0,
+        user_token_account_0,
+        token_vault_0,
+        program.payer(),
+        amount_0_max,
+    )?;
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_1,
+        user_token_account_1,
+        token_vault_1,
+        program.payer(),
+        amount_1_max,
+    )?;
     let instructions = program
         .request()
         .accounts(raydium_accounts::OpenPositionV2 {
@@ -311,7 +601,7 @@ pub fn open_position_with_token22_nft_instr(
     nft_to_owner: Pubkey,
     user_token_account_0: Pubkey,
     user_token_account_1: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
+    mut remaining_accounts: Vec<AccountMeta>,
     liquidity: u128,
     amount_0_max: u64,
     amount_1_max: u64,
@@ -320,6 +610,9 @@ pub fn open_position_with_token22_nft_instr(
     tick_array_lower_start_index: i32,
     tick_array_upper_start_index: i32,
     with_metadata: bool,
+    deadline: Option<u64>,
+    sqrt_price_min_x64: Option<u128>,
+    sqrt_price_max_x64: Option<u128>,
 ) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.payer_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
@@ -361,7 +654,26 @@ pub fn open_position_with_token22_nft_instr(
         &[POSITION_SEED.as_bytes(), nft_mint_key.to_bytes().as_ref()],
         &program.id(),
     );
-    let instructions = program
+    let rpc_client = RpcClient::new(config.http_url.to_string());
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_0,
+        user_token_account_0,
+        token_vault_0,
+        program.payer(),
+        amount_0_max,
+    )?;
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_1,
+        user_token_account_1,
+        token_vault_1,
+        program.payer(),
+        amount_1_max,
+    )?;
+    let request = program
         .request()
         .accounts(raydium_accounts::OpenPositionWithToken22Nft {
             payer: program.payer(),
@@ -385,19 +697,37 @@ pub fn open_position_with_token22_nft_instr(
             vault_0_mint: token_mint_0,
             vault_1_mint: token_mint_1,
         })
-        .accounts(remaining_accounts)
-        .args(raydium_instruction::OpenPositionWithToken22Nft {
-            liquidity,
-            amount_0_max,
-            amount_1_max,
-            tick_lower_index,
-            tick_upper_index,
-            tick_array_lower_start_index,
-            tick_array_upper_start_index,
-            with_metadata,
-            base_flag: None,
-        })
-        .instructions()?;
+        .accounts(remaining_accounts);
+    let request =
+        if deadline.is_some() || sqrt_price_min_x64.is_some() || sqrt_price_max_x64.is_some() {
+            request.args(raydium_instruction::OpenPositionWithToken22NftWithOptions {
+                liquidity,
+                amount_0_max,
+                amount_1_max,
+                tick_lower_index,
+                tick_upper_index,
+                tick_array_lower_start_index,
+                tick_array_upper_start_index,
+                with_metadata,
+                base_flag: None,
+                sqrt_price_min_x64,
+                sqrt_price_max_x64,
+                deadline,
+            })
+        } else {
+            request.args(raydium_instruction::OpenPositionWithToken22Nft {
+                liquidity,
+                amount_0_max,
+                amount_1_max,
+                tick_lower_index,
+                tick_upper_index,
+                tick_array_lower_start_index,
+                tick_array_upper_start_index,
+                with_metadata,
+                base_flag: None,
+            })
+        };
+    let instructions = request.instructions()?;
     Ok(instructions)
 }
 
@@ -412,7 +742,7 @@ pub fn increase_liquidity_instr(
     nft_token_key: Pubkey,
     user_token_account_0: Pubkey,
     user_token_account_1: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
+    mut remaining_accounts: Vec<AccountMeta>,
     liquidity: u128,
     amount_0_max: u64,
     amount_1_max: u64,
@@ -420,6 +750,9 @@ pub fn increase_liquidity_instr(
     tick_upper_index: i32,
     tick_array_lower_start_index: i32,
     tick_array_upper_start_index: i32,
+    deadline: Option<u64>,
+    sqrt_price_min_x64: Option<u128>,
+    sqrt_price_max_x64: Option<u128>,
 ) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.payer_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
@@ -456,7 +789,26 @@ pub fn increase_liquidity_instr(
         &program.id(),
     );
 
-    let instructions = program
+    let rpc_client = RpcClient::new(config.http_url.to_string());
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_0,
+        user_token_account_0,
+        token_vault_0,
+        program.payer(),
+        amount_0_max,
+    )?;
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_1,
+        user_token_account_1,
+        token_vault_1,
+        program.payer(),
+        amount_1_max,
+    )?;
+    let request = program
         .request()
         .accounts(raydium_accounts::IncreaseLiquidityV2 {
             nft_owner: program.payer(),
@@ -475,14 +827,27 @@ pub fn increase_liquidity_instr(
             vault_0_mint: token_mint_0,
             vault_1_mint: token_mint_1,
         })
-        .accounts(remaining_accounts)
-        .args(raydium_instruction::IncreaseLiquidityV2 {
-            liquidity,
-            amount_0_max,
-            amount_1_max,
-            base_flag: None,
-        })
-        .instructions()?;
+        .accounts(remaining_accounts);
+    let request =
+        if deadline.is_some() || sqrt_price_min_x64.is_some() || sqrt_price_max_x64.is_some() {
+            request.args(raydium_instruction::IncreaseLiquidityV2WithOptions {
+                liquidity,
+                amount_0_max,
+                amount_1_max,
+                base_flag: None,
+                sqrt_price_min_x64,
+                sqrt_price_max_x64,
+                deadline,
+            })
+        } else {
+            request.args(raydium_instruction::IncreaseLiquidityV2 {
+                liquidity,
+                amount_0_max,
+                amount_1_max,
+                base_flag: None,
+            })
+        };
+    let instructions = request.instructions()?;
     Ok(instructions)
 }
 
@@ -497,7 +862,7 @@ pub fn decrease_liquidity_instr(
     nft_token_key: Pubkey,
     user_token_account_0: Pubkey,
     user_token_account_1: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
+    mut remaining_accounts: Vec<AccountMeta>,
     liquidity: u128,
     amount_0_min: u64,
     amount_1_min: u64,
@@ -505,6 +870,9 @@ pub fn decrease_liquidity_instr(
     tick_upper_index: i32,
     tick_array_lower_start_index: i32,
     tick_array_upper_start_index: i32,
+    deadline: Option<u64>,
+    sqrt_price_min_x64: Option<u128>,
+    sqrt_price_max_x64: Option<u128>,
 ) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.payer_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
@@ -540,7 +908,26 @@ pub fn decrease_liquidity_instr(
         ],
         &program.id(),
     );
-    let instructions = program
+    let rpc_client = RpcClient::new(config.http_url.to_string());
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_0,
+        token_vault_0,
+        user_token_account_0,
+        pool_account_key,
+        amount_0_min,
+    )?;
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        token_mint_1,
+        token_vault_1,
+        user_token_account_1,
+        pool_account_key,
+        amount_1_min,
+    )?;
+    let request = program
         .request()
         .accounts(raydium_accounts::DecreaseLiquidityV2 {
             nft_owner: program.payer(),
@@ -560,13 +947,25 @@ pub fn decrease_liquidity_instr(
             vault_0_mint: token_mint_0,
             vault_1_mint: token_mint_1,
         })
-        .accounts(remaining_accounts)
-        .args(raydium_instruction::DecreaseLiquidityV2 {
-            liquidity,
-            amount_0_min,
-            amount_1_min,
-        })
-        .instructions()?;
+        .accounts(remaining_accounts);
+    let request =
+        if deadline.is_some() || sqrt_price_min_x64.is_some() || sqrt_price_max_x64.is_some() {
+            request.args(raydium_instruction::DecreaseLiquidityV2WithOptions {
+                liquidity,
+                amount_0_min,
+                amount_1_min,
+                sqrt_price_min_x64,
+                sqrt_price_max_x64,
+                deadline,
+            })
+        } else {
+            request.args(raydium_instruction::DecreaseLiquidityV2 {
+                liquidity,
+                amount_0_min,
+                amount_1_min,
+            })
+        };
+    let instructions = request.instructions()?;
     Ok(instructions)
 }
 
@@ -657,42 +1056,82 @@ pub fn swap_v2_instr(
     user_out_put_token: Pubkey,
     input_vault_mint: Pubkey,
     output_vault_mint: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
+    mut remaining_accounts: Vec<AccountMeta>,
     amount: u64,
     other_amount_threshold: u64,
     sqrt_price_limit_x64: Option<u128>,
     is_base_input: bool,
+    referrer_token_account: Option<Pubkey>,
+    referral_fee_rate: u32,
+    deadline: Option<u64>,
 ) -> Result<Vec<Instruction>> {
     let payer = read_keypair_file(&config.payer_path)?;
     let url = Cluster::Custom(config.http_url.clone(), config.ws_url.clone());
     // Client.
     let client = Client::new(url, Rc::new(payer));
     let program = client.program(config.raydium_v3_program)?;
-    let instructions = program
-        .request()
-        .accounts(raydium_accounts::SwapSingleV2 {
-            payer: program.payer(),
-            amm_config,
-            pool_state: pool_account_key,
-            input_token_account: user_input_token,
-            output_token_account: user_out_put_token,
-            input_vault,
-            output_vault,
-            observation_state,
-            token_program: spl_token::id(),
-            token_program_2022: spl_token_2022::id(),
-            memo_program: spl_memo::id(),
-            input_vault_mint,
-            output_vault_mint,
-        })
-        .accounts(remaining_accounts)
-        .args(raydium_instruction::SwapV2 {
-            amount,
-            other_amount_threshold,
-            sqrt_price_limit_x64: sqrt_price_limit_x64.unwrap_or(0u128),
-            is_base_input,
-        })
-        .instructions()?;
+    let rpc_client = RpcClient::new(config.http_url.to_string());
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        input_vault_mint,
+        user_input_token,
+        input_vault,
+        program.payer(),
+        amount,
+    )?;
+    append_transfer_hook_accounts(
+        &rpc_client,
+        &mut remaining_accounts,
+        output_vault_mint,
+        output_vault,
+        user_out_put_token,
+        pool_account_key,
+        amount,
+    )?;
+    let swap_accounts = raydium_accounts::SwapSingleV2 {
+        payer: program.payer(),
+        amm_config,
+        pool_state: pool_account_key,
+        input_token_account: user_input_token,
+        output_token_account: user_out_put_token,
+        input_vault,
+        output_vault,
+        observation_state,
+        token_program: spl_token::id(),
+        token_program_2022: spl_token_2022::id(),
+        memo_program: spl_memo::id(),
+        input_vault_mint,
+        output_vault_mint,
+    };
+    let request = program.request();
+    let sqrt_price_limit_x64 = sqrt_price_limit_x64.unwrap_or(0u128);
+    let request = match (referrer_token_account, deadline) {
+        (None, None) => request
+            .accounts(swap_accounts)
+            .accounts(remaining_accounts)
+            .args(raydium_instruction::SwapV2 {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit_x64,
+                is_base_input,
+            }),
+        (referrer_token_account, deadline) => request
+            .accounts(raydium_accounts::SwapSingleV2WithOptions {
+                swap: swap_accounts,
+                referrer_token_account,
+            })
+            .accounts(remaining_accounts)
+            .args(raydium_instruction::SwapV2WithOptions {
+                amount,
+                other_amount_threshold,
+                sqrt_price_limit_x64,
+                is_base_input,
+                referral_fee_rate: referrer_token_account.map(|_| referral_fee_rate),
+                deadline,
+            }),
+    };
+    let instructions = request.instructions()?;
     Ok(instructions)
 }
 
@@ -715,6 +1154,20 @@ pub fn initialize_reward_instr(
     let client = Client::new(url, Rc::new(admin));
     let program = client.program(config.raydium_v3_program)?;
 
+    let reward_amount =
+        ((emissions_per_second_x64 * u128::from(end_time - open_time)) >> 64) as u64;
+    let mut remaining_accounts = Vec::new();
+    append_transfer_hook_accounts(
+        &RpcClient::new(config.http_url.to_string()),
+        &mut remaining_accounts,
+        reward_token_mint,
+        user_reward_token,
+        reward_token_vault,
+        program.payer(),
+        reward_amount,
+    )?;
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::InitializeReward {
@@ -723,12 +1176,14 @@ pub fn initialize_reward_instr(
             amm_config,
             pool_state: pool_account_key,
             operation_state: operation_account_key,
+            global_config: global_config_key,
             reward_token_mint,
             reward_token_vault,
             reward_token_program,
             system_program: system_program::id(),
             rent: sysvar::rent::id(),
         })
+        .accounts(remaining_accounts)
         .args(raydium_instruction::InitializeReward {
             param: stabbleorg_clmm::instructions::InitializeRewardParam {
                 open_time,
@@ -764,6 +1219,8 @@ pub fn set_reward_params_instr(
         AccountMeta::new_readonly(spl_token::id(), false),
     ];
 
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::SetRewardParams {
@@ -771,6 +1228,7 @@ pub fn set_reward_params_instr(
             amm_config,
             pool_state: pool_account_key,
             operation_state: operation_account_key,
+            global_config: global_config_key,
             token_program: spl_token::id(),
             token_program_2022: spl_token_2022::id(),
         })
@@ -798,6 +1256,8 @@ pub fn transfer_reward_owner(
     let client = Client::new(url, Rc::new(admin));
     let program = client.program(config.raydium_v3_program)?;
 
+    let (global_config_key, __bump) =
+        Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED.as_bytes()], &program.id());
     let instructions = program
         .request()
         .accounts(raydium_accounts::TransferRewardOwner {
@@ -806,6 +1266,7 @@ pub fn transfer_reward_owner(
             } else {
                 program.payer()
             },
+            global_config: global_config_key,
             pool_state: pool_account_key,
         })
         .args(raydium_instruction::TransferRewardOwner { new_owner })
@@ -143,12 +143,51 @@ pub fn handle_program_log(
             ConfigChangeEvent::DISCRIMINATOR => {
                 println!("{:#?}", decode_event::<ConfigChangeEvent>(&mut slice)?);
             }
+            AdminChangeEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<AdminChangeEvent>(&mut slice)?);
+            }
+            PoolConfigChangeEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<PoolConfigChangeEvent>(&mut slice)?);
+            }
+            PoolStatusChangeEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<PoolStatusChangeEvent>(&mut slice)?);
+            }
+            TwapGuardChangeEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<TwapGuardChangeEvent>(&mut slice)?);
+            }
+            MigratePoolConfigEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<MigratePoolConfigEvent>(&mut slice)?);
+            }
+            PoolConfigPendingUpdateEvent::DISCRIMINATOR => {
+                println!(
+                    "{:#?}",
+                    decode_event::<PoolConfigPendingUpdateEvent>(&mut slice)?
+                );
+            }
+            ConfigUpdateDelayChangeEvent::DISCRIMINATOR => {
+                println!(
+                    "{:#?}",
+                    decode_event::<ConfigUpdateDelayChangeEvent>(&mut slice)?
+                );
+            }
             CollectPersonalFeeEvent::DISCRIMINATOR => {
                 println!(
                     "{:#?}",
                     decode_event::<CollectPersonalFeeEvent>(&mut slice)?
                 );
             }
+            CompoundPositionEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<CompoundPositionEvent>(&mut slice)?);
+            }
+            PlaceRangeOrderEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<PlaceRangeOrderEvent>(&mut slice)?);
+            }
+            ClaimRangeOrderEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<ClaimRangeOrderEvent>(&mut slice)?);
+            }
+            LockPositionEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<LockPositionEvent>(&mut slice)?);
+            }
             CollectProtocolFeeEvent::DISCRIMINATOR => {
                 println!(
                     "{:#?}",
@@ -176,6 +215,9 @@ pub fn handle_program_log(
             LiquidityChangeEvent::DISCRIMINATOR => {
                 println!("{:#?}", decode_event::<LiquidityChangeEvent>(&mut slice)?);
             }
+            FlashLoanEvent::DISCRIMINATOR => {
+                println!("{:#?}", decode_event::<FlashLoanEvent>(&mut slice)?);
+            }
             // PriceChangeEvent::DISCRIMINATOR => {
             //     println!("{:#?}", decode_event::<PriceChangeEvent>(&mut slice)?);
             // }
@@ -392,6 +434,127 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", UpdateAmmConfig::from(ix));
         }
+        instruction::SetConfigUpdateDelay::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SetConfigUpdateDelay>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SetConfigUpdateDelay {
+                pub delay: u64,
+            }
+            impl From<instruction::SetConfigUpdateDelay> for SetConfigUpdateDelay {
+                fn from(instr: instruction::SetConfigUpdateDelay) -> SetConfigUpdateDelay {
+                    SetConfigUpdateDelay { delay: instr.delay }
+                }
+            }
+            println!("{:#?}", SetConfigUpdateDelay::from(ix));
+        }
+        instruction::ScheduleAmmConfigUpdate::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::ScheduleAmmConfigUpdate>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct ScheduleAmmConfigUpdate {
+                pub param: u8,
+                pub value: u32,
+            }
+            impl From<instruction::ScheduleAmmConfigUpdate> for ScheduleAmmConfigUpdate {
+                fn from(instr: instruction::ScheduleAmmConfigUpdate) -> ScheduleAmmConfigUpdate {
+                    ScheduleAmmConfigUpdate {
+                        param: instr.param,
+                        value: instr.value,
+                    }
+                }
+            }
+            println!("{:#?}", ScheduleAmmConfigUpdate::from(ix));
+        }
+        instruction::ExecuteAmmConfigUpdate::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::ExecuteAmmConfigUpdate>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct ExecuteAmmConfigUpdate;
+            impl From<instruction::ExecuteAmmConfigUpdate> for ExecuteAmmConfigUpdate {
+                fn from(_instr: instruction::ExecuteAmmConfigUpdate) -> ExecuteAmmConfigUpdate {
+                    ExecuteAmmConfigUpdate
+                }
+            }
+            println!("{:#?}", ExecuteAmmConfigUpdate::from(ix));
+        }
+        instruction::CancelAmmConfigUpdate::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::CancelAmmConfigUpdate>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CancelAmmConfigUpdate;
+            impl From<instruction::CancelAmmConfigUpdate> for CancelAmmConfigUpdate {
+                fn from(_instr: instruction::CancelAmmConfigUpdate) -> CancelAmmConfigUpdate {
+                    CancelAmmConfigUpdate
+                }
+            }
+            println!("{:#?}", CancelAmmConfigUpdate::from(ix));
+        }
+        instruction::ProposeAmmConfigOwner::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::ProposeAmmConfigOwner>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct ProposeAmmConfigOwner {
+                pub new_owner: Option<Pubkey>,
+                pub new_fund_owner: Option<Pubkey>,
+            }
+            impl From<instruction::ProposeAmmConfigOwner> for ProposeAmmConfigOwner {
+                fn from(instr: instruction::ProposeAmmConfigOwner) -> ProposeAmmConfigOwner {
+                    ProposeAmmConfigOwner {
+                        new_owner: instr.new_owner,
+                        new_fund_owner: instr.new_fund_owner,
+                    }
+                }
+            }
+            println!("{:#?}", ProposeAmmConfigOwner::from(ix));
+        }
+        instruction::AcceptAmmConfigOwner::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::AcceptAmmConfigOwner>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct AcceptAmmConfigOwner;
+            impl From<instruction::AcceptAmmConfigOwner> for AcceptAmmConfigOwner {
+                fn from(_instr: instruction::AcceptAmmConfigOwner) -> AcceptAmmConfigOwner {
+                    AcceptAmmConfigOwner
+                }
+            }
+            println!("{:#?}", AcceptAmmConfigOwner::from(ix));
+        }
+        instruction::CreateGlobalConfig::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::CreateGlobalConfig>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CreateGlobalConfig;
+            impl From<instruction::CreateGlobalConfig> for CreateGlobalConfig {
+                fn from(_instr: instruction::CreateGlobalConfig) -> CreateGlobalConfig {
+                    CreateGlobalConfig
+                }
+            }
+            println!("{:#?}", CreateGlobalConfig::from(ix));
+        }
+        instruction::ProposeAdmin::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::ProposeAdmin>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct ProposeAdmin {
+                pub new_admin: Pubkey,
+            }
+            impl From<instruction::ProposeAdmin> for ProposeAdmin {
+                fn from(instr: instruction::ProposeAdmin) -> ProposeAdmin {
+                    ProposeAdmin {
+                        new_admin: instr.new_admin,
+                    }
+                }
+            }
+            println!("{:#?}", ProposeAdmin::from(ix));
+        }
+        instruction::AcceptAdmin::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::AcceptAdmin>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct AcceptAdmin;
+            impl From<instruction::AcceptAdmin> for AcceptAdmin {
+                fn from(_instr: instruction::AcceptAdmin) -> AcceptAdmin {
+                    AcceptAdmin
+                }
+            }
+            println!("{:#?}", AcceptAdmin::from(ix));
+        }
         instruction::CreatePool::DISCRIMINATOR => {
             let ix = decode_instruction::<instruction::CreatePool>(&mut ix_data).unwrap();
             #[derive(Debug)]
@@ -409,6 +572,30 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", CreatePool::from(ix));
         }
+        instruction::CreatePoolWithLaunchFee::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::CreatePoolWithLaunchFee>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CreatePoolWithLaunchFee {
+                pub sqrt_price_x64: u128,
+                pub open_time: u64,
+                pub launch_fee_start_rate: u32,
+                pub launch_fee_end_rate: u32,
+                pub launch_fee_duration: u32,
+            }
+            impl From<instruction::CreatePoolWithLaunchFee> for CreatePoolWithLaunchFee {
+                fn from(instr: instruction::CreatePoolWithLaunchFee) -> CreatePoolWithLaunchFee {
+                    CreatePoolWithLaunchFee {
+                        sqrt_price_x64: instr.sqrt_price_x64,
+                        open_time: instr.open_time,
+                        launch_fee_start_rate: instr.launch_fee_start_rate,
+                        launch_fee_end_rate: instr.launch_fee_end_rate,
+                        launch_fee_duration: instr.launch_fee_duration,
+                    }
+                }
+            }
+            println!("{:#?}", CreatePoolWithLaunchFee::from(ix));
+        }
         instruction::UpdatePoolStatus::DISCRIMINATOR => {
             let ix = decode_instruction::<instruction::UpdatePoolStatus>(&mut ix_data).unwrap();
             #[derive(Debug)]
@@ -424,6 +611,216 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", UpdatePoolStatus::from(ix));
         }
+        instruction::UpdateAmmConfigStatus::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::UpdateAmmConfigStatus>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct UpdateAmmConfigStatus {
+                pub status: u8,
+            }
+            impl From<instruction::UpdateAmmConfigStatus> for UpdateAmmConfigStatus {
+                fn from(instr: instruction::UpdateAmmConfigStatus) -> UpdateAmmConfigStatus {
+                    UpdateAmmConfigStatus {
+                        status: instr.status,
+                    }
+                }
+            }
+            println!("{:#?}", UpdateAmmConfigStatus::from(ix));
+        }
+        instruction::SetPauseGuardian::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SetPauseGuardian>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SetPauseGuardian {
+                pub pause_guardian: Pubkey,
+            }
+            impl From<instruction::SetPauseGuardian> for SetPauseGuardian {
+                fn from(instr: instruction::SetPauseGuardian) -> SetPauseGuardian {
+                    SetPauseGuardian {
+                        pause_guardian: instr.pause_guardian,
+                    }
+                }
+            }
+            println!("{:#?}", SetPauseGuardian::from(ix));
+        }
+        instruction::PausePool::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::PausePool>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct PausePool {
+                pub status: u8,
+            }
+            impl From<instruction::PausePool> for PausePool {
+                fn from(instr: instruction::PausePool) -> PausePool {
+                    PausePool {
+                        status: instr.status,
+                    }
+                }
+            }
+            println!("{:#?}", PausePool::from(ix));
+        }
+        instruction::PauseAmmConfig::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::PauseAmmConfig>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct PauseAmmConfig {
+                pub status: u8,
+            }
+            impl From<instruction::PauseAmmConfig> for PauseAmmConfig {
+                fn from(instr: instruction::PauseAmmConfig) -> PauseAmmConfig {
+                    PauseAmmConfig {
+                        status: instr.status,
+                    }
+                }
+            }
+            println!("{:#?}", PauseAmmConfig::from(ix));
+        }
+        instruction::SetPoolFeeOverrides::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SetPoolFeeOverrides>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SetPoolFeeOverrides {
+                pub trade_fee_rate: Option<u32>,
+                pub protocol_fee_rate: Option<u32>,
+                pub fund_fee_rate: Option<u32>,
+            }
+            impl From<instruction::SetPoolFeeOverrides> for SetPoolFeeOverrides {
+                fn from(instr: instruction::SetPoolFeeOverrides) -> SetPoolFeeOverrides {
+                    SetPoolFeeOverrides {
+                        trade_fee_rate: instr.trade_fee_rate,
+                        protocol_fee_rate: instr.protocol_fee_rate,
+                        fund_fee_rate: instr.fund_fee_rate,
+                    }
+                }
+            }
+            println!("{:#?}", SetPoolFeeOverrides::from(ix));
+        }
+        instruction::SetTwapGuard::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SetTwapGuard>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SetTwapGuard {
+                pub window: u32,
+                pub max_tick_deviation: u32,
+                pub mode: u8,
+            }
+            impl From<instruction::SetTwapGuard> for SetTwapGuard {
+                fn from(instr: instruction::SetTwapGuard) -> SetTwapGuard {
+                    SetTwapGuard {
+                        window: instr.window,
+                        max_tick_deviation: instr.max_tick_deviation,
+                        mode: instr.mode,
+                    }
+                }
+            }
+            println!("{:#?}", SetTwapGuard::from(ix));
+        }
+        instruction::MigratePoolConfig::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::MigratePoolConfig>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct MigratePoolConfig;
+            impl From<instruction::MigratePoolConfig> for MigratePoolConfig {
+                fn from(_instr: instruction::MigratePoolConfig) -> MigratePoolConfig {
+                    MigratePoolConfig
+                }
+            }
+            println!("{:#?}", MigratePoolConfig::from(ix));
+        }
+        instruction::SchedulePoolFeeOverrides::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::SchedulePoolFeeOverrides>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SchedulePoolFeeOverrides {
+                pub trade_fee_rate: Option<u32>,
+                pub protocol_fee_rate: Option<u32>,
+                pub fund_fee_rate: Option<u32>,
+            }
+            impl From<instruction::SchedulePoolFeeOverrides> for SchedulePoolFeeOverrides {
+                fn from(instr: instruction::SchedulePoolFeeOverrides) -> SchedulePoolFeeOverrides {
+                    SchedulePoolFeeOverrides {
+                        trade_fee_rate: instr.trade_fee_rate,
+                        protocol_fee_rate: instr.protocol_fee_rate,
+                        fund_fee_rate: instr.fund_fee_rate,
+                    }
+                }
+            }
+            println!("{:#?}", SchedulePoolFeeOverrides::from(ix));
+        }
+        instruction::SchedulePoolConfigMigration::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SchedulePoolConfigMigration>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct SchedulePoolConfigMigration;
+            impl From<instruction::SchedulePoolConfigMigration> for SchedulePoolConfigMigration {
+                fn from(
+                    _instr: instruction::SchedulePoolConfigMigration,
+                ) -> SchedulePoolConfigMigration {
+                    SchedulePoolConfigMigration
+                }
+            }
+            println!("{:#?}", SchedulePoolConfigMigration::from(ix));
+        }
+        instruction::CancelPoolConfigUpdate::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::CancelPoolConfigUpdate>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CancelPoolConfigUpdate;
+            impl From<instruction::CancelPoolConfigUpdate> for CancelPoolConfigUpdate {
+                fn from(_instr: instruction::CancelPoolConfigUpdate) -> CancelPoolConfigUpdate {
+                    CancelPoolConfigUpdate
+                }
+            }
+            println!("{:#?}", CancelPoolConfigUpdate::from(ix));
+        }
+        instruction::CreateSupportMintAssociated::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::CreateSupportMintAssociated>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct CreateSupportMintAssociated {
+                pub allowed_extensions: u64,
+                pub vault_immutable_owner: bool,
+            }
+            impl From<instruction::CreateSupportMintAssociated> for CreateSupportMintAssociated {
+                fn from(
+                    instr: instruction::CreateSupportMintAssociated,
+                ) -> CreateSupportMintAssociated {
+                    CreateSupportMintAssociated {
+                        allowed_extensions: instr.allowed_extensions,
+                        vault_immutable_owner: instr.vault_immutable_owner,
+                    }
+                }
+            }
+            println!("{:#?}", CreateSupportMintAssociated::from(ix));
+        }
+        instruction::UpdateSupportMintAssociated::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::UpdateSupportMintAssociated>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct UpdateSupportMintAssociated {
+                pub allowed_extensions: u64,
+                pub vault_immutable_owner: bool,
+            }
+            impl From<instruction::UpdateSupportMintAssociated> for UpdateSupportMintAssociated {
+                fn from(
+                    instr: instruction::UpdateSupportMintAssociated,
+                ) -> UpdateSupportMintAssociated {
+                    UpdateSupportMintAssociated {
+                        allowed_extensions: instr.allowed_extensions,
+                        vault_immutable_owner: instr.vault_immutable_owner,
+                    }
+                }
+            }
+            println!("{:#?}", UpdateSupportMintAssociated::from(ix));
+        }
+        instruction::MigrateSupportMintAssociated::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::MigrateSupportMintAssociated>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct MigrateSupportMintAssociated;
+            impl From<instruction::MigrateSupportMintAssociated> for MigrateSupportMintAssociated {
+                fn from(
+                    _instr: instruction::MigrateSupportMintAssociated,
+                ) -> MigrateSupportMintAssociated {
+                    MigrateSupportMintAssociated
+                }
+            }
+            println!("{:#?}", MigrateSupportMintAssociated::from(ix));
+        }
         instruction::CreateOperationAccount::DISCRIMINATOR => {
             let ix =
                 decode_instruction::<instruction::CreateOperationAccount>(&mut ix_data).unwrap();
@@ -596,6 +993,37 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", IncreaseLiquidityV2::from(ix));
         }
+        instruction::IncreaseLiquidityV2WithOptions::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::IncreaseLiquidityV2WithOptions>(&mut ix_data)
+                    .unwrap();
+            #[derive(Debug)]
+            pub struct IncreaseLiquidityV2WithOptions {
+                pub liquidity: u128,
+                pub amount_0_max: u64,
+                pub amount_1_max: u64,
+                pub base_flag: Option<bool>,
+                pub sqrt_price_min_x64: Option<u128>,
+                pub sqrt_price_max_x64: Option<u128>,
+                pub deadline: Option<u64>,
+            }
+            impl From<instruction::IncreaseLiquidityV2WithOptions> for IncreaseLiquidityV2WithOptions {
+                fn from(
+                    instr: instruction::IncreaseLiquidityV2WithOptions,
+                ) -> IncreaseLiquidityV2WithOptions {
+                    IncreaseLiquidityV2WithOptions {
+                        liquidity: instr.liquidity,
+                        amount_0_max: instr.amount_0_max,
+                        amount_1_max: instr.amount_1_max,
+                        base_flag: instr.base_flag,
+                        sqrt_price_min_x64: instr.sqrt_price_min_x64,
+                        sqrt_price_max_x64: instr.sqrt_price_max_x64,
+                        deadline: instr.deadline,
+                    }
+                }
+            }
+            println!("{:#?}", IncreaseLiquidityV2WithOptions::from(ix));
+        }
         instruction::DecreaseLiquidityV2::DISCRIMINATOR => {
             let ix = decode_instruction::<instruction::DecreaseLiquidityV2>(&mut ix_data).unwrap();
             #[derive(Debug)]
@@ -615,6 +1043,129 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", DecreaseLiquidityV2::from(ix));
         }
+        instruction::DecreaseLiquidityV2WithOptions::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::DecreaseLiquidityV2WithOptions>(&mut ix_data)
+                    .unwrap();
+            #[derive(Debug)]
+            pub struct DecreaseLiquidityV2WithOptions {
+                pub liquidity: u128,
+                pub amount_0_min: u64,
+                pub amount_1_min: u64,
+                pub sqrt_price_min_x64: Option<u128>,
+                pub sqrt_price_max_x64: Option<u128>,
+                pub deadline: Option<u64>,
+            }
+            impl From<instruction::DecreaseLiquidityV2WithOptions> for DecreaseLiquidityV2WithOptions {
+                fn from(
+                    instr: instruction::DecreaseLiquidityV2WithOptions,
+                ) -> DecreaseLiquidityV2WithOptions {
+                    DecreaseLiquidityV2WithOptions {
+                        liquidity: instr.liquidity,
+                        amount_0_min: instr.amount_0_min,
+                        amount_1_min: instr.amount_1_min,
+                        sqrt_price_min_x64: instr.sqrt_price_min_x64,
+                        sqrt_price_max_x64: instr.sqrt_price_max_x64,
+                        deadline: instr.deadline,
+                    }
+                }
+            }
+            println!("{:#?}", DecreaseLiquidityV2WithOptions::from(ix));
+        }
+        instruction::CollectFeesAndRewards::DISCRIMINATOR => {
+            let ix =
+                decode_instruction::<instruction::CollectFeesAndRewards>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CollectFeesAndRewards;
+            impl From<instruction::CollectFeesAndRewards> for CollectFeesAndRewards {
+                fn from(_instr: instruction::CollectFeesAndRewards) -> CollectFeesAndRewards {
+                    CollectFeesAndRewards
+                }
+            }
+            println!("{:#?}", CollectFeesAndRewards::from(ix));
+        }
+        instruction::CompoundPosition::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::CompoundPosition>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct CompoundPosition {
+                pub sqrt_price_min_x64: Option<u128>,
+                pub sqrt_price_max_x64: Option<u128>,
+            }
+            impl From<instruction::CompoundPosition> for CompoundPosition {
+                fn from(instr: instruction::CompoundPosition) -> CompoundPosition {
+                    CompoundPosition {
+                        sqrt_price_min_x64: instr.sqrt_price_min_x64,
+                        sqrt_price_max_x64: instr.sqrt_price_max_x64,
+                    }
+                }
+            }
+            println!("{:#?}", CompoundPosition::from(ix));
+        }
+        instruction::PlaceRangeOrder::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::PlaceRangeOrder>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct PlaceRangeOrder;
+            impl From<instruction::PlaceRangeOrder> for PlaceRangeOrder {
+                fn from(_instr: instruction::PlaceRangeOrder) -> PlaceRangeOrder {
+                    PlaceRangeOrder
+                }
+            }
+            println!("{:#?}", PlaceRangeOrder::from(ix));
+        }
+        instruction::ClaimRangeOrder::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::ClaimRangeOrder>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct ClaimRangeOrder;
+            impl From<instruction::ClaimRangeOrder> for ClaimRangeOrder {
+                fn from(_instr: instruction::ClaimRangeOrder) -> ClaimRangeOrder {
+                    ClaimRangeOrder
+                }
+            }
+            println!("{:#?}", ClaimRangeOrder::from(ix));
+        }
+        instruction::LockPosition::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::LockPosition>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct LockPosition {
+                pub locked_until: u64,
+            }
+            impl From<instruction::LockPosition> for LockPosition {
+                fn from(instr: instruction::LockPosition) -> LockPosition {
+                    LockPosition {
+                        locked_until: instr.locked_until,
+                    }
+                }
+            }
+            println!("{:#?}", LockPosition::from(ix));
+        }
+        instruction::FlashLoanBorrow::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::FlashLoanBorrow>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct FlashLoanBorrow {
+                pub amount_0: u64,
+                pub amount_1: u64,
+            }
+            impl From<instruction::FlashLoanBorrow> for FlashLoanBorrow {
+                fn from(instr: instruction::FlashLoanBorrow) -> FlashLoanBorrow {
+                    FlashLoanBorrow {
+                        amount_0: instr.amount_0,
+                        amount_1: instr.amount_1,
+                    }
+                }
+            }
+            println!("{:#?}", FlashLoanBorrow::from(ix));
+        }
+        instruction::FlashLoanRepay::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::FlashLoanRepay>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct FlashLoanRepay;
+            impl From<instruction::FlashLoanRepay> for FlashLoanRepay {
+                fn from(_instr: instruction::FlashLoanRepay) -> FlashLoanRepay {
+                    FlashLoanRepay
+                }
+            }
+            println!("{:#?}", FlashLoanRepay::from(ix));
+        }
         instruction::SwapV2::DISCRIMINATOR => {
             let ix = decode_instruction::<instruction::SwapV2>(&mut ix_data).unwrap();
             #[derive(Debug)]
@@ -636,6 +1187,56 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", SwapV2::from(ix));
         }
+        instruction::SwapV2WithOptions::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SwapV2WithOptions>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SwapV2WithOptions {
+                pub amount: u64,
+                pub other_amount_threshold: u64,
+                pub sqrt_price_limit_x64: u128,
+                pub is_base_input: bool,
+                pub referral_fee_rate: Option<u32>,
+                pub deadline: Option<u64>,
+            }
+            impl From<instruction::SwapV2WithOptions> for SwapV2WithOptions {
+                fn from(instr: instruction::SwapV2WithOptions) -> SwapV2WithOptions {
+                    SwapV2WithOptions {
+                        amount: instr.amount,
+                        other_amount_threshold: instr.other_amount_threshold,
+                        sqrt_price_limit_x64: instr.sqrt_price_limit_x64,
+                        is_base_input: instr.is_base_input,
+                        referral_fee_rate: instr.referral_fee_rate,
+                        deadline: instr.deadline,
+                    }
+                }
+            }
+            println!("{:#?}", SwapV2WithOptions::from(ix));
+        }
+        instruction::FlashSwap::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::FlashSwap>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct FlashSwap {
+                pub amount: u64,
+                pub other_amount_threshold: u64,
+                pub sqrt_price_limit_x64: u128,
+                pub is_base_input: bool,
+                pub callback_account_count: u8,
+                pub callback_data: Vec<u8>,
+            }
+            impl From<instruction::FlashSwap> for FlashSwap {
+                fn from(instr: instruction::FlashSwap) -> FlashSwap {
+                    FlashSwap {
+                        amount: instr.amount,
+                        other_amount_threshold: instr.other_amount_threshold,
+                        sqrt_price_limit_x64: instr.sqrt_price_limit_x64,
+                        is_base_input: instr.is_base_input,
+                        callback_account_count: instr.callback_account_count,
+                        callback_data: instr.callback_data,
+                    }
+                }
+            }
+            println!("{:#?}", FlashSwap::from(ix));
+        }
         instruction::SwapRouterBaseIn::DISCRIMINATOR => {
             let ix = decode_instruction::<instruction::SwapRouterBaseIn>(&mut ix_data).unwrap();
             #[derive(Debug)]
@@ -653,6 +1254,71 @@ pub fn handle_program_instruction(
             }
             println!("{:#?}", SwapRouterBaseIn::from(ix));
         }
+        instruction::SwapRouterBaseInWithOptions::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SwapRouterBaseInWithOptions>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct SwapRouterBaseInWithOptions {
+                pub amount_in: u64,
+                pub amount_out_minimum: u64,
+                pub referral_fee_rate: Option<u32>,
+                pub deadline: Option<u64>,
+            }
+            impl From<instruction::SwapRouterBaseInWithOptions> for SwapRouterBaseInWithOptions {
+                fn from(
+                    instr: instruction::SwapRouterBaseInWithOptions,
+                ) -> SwapRouterBaseInWithOptions {
+                    SwapRouterBaseInWithOptions {
+                        amount_in: instr.amount_in,
+                        amount_out_minimum: instr.amount_out_minimum,
+                        referral_fee_rate: instr.referral_fee_rate,
+                        deadline: instr.deadline,
+                    }
+                }
+            }
+            println!("{:#?}", SwapRouterBaseInWithOptions::from(ix));
+        }
+        instruction::SwapRouterBaseOut::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SwapRouterBaseOut>(&mut ix_data).unwrap();
+            #[derive(Debug)]
+            pub struct SwapRouterBaseOut {
+                pub amount_out: u64,
+                pub amount_in_maximum: u64,
+            }
+            impl From<instruction::SwapRouterBaseOut> for SwapRouterBaseOut {
+                fn from(instr: instruction::SwapRouterBaseOut) -> SwapRouterBaseOut {
+                    SwapRouterBaseOut {
+                        amount_out: instr.amount_out,
+                        amount_in_maximum: instr.amount_in_maximum,
+                    }
+                }
+            }
+            println!("{:#?}", SwapRouterBaseOut::from(ix));
+        }
+        instruction::SwapRouterBaseOutWithOptions::DISCRIMINATOR => {
+            let ix = decode_instruction::<instruction::SwapRouterBaseOutWithOptions>(&mut ix_data)
+                .unwrap();
+            #[derive(Debug)]
+            pub struct SwapRouterBaseOutWithOptions {
+                pub amount_out: u64,
+                pub amount_in_maximum: u64,
+                pub referral_fee_rate: Option<u32>,
+                pub deadline: Option<u64>,
+            }
+            impl From<instruction::SwapRouterBaseOutWithOptions> for SwapRouterBaseOutWithOptions {
+                fn from(
+                    instr: instruction::SwapRouterBaseOutWithOptions,
+                ) -> SwapRouterBaseOutWithOptions {
+                    SwapRouterBaseOutWithOptions {
+                        amount_out: instr.amount_out,
+                        amount_in_maximum: instr.amount_in_maximum,
+                        referral_fee_rate: instr.referral_fee_rate,
+                        deadline: instr.deadline,
+                    }
+                }
+            }
+            println!("{:#?}", SwapRouterBaseOutWithOptions::from(ix));
+        }
         _ => {
             println!("unknow instruction: {}", instr_data);
         }
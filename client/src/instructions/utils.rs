@@ -1,11 +1,12 @@
 use anchor_lang::AccountDeserialize;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use stabbleorg_clmm::libraries::fixed_point_64;
 use stabbleorg_clmm::libraries::*;
+use stabbleorg_clmm::quote;
 use stabbleorg_clmm::states::*;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::program_pack::Pack;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{account::Account, instruction::AccountMeta, pubkey::Pubkey};
 use spl_token_2022::{
     extension::{
         confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
@@ -23,7 +24,10 @@ use spl_token_2022::{
     state::Mint,
 };
 use std::collections::VecDeque;
-use std::ops::{DerefMut, Mul, Neg};
+use std::future::Future;
+use std::ops::Mul;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
 
 pub fn deserialize_anchor_account<T: AccountDeserialize>(account: &Account) -> Result<T> {
     let mut data: &[u8] = &account.data;
@@ -54,6 +58,27 @@ pub struct TransferFeeInfo {
     pub transfer_fee: u64,
 }
 
+/// The address of a pool, `amm_config` must be the config the pool was created with.
+/// After `migrate_pool_config` it differs from `PoolState::get_amm_config`, which is the config
+/// to pass to the pool instructions.
+pub fn get_pool_address(
+    program_id: &Pubkey,
+    amm_config: &Pubkey,
+    token_mint_0: &Pubkey,
+    token_mint_1: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            POOL_SEED.as_bytes(),
+            amm_config.to_bytes().as_ref(),
+            token_mint_0.to_bytes().as_ref(),
+            token_mint_1.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
 pub fn amount_with_slippage(amount: u64, slippage: f64, round_up: bool) -> u64 {
     if round_up {
         (amount as f64).mul(1_f64 + slippage).ceil() as u64
@@ -155,6 +180,58 @@ pub fn get_transfer_fee<'data, S: BaseState + Pack>(
     fee
 }
 
+/// Appends the extra accounts which the transfer hook of `mint` needs to transfer `amount` from
+/// `source` to `destination`, the program resolves them from the remaining accounts.
+/// Nothing is appended for mints without a transfer hook or accounts which are already present.
+pub fn append_transfer_hook_accounts(
+    rpc_client: &RpcClient,
+    remaining_accounts: &mut Vec<AccountMeta>,
+    mint: Pubkey,
+    source: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let mut transfer_instruction = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::id(),
+        &source,
+        &mint,
+        &destination,
+        &authority,
+        &[],
+        amount,
+        0,
+    )?;
+    let transfer_accounts_len = transfer_instruction.accounts.len();
+    let resolve = spl_token_2022::offchain::add_extra_account_metas(
+        &mut transfer_instruction,
+        &source,
+        &mint,
+        &destination,
+        &authority,
+        amount,
+        |address| std::future::ready(Ok(rpc_client.get_account(&address).ok().map(|a| a.data))),
+    );
+    // the rpc client blocks, so every account fetch is ready the first time it is polled
+    match pin!(resolve).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(result) => result.map_err(|e| anyhow!(e))?,
+        Poll::Pending => unreachable!(),
+    }
+    for account in transfer_instruction.accounts.split_off(transfer_accounts_len) {
+        match remaining_accounts
+            .iter_mut()
+            .find(|item| item.pubkey == account.pubkey)
+        {
+            Some(item) => item.is_writable |= account.is_writable,
+            None => remaining_accounts.push(AccountMeta {
+                is_signer: false,
+                ..account
+            }),
+        }
+    }
+    Ok(())
+}
+
 pub fn get_account_extensions<'data, S: BaseState + Pack>(
     account_state: &StateWithExtensions<'data, S>,
 ) -> Vec<ExtensionStruct> {
@@ -276,38 +353,8 @@ pub fn sqrt_price_x64_to_price(price: u128, decimals_0: u8, decimals_1: u8) -> f
     from_x64_price(price).powi(2) * multipler(decimals_0) / multipler(decimals_1)
 }
 
-// the top level state of the swap, the results of which are recorded in storage at the end
-#[derive(Debug)]
-pub struct SwapState {
-    // the amount remaining to be swapped in/out of the input/output asset
-    pub amount_specified_remaining: u64,
-    // the amount already swapped out/in of the output/input asset
-    pub amount_calculated: u64,
-    // current sqrt(price)
-    pub sqrt_price_x64: u128,
-    // the tick associated with the current price
-    pub tick: i32,
-    // the current liquidity in range
-    pub liquidity: u128,
-}
-#[derive(Default)]
-struct StepComputations {
-    // the price at the beginning of the step
-    sqrt_price_start_x64: u128,
-    // the next tick to swap to from the current tick in the swap direction
-    tick_next: i32,
-    // whether tick_next is initialized or not
-    initialized: bool,
-    // sqrt(price) for the next tick (1/0)
-    sqrt_price_next_x64: u128,
-    // how much is being swapped in in this step
-    amount_in: u64,
-    // how much is being swapped out
-    amount_out: u64,
-    // how much fee is being paid in
-    fee_amount: u64,
-}
-
+/// Quotes a swap with the transfer fees of both mints and the twap guard of the pool, the returned
+/// amount is the amount the user receives if `is_base_input`, otherwise the amount the user sends
 pub fn get_out_put_amount_and_remaining_accounts(
     input_amount: u64,
     sqrt_price_limit_x64: Option<u128>,
@@ -317,211 +364,46 @@ pub fn get_out_put_amount_and_remaining_accounts(
     pool_state: &PoolState,
     tickarray_bitmap_extension: &TickArrayBitmapExtension,
     tick_arrays: &mut VecDeque<TickArrayState>,
+    observation_account: &Account,
+    input_mint_state: &StateWithExtensions<Mint>,
+    output_mint_state: &StateWithExtensions<Mint>,
+    epoch: u64,
 ) -> Result<(u64, VecDeque<i32>), &'static str> {
-    let (is_pool_current_tick_array, current_valid_tick_array_start_index) = pool_state
-        .get_first_initialized_tick_array(&Some(*tickarray_bitmap_extension), zero_for_one)
-        .unwrap();
-
-    let (amount_calculated, tick_array_start_index_vec) = swap_compute(
-        zero_for_one,
-        is_base_input,
-        is_pool_current_tick_array,
-        pool_config.trade_fee_rate,
-        input_amount,
-        current_valid_tick_array_start_index,
-        sqrt_price_limit_x64.unwrap_or(0),
+    let block_timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32;
+    // the twap guard of the pool caps or rejects the swap like the program does
+    let (observation_state, observation_extension) =
+        quote::load_observation_account(&observation_account.data)
+            .map_err(|_| "load observation account failed")?;
+    let quote = quote::quote_swap(
+        pool_config,
         pool_state,
-        tickarray_bitmap_extension,
-        tick_arrays,
-    )?;
-    println!("tick_array_start_index:{:?}", tick_array_start_index_vec);
-
-    Ok((amount_calculated, tick_array_start_index_vec))
-}
-
-fn swap_compute(
-    zero_for_one: bool,
-    is_base_input: bool,
-    is_pool_current_tick_array: bool,
-    fee: u32,
-    amount_specified: u64,
-    current_valid_tick_array_start_index: i32,
-    sqrt_price_limit_x64: u128,
-    pool_state: &PoolState,
-    tickarray_bitmap_extension: &TickArrayBitmapExtension,
-    tick_arrays: &mut VecDeque<TickArrayState>,
-) -> Result<(u64, VecDeque<i32>), &'static str> {
-    if amount_specified == 0 {
-        return Result::Err("amountSpecified must not be 0");
-    }
-    let sqrt_price_limit_x64 = if sqrt_price_limit_x64 == 0 {
-        if zero_for_one {
-            tick_math::MIN_SQRT_PRICE_X64 + 1
-        } else {
-            tick_math::MAX_SQRT_PRICE_X64 - 1
-        }
-    } else {
-        sqrt_price_limit_x64
-    };
-    if zero_for_one {
-        if sqrt_price_limit_x64 < tick_math::MIN_SQRT_PRICE_X64 {
-            return Result::Err("sqrt_price_limit_x64 must greater than MIN_SQRT_PRICE_X64");
-        }
-        if sqrt_price_limit_x64 >= pool_state.sqrt_price_x64 {
-            return Result::Err("sqrt_price_limit_x64 must smaller than current");
-        }
+        &Some(*tickarray_bitmap_extension),
+        tick_arrays.make_contiguous(),
+        &quote::QuoteSwapParams {
+            amount_specified: input_amount,
+            sqrt_price_limit_x64: sqrt_price_limit_x64.unwrap_or(0),
+            zero_for_one,
+            is_base_input,
+            block_timestamp,
+            epoch,
+            input_transfer_fee_config: input_mint_state.get_extension::<TransferFeeConfig>().ok(),
+            output_transfer_fee_config: output_mint_state.get_extension::<TransferFeeConfig>().ok(),
+            observation_state: Some(&observation_state),
+            observation_extension: &observation_extension,
+        },
+    )
+    .map_err(|_| "swap quote failed")?;
+    let amount_calculated = if is_base_input {
+        quote.transfer_amount_out
     } else {
-        if sqrt_price_limit_x64 > tick_math::MAX_SQRT_PRICE_X64 {
-            return Result::Err("sqrt_price_limit_x64 must smaller than MAX_SQRT_PRICE_X64");
-        }
-        if sqrt_price_limit_x64 <= pool_state.sqrt_price_x64 {
-            return Result::Err("sqrt_price_limit_x64 must greater than current");
-        }
-    }
-    let mut tick_match_current_tick_array = is_pool_current_tick_array;
-
-    let mut state = SwapState {
-        amount_specified_remaining: amount_specified,
-        amount_calculated: 0,
-        sqrt_price_x64: pool_state.sqrt_price_x64,
-        tick: pool_state.tick_current,
-        liquidity: pool_state.liquidity,
+        quote.transfer_amount_in
     };
+    let tick_array_start_index_vec: VecDeque<i32> =
+        quote.computation.tick_array_start_indexes.into();
+    println!("tick_array_start_index:{:?}", tick_array_start_index_vec);
 
-    let mut tick_array_current = tick_arrays.pop_front().unwrap();
-    if tick_array_current.start_tick_index != current_valid_tick_array_start_index {
-        return Result::Err("tick array start tick index does not match");
-    }
-    let mut tick_array_start_index_vec = VecDeque::new();
-    tick_array_start_index_vec.push_back(tick_array_current.start_tick_index);
-    let mut loop_count = 0;
-    // loop across ticks until input liquidity is consumed, or the limit price is reached
-    while state.amount_specified_remaining != 0
-        && state.sqrt_price_x64 != sqrt_price_limit_x64
-        && state.tick < tick_math::MAX_TICK
-        && state.tick > tick_math::MIN_TICK
-    {
-        if loop_count > 10 {
-            return Result::Err("loop_count limit");
-        }
-        let mut step = StepComputations::default();
-        step.sqrt_price_start_x64 = state.sqrt_price_x64;
-        // save the bitmap, and the tick account if it is initialized
-        let mut next_initialized_tick = if let Some(tick_state) = tick_array_current
-            .next_initialized_tick(state.tick, pool_state.tick_spacing, zero_for_one)
-            .unwrap()
-        {
-            Box::new(*tick_state)
-        } else {
-            if !tick_match_current_tick_array {
-                tick_match_current_tick_array = true;
-                Box::new(
-                    *tick_array_current
-                        .first_initialized_tick(zero_for_one)
-                        .unwrap(),
-                )
-            } else {
-                Box::new(TickState::default())
-            }
-        };
-        if !next_initialized_tick.is_initialized() {
-            let current_valid_tick_array_start_index = pool_state
-                .next_initialized_tick_array_start_index(
-                    &Some(*tickarray_bitmap_extension),
-                    current_valid_tick_array_start_index,
-                    zero_for_one,
-                )
-                .unwrap();
-            tick_array_current = tick_arrays.pop_front().unwrap();
-            if current_valid_tick_array_start_index.is_none() {
-                return Result::Err("tick array start tick index out of range limit");
-            }
-            if tick_array_current.start_tick_index != current_valid_tick_array_start_index.unwrap()
-            {
-                return Result::Err("tick array start tick index does not match");
-            }
-            tick_array_start_index_vec.push_back(tick_array_current.start_tick_index);
-            let mut first_initialized_tick = tick_array_current
-                .first_initialized_tick(zero_for_one)
-                .unwrap();
-
-            next_initialized_tick = Box::new(*first_initialized_tick.deref_mut());
-        }
-        step.tick_next = next_initialized_tick.tick;
-        step.initialized = next_initialized_tick.is_initialized();
-        if step.tick_next < MIN_TICK {
-            step.tick_next = MIN_TICK;
-        } else if step.tick_next > MAX_TICK {
-            step.tick_next = MAX_TICK;
-        }
-
-        step.sqrt_price_next_x64 = tick_math::get_sqrt_price_at_tick(step.tick_next).unwrap();
-
-        let target_price = if (zero_for_one && step.sqrt_price_next_x64 < sqrt_price_limit_x64)
-            || (!zero_for_one && step.sqrt_price_next_x64 > sqrt_price_limit_x64)
-        {
-            sqrt_price_limit_x64
-        } else {
-            step.sqrt_price_next_x64
-        };
-        let swap_step = swap_math::compute_swap_step(
-            state.sqrt_price_x64,
-            target_price,
-            state.liquidity,
-            state.amount_specified_remaining,
-            fee,
-            is_base_input,
-            zero_for_one,
-            1,
-        )
-        .unwrap();
-        state.sqrt_price_x64 = swap_step.sqrt_price_next_x64;
-        step.amount_in = swap_step.amount_in;
-        step.amount_out = swap_step.amount_out;
-        step.fee_amount = swap_step.fee_amount;
-
-        if is_base_input {
-            state.amount_specified_remaining = state
-                .amount_specified_remaining
-                .checked_sub(step.amount_in + step.fee_amount)
-                .unwrap();
-            state.amount_calculated = state
-                .amount_calculated
-                .checked_add(step.amount_out)
-                .unwrap();
-        } else {
-            state.amount_specified_remaining = state
-                .amount_specified_remaining
-                .checked_sub(step.amount_out)
-                .unwrap();
-            state.amount_calculated = state
-                .amount_calculated
-                .checked_add(step.amount_in + step.fee_amount)
-                .unwrap();
-        }
-
-        if state.sqrt_price_x64 == step.sqrt_price_next_x64 {
-            // if the tick is initialized, run the tick transition
-            if step.initialized {
-                let mut liquidity_net = next_initialized_tick.liquidity_net;
-                if zero_for_one {
-                    liquidity_net = liquidity_net.neg();
-                }
-                state.liquidity =
-                    liquidity_math::add_delta(state.liquidity, liquidity_net).unwrap();
-            }
-
-            state.tick = if zero_for_one {
-                step.tick_next - 1
-            } else {
-                step.tick_next
-            };
-        } else if state.sqrt_price_x64 != step.sqrt_price_start_x64 {
-            // recompute unless we're on a lower tick boundary (i.e. already transitioned ticks), and haven't moved
-            state.tick = tick_math::get_tick_at_sqrt_price(state.sqrt_price_x64).unwrap();
-        }
-        loop_count += 1;
-    }
-
-    Ok((state.amount_calculated, tick_array_start_index_vec))
+    Ok((amount_calculated, tick_array_start_index_vec))
 }
@@ -130,18 +130,14 @@ fn load_cfg(client_config: &String) -> Result<ClientConfig> {
             mint0 = mint1;
             mint1 = temp_mint;
         }
-        Some(
-            Pubkey::find_program_address(
-                &[
-                    stabbleorg_clmm::states::POOL_SEED.as_bytes(),
-                    amm_config_key.to_bytes().as_ref(),
-                    mint0.unwrap().to_bytes().as_ref(),
-                    mint1.unwrap().to_bytes().as_ref(),
-                ],
-                &raydium_v3_program,
-            )
-            .0,
-        )
+        // amm_config_index names the config the pool was created with, which seeds the pool
+        // address even after the pool is migrated to another config
+        Some(get_pool_address(
+            &raydium_v3_program,
+            &amm_config_key,
+            &mint0.unwrap(),
+            &mint1.unwrap(),
+        ))
     } else {
         None
     };
@@ -394,6 +390,30 @@ pub enum CommandsName {
         value: u32,
         remaining: Option<Pubkey>,
     },
+    AcceptConfigOwner {
+        config_index: u16,
+    },
+    SetConfigUpdateDelay {
+        delay: u64,
+    },
+    ScheduleConfigUpdate {
+        config_index: u16,
+        param: u8,
+        value: u32,
+    },
+    ExecuteConfigUpdate {
+        config_index: u16,
+        param: u8,
+    },
+    CancelConfigUpdate {
+        config_index: u16,
+        param: u8,
+    },
+    CreateGlobalConfig,
+    ProposeAdmin {
+        new_admin: Pubkey,
+    },
+    AcceptAdmin,
     CreateOperation,
     UpdateOperation {
         param: u8,
@@ -406,6 +426,12 @@ pub enum CommandsName {
         mint1: Pubkey,
         #[arg(short, long, default_value_t = 0)]
         open_time: u64,
+        #[arg(long, default_value_t = 0)]
+        launch_fee_start_rate: u32,
+        #[arg(long, default_value_t = 0)]
+        launch_fee_end_rate: u32,
+        #[arg(long, default_value_t = 0)]
+        launch_fee_duration: u32,
     },
     InitReward {
         open_time: u64,
@@ -435,6 +461,12 @@ pub enum CommandsName {
         input_amount: u64,
         #[arg(short, long)]
         with_metadata: bool,
+        #[arg(long)]
+        deadline: Option<u64>,
+        #[arg(long)]
+        sqrt_price_min_x64: Option<u128>,
+        #[arg(long)]
+        sqrt_price_max_x64: Option<u128>,
     },
     IncreaseLiquidity {
         tick_lower_price: f64,
@@ -442,6 +474,12 @@ pub enum CommandsName {
         #[arg(short, long)]
         is_base_0: bool,
         imput_amount: u64,
+        #[arg(long)]
+        deadline: Option<u64>,
+        #[arg(long)]
+        sqrt_price_min_x64: Option<u128>,
+        #[arg(long)]
+        sqrt_price_max_x64: Option<u128>,
     },
     DecreaseLiquidity {
         tick_lower_index: i32,
@@ -449,6 +487,12 @@ pub enum CommandsName {
         liquidity: Option<u128>,
         #[arg(short, long)]
         simulate: bool,
+        #[arg(long)]
+        deadline: Option<u64>,
+        #[arg(long)]
+        sqrt_price_min_x64: Option<u128>,
+        #[arg(long)]
+        sqrt_price_max_x64: Option<u128>,
     },
     Swap {
         input_token: Pubkey,
@@ -469,6 +513,12 @@ pub enum CommandsName {
         simulate: bool,
         amount: u64,
         limit_price: Option<f64>,
+        #[arg(long)]
+        referrer_token: Option<Pubkey>,
+        #[arg(long, default_value_t = 0)]
+        referral_fee_rate: u32,
+        #[arg(long)]
+        deadline: Option<u64>,
     },
     PPositionByOwner {
         user_wallet: Pubkey,
@@ -532,6 +582,9 @@ pub enum CommandsName {
     PPersonal {
         personal_id: Pubkey,
     },
+    PPositionLock {
+        personal_id: Pubkey,
+    },
     DecodeInstruction {
         instr_hex_data: String,
     },
@@ -799,23 +852,86 @@ fn main() -> Result<()> {
             value,
             remaining,
         } => {
-            let mut remaing_accounts = Vec::new();
-            let mut update_value = 0;
+            let (amm_config_key, __bump) = Pubkey::find_program_address(
+                &[
+                    stabbleorg_clmm::states::AMM_CONFIG_SEED.as_bytes(),
+                    &config_index.to_be_bytes(),
+                ],
+                &pool_config.raydium_v3_program,
+            );
             let match_param = Some(param);
-            match match_param {
-                Some(0) => update_value = value,
-                Some(1) => update_value = value,
-                Some(2) => update_value = value,
-                Some(3) => {
-                    let remaining_key = remaining.unwrap();
-                    remaing_accounts.push(AccountMeta::new_readonly(remaining_key, false));
-                }
-                Some(4) => {
-                    let remaining_key = remaining.unwrap();
-                    remaing_accounts.push(AccountMeta::new_readonly(remaining_key, false));
+            let instr = match match_param {
+                Some(0) | Some(1) | Some(2) | Some(5) | Some(6) | Some(7) | Some(8) | Some(9)
+                | Some(10) => {
+                    update_amm_config_instr(&pool_config.clone(), amm_config_key, param, value)?
                 }
+                // the owners change once the proposed key accepts
+                Some(3) => propose_amm_config_owner_instr(
+                    &pool_config.clone(),
+                    amm_config_key,
+                    Some(remaining.unwrap()),
+                    None,
+                )?,
+                Some(4) => propose_amm_config_owner_instr(
+                    &pool_config.clone(),
+                    amm_config_key,
+                    None,
+                    Some(remaining.unwrap()),
+                )?,
                 _ => panic!("error input"),
-            }
+            };
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::AcceptConfigOwner { config_index } => {
+            let (amm_config_key, __bump) = Pubkey::find_program_address(
+                &[
+                    stabbleorg_clmm::states::AMM_CONFIG_SEED.as_bytes(),
+                    &config_index.to_be_bytes(),
+                ],
+                &pool_config.raydium_v3_program,
+            );
+            let accept_instr = accept_amm_config_owner_instr(&pool_config.clone(), amm_config_key)?;
+            // send
+            let signers = vec![&payer];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &accept_instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::SetConfigUpdateDelay { delay } => {
+            let instr = set_config_update_delay_instr(&pool_config.clone(), delay)?;
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::ScheduleConfigUpdate {
+            config_index,
+            param,
+            value,
+        } => {
             let (amm_config_key, __bump) = Pubkey::find_program_address(
                 &[
                     stabbleorg_clmm::states::AMM_CONFIG_SEED.as_bytes(),
@@ -823,18 +939,109 @@ fn main() -> Result<()> {
                 ],
                 &pool_config.raydium_v3_program,
             );
-            let update_amm_config_instr = update_amm_config_instr(
+            let instr = schedule_amm_config_update_instr(
                 &pool_config.clone(),
                 amm_config_key,
-                remaing_accounts,
                 param,
-                update_value,
+                value,
             )?;
             // send
             let signers = vec![&payer, &admin];
             let recent_hash = rpc_client.get_latest_blockhash()?;
             let txn = Transaction::new_signed_with_payer(
-                &update_amm_config_instr,
+                &instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::ExecuteConfigUpdate {
+            config_index,
+            param,
+        } => {
+            let (amm_config_key, __bump) = Pubkey::find_program_address(
+                &[
+                    stabbleorg_clmm::states::AMM_CONFIG_SEED.as_bytes(),
+                    &config_index.to_be_bytes(),
+                ],
+                &pool_config.raydium_v3_program,
+            );
+            let instr =
+                execute_amm_config_update_instr(&pool_config.clone(), amm_config_key, param)?;
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::CancelConfigUpdate {
+            config_index,
+            param,
+        } => {
+            let (amm_config_key, __bump) = Pubkey::find_program_address(
+                &[
+                    stabbleorg_clmm::states::AMM_CONFIG_SEED.as_bytes(),
+                    &config_index.to_be_bytes(),
+                ],
+                &pool_config.raydium_v3_program,
+            );
+            let instr =
+                cancel_amm_config_update_instr(&pool_config.clone(), amm_config_key, param)?;
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::CreateGlobalConfig => {
+            let create_instr = create_global_config_instr(&pool_config.clone())?;
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &create_instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::ProposeAdmin { new_admin } => {
+            let propose_instr = propose_admin_instr(&pool_config.clone(), new_admin)?;
+            // send
+            let signers = vec![&payer, &admin];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &propose_instr,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_hash,
+            );
+            let signature = send_txn(&rpc_client, &txn, true)?;
+            println!("{}", signature);
+        }
+        CommandsName::AcceptAdmin => {
+            let accept_instr = accept_admin_instr(&pool_config.clone())?;
+            // send
+            let signers = vec![&payer];
+            let recent_hash = rpc_client.get_latest_blockhash()?;
+            let txn = Transaction::new_signed_with_payer(
+                &accept_instr,
                 Some(&payer.pubkey()),
                 &signers,
                 recent_hash,
@@ -876,6 +1083,9 @@ fn main() -> Result<()> {
             mint0,
             mint1,
             open_time,
+            launch_fee_start_rate,
+            launch_fee_end_rate,
+            launch_fee_duration,
         } => {
             let mut price = price;
             let mut mint0 = mint0;
@@ -918,6 +1128,9 @@ fn main() -> Result<()> {
                 pool_config.tickarray_bitmap_extension.unwrap(),
                 sqrt_price_x64,
                 open_time,
+                launch_fee_start_rate,
+                launch_fee_end_rate,
+                launch_fee_duration,
             )?;
 
             // send
@@ -963,7 +1176,7 @@ fn main() -> Result<()> {
             let create_instr = initialize_reward_instr(
                 &pool_config.clone(),
                 pool_config.pool_id_account.unwrap(),
-                pool_account.amm_config,
+                pool_account.get_amm_config(),
                 operator_account_key,
                 reward_mint,
                 reward_token_vault,
@@ -1016,7 +1229,7 @@ fn main() -> Result<()> {
             let user_reward_token = get_associated_token_address(&admin.pubkey(), &reward_mint);
             let create_instr = set_reward_params_instr(
                 &pool_config.clone(),
-                pool_account.amm_config,
+                pool_account.get_amm_config(),
                 pool_config.pool_id_account.unwrap(),
                 reward_token_vault,
                 user_reward_token,
@@ -1076,6 +1289,9 @@ fn main() -> Result<()> {
             is_base_0,
             input_amount,
             with_metadata,
+            deadline,
+            sqrt_price_min_x64,
+            sqrt_price_max_x64,
         } => {
             // load pool to get observation
             let pool: stabbleorg_clmm::states::PoolState =
@@ -1239,6 +1455,9 @@ fn main() -> Result<()> {
                     tick_array_lower_start_index,
                     tick_array_upper_start_index,
                     with_metadata,
+                    deadline,
+                    sqrt_price_min_x64,
+                    sqrt_price_max_x64,
                 )?;
                 instructions.extend(open_position_instr);
                 // send
@@ -1262,6 +1481,9 @@ fn main() -> Result<()> {
             tick_upper_price,
             is_base_0,
             imput_amount,
+            deadline,
+            sqrt_price_min_x64,
+            sqrt_price_max_x64,
         } => {
             // load pool to get observation
             let pool: stabbleorg_clmm::states::PoolState =
@@ -1425,6 +1647,9 @@ fn main() -> Result<()> {
                     tick_upper_index,
                     tick_array_lower_start_index,
                     tick_array_upper_start_index,
+                    deadline,
+                    sqrt_price_min_x64,
+                    sqrt_price_max_x64,
                 )?;
                 // send
                 let signers = vec![&payer];
@@ -1447,6 +1672,9 @@ fn main() -> Result<()> {
             tick_upper_index,
             liquidity,
             simulate,
+            deadline,
+            sqrt_price_min_x64,
+            sqrt_price_max_x64,
         } => {
             // load pool to get observation
             let pool: stabbleorg_clmm::states::PoolState =
@@ -1554,6 +1782,19 @@ fn main() -> Result<()> {
                     .map(|item| AccountMeta::new(item, false))
                     .collect();
                 remaining_accounts.append(&mut accounts);
+                for (i, item) in pool.reward_infos.into_iter().enumerate() {
+                    if item.token_mint != Pubkey::default() {
+                        append_transfer_hook_accounts(
+                            &rpc_client,
+                            &mut remaining_accounts,
+                            item.token_mint,
+                            item.token_vault,
+                            get_associated_token_address(&payer.pubkey(), &item.token_mint),
+                            pool_config.pool_id_account.unwrap(),
+                            find_position.reward_infos[i].reward_amount_owed,
+                        )?;
+                    }
+                }
                 // personal position exist
                 let mut decrease_instr = decrease_liquidity_instr(
                     &pool_config.clone(),
@@ -1582,6 +1823,9 @@ fn main() -> Result<()> {
                     tick_upper_index,
                     tick_array_lower_start_index,
                     tick_array_upper_start_index,
+                    deadline,
+                    sqrt_price_min_x64,
+                    sqrt_price_max_x64,
                 )?;
                 if liquidity == find_position.liquidity {
                     let close_position_instr = close_personal_position_instr(
@@ -1633,22 +1877,35 @@ fn main() -> Result<()> {
                 pool_config.amm_config_key,
                 pool_config.pool_id_account.unwrap(),
                 pool_config.tickarray_bitmap_extension.unwrap(),
+                pool_config.mint0.unwrap(),
+                pool_config.mint1.unwrap(),
             ];
             let rsps = rpc_client.get_multiple_accounts(&load_accounts)?;
-            let [user_input_account, user_output_account, amm_config_account, pool_account, tickarray_bitmap_extension_account] =
-                array_ref![rsps, 0, 5];
+            let epoch = rpc_client.get_epoch_info().unwrap().epoch;
+            let [user_input_account, user_output_account, amm_config_account, pool_account, tickarray_bitmap_extension_account, mint0_account, mint1_account] =
+                array_ref![rsps, 0, 7];
             let user_input_state =
                 StateWithExtensions::<Account>::unpack(&user_input_account.as_ref().unwrap().data)
                     .unwrap();
             let user_output_state =
                 StateWithExtensions::<Account>::unpack(&user_output_account.as_ref().unwrap().data)
                     .unwrap();
+            let mint0_data = mint0_account.clone().unwrap().data;
+            let mint0_state = StateWithExtensions::<Mint>::unpack(&mint0_data)?;
+            let mint1_data = mint1_account.clone().unwrap().data;
+            let mint1_state = StateWithExtensions::<Mint>::unpack(&mint1_data)?;
             let amm_config_state = deserialize_anchor_account::<stabbleorg_clmm::states::AmmConfig>(
                 amm_config_account.as_ref().unwrap(),
             )?;
             let pool_state = deserialize_anchor_account::<stabbleorg_clmm::states::PoolState>(
                 pool_account.as_ref().unwrap(),
             )?;
+            // a migrated pool is priced by its effective config, not the config seeding its address
+            let amm_config_state = if pool_state.get_amm_config() == pool_config.amm_config_key {
+                amm_config_state
+            } else {
+                program.account(pool_state.get_amm_config())?
+            };
             let tickarray_bitmap_extension =
                 deserialize_anchor_account::<stabbleorg_clmm::states::TickArrayBitmapExtension>(
                     tickarray_bitmap_extension_account.as_ref().unwrap(),
@@ -1663,6 +1920,7 @@ fn main() -> Result<()> {
                 &tickarray_bitmap_extension,
                 zero_for_one,
             );
+            let observation_account = rpc_client.get_account(&pool_state.observation_key)?;
 
             let mut sqrt_price_limit_x64 = None;
             if limit_price.is_some() {
@@ -1674,6 +1932,11 @@ fn main() -> Result<()> {
                 sqrt_price_limit_x64 = Some(sqrt_price_x64);
             }
 
+            let (input_mint_state, output_mint_state) = if zero_for_one {
+                (&mint0_state, &mint1_state)
+            } else {
+                (&mint1_state, &mint0_state)
+            };
             let (mut other_amount_threshold, mut tick_array_indexs) =
                 utils::get_out_put_amount_and_remaining_accounts(
                     amount,
@@ -1684,6 +1947,10 @@ fn main() -> Result<()> {
                     &pool_state,
                     &tickarray_bitmap_extension,
                     &mut tick_arrays,
+                    &observation_account,
+                    input_mint_state,
+                    output_mint_state,
+                    epoch,
                 )
                 .unwrap();
             println!(
@@ -1737,7 +2004,7 @@ fn main() -> Result<()> {
             instructions.push(request_inits_instr);
             let swap_instr = swap_instr(
                 &pool_config.clone(),
-                pool_state.amm_config,
+                pool_state.get_amm_config(),
                 pool_config.pool_id_account.unwrap(),
                 if zero_for_one {
                     pool_state.token_vault_0
@@ -1786,6 +2053,9 @@ fn main() -> Result<()> {
             simulate,
             amount,
             limit_price,
+            referrer_token,
+            referral_fee_rate,
+            deadline,
         } => {
             // load mult account
             let load_accounts = vec![
@@ -1817,6 +2087,12 @@ fn main() -> Result<()> {
             let pool_state = deserialize_anchor_account::<stabbleorg_clmm::states::PoolState>(
                 pool_account.as_ref().unwrap(),
             )?;
+            // a migrated pool is priced by its effective config, not the config seeding its address
+            let amm_config_state = if pool_state.get_amm_config() == pool_config.amm_config_key {
+                amm_config_state
+            } else {
+                program.account(pool_state.get_amm_config())?
+            };
             let tickarray_bitmap_extension =
                 deserialize_anchor_account::<stabbleorg_clmm::states::TickArrayBitmapExtension>(
                     tickarray_bitmap_extension_account.as_ref().unwrap(),
@@ -1824,16 +2100,6 @@ fn main() -> Result<()> {
             let zero_for_one = user_input_state.base.mint == pool_state.token_mint_0
                 && user_output_state.base.mint == pool_state.token_mint_1;
 
-            let transfer_fee = if base_in {
-                if zero_for_one {
-                    get_transfer_fee(&mint0_state, epoch, amount)
-                } else {
-                    get_transfer_fee(&mint1_state, epoch, amount)
-                }
-            } else {
-                0
-            };
-            let amount_specified = amount.checked_sub(transfer_fee).unwrap();
             // load tick_arrays
             let mut tick_arrays = load_cur_and_next_five_tick_array(
                 &rpc_client,
@@ -1842,6 +2108,7 @@ fn main() -> Result<()> {
                 &tickarray_bitmap_extension,
                 zero_for_one,
             );
+            let observation_account = rpc_client.get_account(&pool_state.observation_key)?;
 
             let mut sqrt_price_limit_x64 = None;
             if limit_price.is_some() {
@@ -1853,9 +2120,15 @@ fn main() -> Result<()> {
                 sqrt_price_limit_x64 = Some(sqrt_price_x64);
             }
 
+            let (input_mint_state, output_mint_state) = if zero_for_one {
+                (&mint0_state, &mint1_state)
+            } else {
+                (&mint1_state, &mint0_state)
+            };
+            // the quote includes the transfer fees, the threshold is the amount the user sees
             let (mut other_amount_threshold, tick_array_indexs) =
                 utils::get_out_put_amount_and_remaining_accounts(
-                    amount_specified,
+                    amount,
                     sqrt_price_limit_x64,
                     zero_for_one,
                     base_in,
@@ -1863,6 +2136,10 @@ fn main() -> Result<()> {
                     &pool_state,
                     &tickarray_bitmap_extension,
                     &mut tick_arrays,
+                    &observation_account,
+                    input_mint_state,
+                    output_mint_state,
+                    epoch,
                 )
                 .unwrap();
             println!(
@@ -1877,13 +2154,6 @@ fn main() -> Result<()> {
                 // calc max in with slippage
                 other_amount_threshold =
                     amount_with_slippage(other_amount_threshold, pool_config.slippage, true);
-                // calc max in with transfer_fee
-                let transfer_fee = if zero_for_one {
-                    get_transfer_inverse_fee(&mint0_state, epoch, other_amount_threshold)
-                } else {
-                    get_transfer_inverse_fee(&mint1_state, epoch, other_amount_threshold)
-                };
-                other_amount_threshold += transfer_fee;
             }
 
             let mut remaining_accounts = Vec::new();
@@ -1914,7 +2184,7 @@ fn main() -> Result<()> {
             instructions.push(request_inits_instr);
             let swap_instr = swap_v2_instr(
                 &pool_config.clone(),
-                pool_state.amm_config,
+                pool_state.get_amm_config(),
                 pool_config.pool_id_account.unwrap(),
                 if zero_for_one {
                     pool_state.token_vault_0
@@ -1944,6 +2214,9 @@ fn main() -> Result<()> {
                 other_amount_threshold,
                 sqrt_price_limit_x64,
                 base_in,
+                referrer_token,
+                referral_fee_rate,
+                deadline,
             )
             .unwrap();
             instructions.extend(swap_instr);
@@ -2294,6 +2567,25 @@ fn main() -> Result<()> {
                 program.account(personal_id)?;
             println!("{:#?}", personal_account);
         }
+        CommandsName::PPositionLock { personal_id } => {
+            let personal_account: stabbleorg_clmm::states::PersonalPositionState =
+                program.account(personal_id)?;
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_secs();
+            if personal_account.locked_until == stabbleorg_clmm::states::PERMANENT_LOCK {
+                println!("position:{} is locked permanently", personal_id);
+            } else if personal_account.is_locked(now) {
+                println!(
+                    "position:{} is locked until:{}, remaining seconds:{}",
+                    personal_id,
+                    personal_account.locked_until,
+                    personal_account.locked_until - now
+                );
+            } else {
+                println!("position:{} is not locked", personal_id);
+            }
+        }
         CommandsName::DecodeInstruction { instr_hex_data } => {
             handle_program_instruction(&instr_hex_data, InstructionDecodeType::BaseHex)?;
         }
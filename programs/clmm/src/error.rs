@@ -107,4 +107,106 @@ pub enum ErrorCode {
     CalculateOverflow,
     #[msg("TransferFee calculate not match")]
     TransferFeeCalculateNotMatch,
+
+    /// oracle errors
+    #[msg("Observation state is not initialized")]
+    ObservationNotInitialized,
+    #[msg("Observation target is older than the oldest observation")]
+    ObservationTooOld,
+    #[msg("Invalid observation period")]
+    InvalidObservationPeriod,
+    #[msg("Invalid observation cardinality")]
+    InvalidObservationCardinality,
+    #[msg("Tick is not initialized")]
+    TickNotInitialized,
+
+    /// range order errors
+    #[msg("Range order must span one tick spacing on one side of the current price")]
+    InvalidRangeOrder,
+    #[msg("Not allowed on a range order position")]
+    RangeOrderPosition,
+    #[msg("Position is not a range order")]
+    NotRangeOrder,
+
+    /// flash loan errors
+    #[msg("Flash loans are disabled for this config")]
+    FlashLoanDisabled,
+    #[msg("The pool has a flash loan in progress")]
+    FlashLoanInProgress,
+    #[msg("The flash loan is not repaid in the same transaction")]
+    FlashLoanNotRepaid,
+    #[msg("The pool has no flash loan to repay")]
+    FlashLoanNotBorrowed,
+
+    /// flash swap errors
+    #[msg("Invalid flash swap callback program")]
+    InvalidFlashSwapCallback,
+    #[msg("The flash swap callback did not pay the input amount")]
+    FlashSwapNotPaid,
+
+    /// position lock errors
+    #[msg("The position is locked")]
+    PositionLocked,
+    #[msg("The lock must end in the future and can only be extended")]
+    InvalidLockTime,
+
+    /// pool config migration errors
+    #[msg("The new amm config must differ from the current one and have the same tick spacing")]
+    InvalidMigrateConfig,
+
+    /// pool launch errors
+    #[msg("The pool is not open for swaps yet")]
+    NotOpenYet,
+    #[msg("The launch fee must not increase and must be within the caps of the amm config")]
+    InvalidLaunchFee,
+
+    /// mint policy errors
+    #[msg("The extension policy of a support mint must allow at least one extension")]
+    InvalidExtensionPolicy,
+
+    /// config timelock errors
+    #[msg("Amm config updates are timelocked, schedule the update instead")]
+    ConfigUpdateTimelocked,
+    #[msg("The scheduled config update can not be executed yet")]
+    ConfigUpdateNotReady,
+
+    /// twap guard errors
+    #[msg("The twap guard needs a tick deviation and a known mode when it is enabled")]
+    InvalidTwapGuard,
+    #[msg("The swap moves the price too far from the time-weighted average price")]
+    TwapDeviationExceeded,
+
+    /// referral fee errors
+    #[msg("The referral fee rate exceeds the max referral fee rate of the amm config")]
+    InvalidReferralFeeRate,
+    #[msg("The referrer token account must hold the input token of the swap")]
+    InvalidReferrerTokenAccount,
+
+    /// liquidity price guard errors
+    #[msg("The pool price is outside of the accepted price range")]
+    SqrtPriceOutOfRange,
+
+    /// pool config timelock errors
+    #[msg("Pool config changes must be scheduled while amm config updates are timelocked")]
+    PoolConfigUpdateTimelocked,
+
+    /// pool open time errors
+    #[msg("The pool open time is too far in the future")]
+    InvalidOpenTime,
+
+    /// pool fee override errors
+    #[msg("The fee overrides exceed the fee rate denominator")]
+    InvalidFeeOverride,
+
+    /// pool config update errors
+    #[msg("The pool config change does not match the scheduled update")]
+    PoolConfigUpdateMismatch,
+
+    /// swap option errors
+    #[msg("The referral fee rate and the referrer token account must be given together")]
+    InvalidReferral,
+
+    /// support mint migration errors
+    #[msg("The mint was neither whitelisted nor a Superstate mint")]
+    NotLegacySupportMint,
 }
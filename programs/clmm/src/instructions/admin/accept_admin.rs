@@ -0,0 +1,28 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The pending admin
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.accept_admin(ctx.accounts.pending_admin.key())?;
+
+    emit!(AdminChangeEvent {
+        admin: global_config.admin,
+        pending_admin: global_config.pending_admin,
+    });
+    Ok(())
+}
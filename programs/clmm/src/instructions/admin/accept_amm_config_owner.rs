@@ -0,0 +1,32 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAmmConfigOwner<'info> {
+    /// The proposed protocol owner or fund owner
+    pub pending_owner: Signer<'info>,
+
+    /// Amm config account to be changed
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_PENDING_OWNER_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+        ],
+        bump = amm_config_pending_owner.bump,
+    )]
+    pub amm_config_pending_owner: Account<'info, AmmConfigPendingOwner>,
+}
+
+pub fn accept_amm_config_owner(ctx: Context<AcceptAmmConfigOwner>) -> Result<()> {
+    let amm_config = &mut ctx.accounts.amm_config;
+    ctx.accounts
+        .amm_config_pending_owner
+        .accept(amm_config, ctx.accounts.pending_owner.key())?;
+
+    emit!(ConfigChangeEvent::new(amm_config));
+    Ok(())
+}
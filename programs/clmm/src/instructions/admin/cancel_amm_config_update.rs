@@ -0,0 +1,45 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAmmConfigUpdate<'info> {
+    /// The admin, receives the rent of the pending update
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The amm config the update is scheduled for
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[amm_config_pending_update.param],
+        ],
+        bump = amm_config_pending_update.bump,
+        close = admin
+    )]
+    pub amm_config_pending_update: Account<'info, AmmConfigPendingUpdate>,
+}
+
+pub fn cancel_amm_config_update(ctx: Context<CancelAmmConfigUpdate>) -> Result<()> {
+    let amm_config_pending_update = &ctx.accounts.amm_config_pending_update;
+    emit!(ConfigChangeEvent {
+        scheduled_param: amm_config_pending_update.param,
+        scheduled_value: amm_config_pending_update.value,
+        execute_after: amm_config_pending_update.execute_after,
+        canceled: true,
+        ..ConfigChangeEvent::new(&ctx.accounts.amm_config)
+    });
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelPoolConfigUpdate<'info> {
+    /// The admin, or an operation owner for the fee overrides, receives the rent of the
+    /// pending update
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// load info from the account to judge the operation permission
+    #[account(
+        seeds = [
+            OPERATION_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub operation_state: AccountLoader<'info, OperationState>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            pool_config_pending_update.pool_state.as_ref(),
+            &[pool_config_pending_update.kind],
+        ],
+        bump = pool_config_pending_update.bump,
+        close = authority
+    )]
+    pub pool_config_pending_update: Account<'info, PoolConfigPendingUpdate>,
+}
+
+pub fn cancel_pool_config_update(ctx: Context<CancelPoolConfigUpdate>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let pool_config_pending_update = &ctx.accounts.pool_config_pending_update;
+    // the operation owners can schedule the fee overrides but not the migrations
+    require!(
+        ctx.accounts.global_config.is_admin(authority)
+            || (pool_config_pending_update.kind == PoolConfigUpdateKind::FeeOverrides as u8
+                && ctx
+                    .accounts
+                    .operation_state
+                    .load()?
+                    .validate_operation_owner(authority)),
+        ErrorCode::NotApproved
+    );
+
+    emit!(PoolConfigPendingUpdateEvent {
+        pool_state: pool_config_pending_update.pool_state,
+        kind: pool_config_pending_update.kind,
+        trade_fee_rate: pool_config_pending_update.trade_fee_rate,
+        protocol_fee_rate: pool_config_pending_update.protocol_fee_rate,
+        fund_fee_rate: pool_config_pending_update.fund_fee_rate,
+        new_amm_config: pool_config_pending_update.new_amm_config,
+        execute_after: pool_config_pending_update.execute_after,
+        canceled: true,
+    });
+    Ok(())
+}
@@ -7,10 +7,19 @@ pub struct CloseProtocolPosition<'info> {
 
     #[account(
         mut,
-        address = crate::admin::ID @ ErrorCode::NotApproved
+        address = global_config.admin @ ErrorCode::NotApproved
     )]
     pub admin: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut, 
         close = admin
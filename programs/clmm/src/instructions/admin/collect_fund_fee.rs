@@ -8,15 +8,24 @@ use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 #[derive(Accounts)]
 pub struct CollectFundFee<'info> {
     /// Only admin or fund_owner can collect fee now
-    #[account(constraint = (owner.key() == amm_config.fund_owner || owner.key() == crate::admin::ID) @ ErrorCode::NotApproved)]
+    #[account(constraint = (owner.key() == amm_config.fund_owner || global_config.is_admin(owner.key())) @ ErrorCode::NotApproved)]
     pub owner: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     /// Pool state stores accumulated protocol fee amount
     #[account(mut)]
     pub pool_state: AccountLoader<'info, PoolState>,
 
     /// Amm config account stores fund_owner
-    #[account(address = pool_state.load()?.amm_config)]
+    #[account(address = pool_state.load()?.get_amm_config())]
     pub amm_config: Account<'info, AmmConfig>,
 
     /// The address that holds pool tokens for token_0
@@ -69,6 +78,7 @@ pub fn collect_fund_fee(
     let amount_1: u64;
     {
         let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        pool_state.require_no_flash_loan()?;
         amount_0 = amount_0_requested.min(pool_state.fund_fees_token_0);
         amount_1 = amount_1_requested.min(pool_state.fund_fees_token_1);
 
@@ -83,6 +93,7 @@ pub fn collect_fund_fee(
         &ctx.accounts.token_program,
         Some(ctx.accounts.token_program_2022.to_account_info()),
         amount_0,
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_pool_vault_to_user(
@@ -93,6 +104,7 @@ pub fn collect_fund_fee(
         &ctx.accounts.token_program,
         Some(ctx.accounts.token_program_2022.to_account_info()),
         amount_1,
+        ctx.remaining_accounts,
     )?;
 
     check_unclaimed_fees_and_vault(
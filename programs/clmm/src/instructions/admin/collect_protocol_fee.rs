@@ -9,16 +9,25 @@ use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 #[derive(Accounts)]
 pub struct CollectProtocolFee<'info> {
     /// Only admin or config owner can collect fee now
-    #[account(constraint = (owner.key() == amm_config.owner || owner.key() == crate::admin::ID) @ ErrorCode::NotApproved)]
+    #[account(constraint = (owner.key() == amm_config.owner || global_config.is_admin(owner.key())) @ ErrorCode::NotApproved)]
     pub owner: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     /// Pool state stores accumulated protocol fee amount
     #[account(mut)]
     pub pool_state: AccountLoader<'info, PoolState>,
 
     /// Amm config account stores owner
     #[account(
-        address = pool_state.load()?.amm_config
+        address = pool_state.load()?.get_amm_config()
     )]
     pub amm_config: Account<'info, AmmConfig>,
 
@@ -72,6 +81,7 @@ pub fn collect_protocol_fee(
     let amount_1: u64;
     {
         let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        pool_state.require_no_flash_loan()?;
 
         amount_0 = amount_0_requested.min(pool_state.protocol_fees_token_0);
         amount_1 = amount_1_requested.min(pool_state.protocol_fees_token_1);
@@ -93,6 +103,7 @@ pub fn collect_protocol_fee(
         &ctx.accounts.token_program,
         Some(ctx.accounts.token_program_2022.to_account_info()),
         amount_0,
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_pool_vault_to_user(
@@ -103,6 +114,7 @@ pub fn collect_protocol_fee(
         &ctx.accounts.token_program,
         Some(ctx.accounts.token_program_2022.to_account_info()),
         amount_1,
+        ctx.remaining_accounts,
     )?;
 
     check_unclaimed_fees_and_vault(
@@ -9,10 +9,19 @@ pub struct CreateAmmConfig<'info> {
     /// Address to be set as protocol owner.
     #[account(
         mut,
-        address = crate::admin::ID @ ErrorCode::NotApproved
+        address = global_config.admin @ ErrorCode::NotApproved
     )]
     pub owner: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     /// Initialize config state account to store protocol owner address and fee rates.
     #[account(
         init,
@@ -47,15 +56,7 @@ pub fn create_amm_config(
     amm_config.fund_fee_rate = fund_fee_rate;
     amm_config.fund_owner = ctx.accounts.owner.key();
 
-    emit!(ConfigChangeEvent {
-        index: amm_config.index,
-        owner: ctx.accounts.owner.key(),
-        protocol_fee_rate: amm_config.protocol_fee_rate,
-        trade_fee_rate: amm_config.trade_fee_rate,
-        tick_spacing: amm_config.tick_spacing,
-        fund_fee_rate: amm_config.fund_fee_rate,
-        fund_owner: amm_config.fund_owner,
-    });
+    emit!(ConfigChangeEvent::new(amm_config));
 
     Ok(())
 }
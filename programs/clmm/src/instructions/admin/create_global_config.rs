@@ -0,0 +1,38 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateGlobalConfig<'info> {
+    /// The deployment admin, becomes the first admin of the global config
+    #[account(
+        mut,
+        address = crate::admin::ID @ ErrorCode::NotApproved
+    )]
+    pub owner: Signer<'info>,
+
+    /// Initialize global config account to store the admin.
+    #[account(
+        init,
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump,
+        payer = owner,
+        space = GlobalConfig::LEN
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_global_config(ctx: Context<CreateGlobalConfig>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.initialize(ctx.bumps.global_config, ctx.accounts.owner.key());
+
+    emit!(AdminChangeEvent {
+        admin: global_config.admin,
+        pending_admin: global_config.pending_admin,
+    });
+    Ok(())
+}
@@ -14,9 +14,18 @@ pub struct CreateSupportMintAssociated<'info> {
     /// Address to be set as protocol owner.
     #[account(
         mut,
-        constraint = (owner.key() == crate::admin::ID || owner.key() == crate::create_support_mint_associated_owner::ID) @ ErrorCode::NotApproved
+        constraint = (global_config.is_admin(owner.key()) || owner.key() == crate::create_support_mint_associated_owner::ID) @ ErrorCode::NotApproved
     )]
     pub owner: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
     /// Support token mint
     #[account(
         owner = token_2022::ID @ ErrorCode::NotApproved
@@ -38,10 +47,18 @@ pub struct CreateSupportMintAssociated<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_support_mint_associated(ctx: Context<CreateSupportMintAssociated>) -> Result<()> {
+pub fn create_support_mint_associated(
+    ctx: Context<CreateSupportMintAssociated>,
+    allowed_extensions: u64,
+    vault_immutable_owner: bool,
+) -> Result<()> {
+    // zero is kept for the accounts created before the policy
+    require!(allowed_extensions != 0, ErrorCode::InvalidExtensionPolicy);
     let support_mint_state = ctx.accounts.support_mint_associated.deref_mut();
-    support_mint_state.bump = ctx.bumps.support_mint_associated;
-    support_mint_state.mint = ctx.accounts.token_mint.key();
-
-    Ok(())
+    support_mint_state.initialize(
+        ctx.bumps.support_mint_associated,
+        ctx.accounts.token_mint.key(),
+        allowed_extensions,
+        vault_immutable_owner,
+    )
 }
@@ -0,0 +1,55 @@
+use super::apply_amm_config_update;
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteAmmConfigUpdate<'info> {
+    /// The admin, receives the rent of the pending update
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Amm config account to be changed
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[amm_config_pending_update.param],
+        ],
+        bump = amm_config_pending_update.bump,
+        close = admin
+    )]
+    pub amm_config_pending_update: Account<'info, AmmConfigPendingUpdate>,
+}
+
+pub fn execute_amm_config_update(ctx: Context<ExecuteAmmConfigUpdate>) -> Result<()> {
+    let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+    let amm_config_pending_update = &ctx.accounts.amm_config_pending_update;
+    require_gte!(
+        block_timestamp,
+        amm_config_pending_update.execute_after,
+        ErrorCode::ConfigUpdateNotReady
+    );
+
+    let amm_config = &mut ctx.accounts.amm_config;
+    apply_amm_config_update(
+        amm_config,
+        amm_config_pending_update.param,
+        amm_config_pending_update.value,
+    )?;
+
+    emit!(ConfigChangeEvent::new(amm_config));
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use crate::decrease_liquidity::check_unclaimed_fees_and_vault;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::util::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct MigratePoolConfig<'info> {
+    /// Only admin can migrate a pool, receives the rent of the scheduled migration
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub owner: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// The pool to migrate, its address does not change
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The config the pool uses now, its owners receive the accrued fees
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub old_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The config the pool uses after the migration
+    pub new_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The address that holds pool tokens for token_0
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token_0 account of the old config owner which receives the protocol fees
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint,
+        constraint = protocol_fee_recipient_0.owner == old_amm_config.owner @ ErrorCode::NotApproved
+    )]
+    pub protocol_fee_recipient_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token_1 account of the old config owner which receives the protocol fees
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint,
+        constraint = protocol_fee_recipient_1.owner == old_amm_config.owner @ ErrorCode::NotApproved
+    )]
+    pub protocol_fee_recipient_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token_0 account of the old config fund owner which receives the fund fees
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint,
+        constraint = fund_fee_recipient_0.owner == old_amm_config.fund_owner @ ErrorCode::NotApproved
+    )]
+    pub fund_fee_recipient_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token_1 account of the old config fund owner which receives the fund fees
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint,
+        constraint = fund_fee_recipient_1.owner == old_amm_config.fund_owner @ ErrorCode::NotApproved
+    )]
+    pub fund_fee_recipient_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The SPL program to perform token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// The SPL program 2022 to perform token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The migration scheduled by `schedule_pool_config_migration`, required while amm config
+    /// updates are timelocked
+    #[account(
+        mut,
+        seeds = [
+            POOL_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            &[PoolConfigUpdateKind::Migration as u8],
+        ],
+        bump = pool_config_pending_update.bump,
+        close = owner
+    )]
+    pub pool_config_pending_update: Option<Box<Account<'info, PoolConfigPendingUpdate>>>,
+}
+
+pub fn migrate_pool_config(ctx: Context<MigratePoolConfig>) -> Result<()> {
+    let old_amm_config = ctx.accounts.old_amm_config.key();
+    let new_amm_config = ctx.accounts.new_amm_config.key();
+    // a migration changes the pool fees like an amm config update, so it follows its timelock
+    match &ctx.accounts.pool_config_pending_update {
+        Some(pool_config_pending_update) => {
+            let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+            pool_config_pending_update.check_migration(new_amm_config, block_timestamp)?;
+        }
+        None => require!(
+            ctx.accounts.global_config.config_update_delay == 0,
+            ErrorCode::PoolConfigUpdateTimelocked
+        ),
+    }
+    let (protocol_fees_token_0, protocol_fees_token_1, fund_fees_token_0, fund_fees_token_1) = {
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        pool_state.require_no_flash_loan()?;
+        // the tick spacing is part of the tick arrays and positions, it can not change
+        require!(
+            new_amm_config != old_amm_config
+                && ctx.accounts.new_amm_config.tick_spacing == pool_state.tick_spacing,
+            ErrorCode::InvalidMigrateConfig
+        );
+
+        // the fees accrued so far belong to the owners of the old config
+        let fees = (
+            pool_state.protocol_fees_token_0,
+            pool_state.protocol_fees_token_1,
+            pool_state.fund_fees_token_0,
+            pool_state.fund_fees_token_1,
+        );
+        pool_state.protocol_fees_token_0 = 0;
+        pool_state.protocol_fees_token_1 = 0;
+        pool_state.fund_fees_token_0 = 0;
+        pool_state.fund_fees_token_1 = 0;
+
+        pool_state.set_amm_config_status(ctx.accounts.new_amm_config.status);
+        pool_state.effective_amm_config = if new_amm_config == pool_state.amm_config {
+            Pubkey::default()
+        } else {
+            new_amm_config
+        };
+        fees
+    };
+
+    let token_program_2022 = Some(ctx.accounts.token_program_2022.to_account_info());
+    for (vault, recipient, mint, amount) in [
+        (
+            &ctx.accounts.token_vault_0,
+            &ctx.accounts.protocol_fee_recipient_0,
+            &ctx.accounts.vault_0_mint,
+            protocol_fees_token_0,
+        ),
+        (
+            &ctx.accounts.token_vault_1,
+            &ctx.accounts.protocol_fee_recipient_1,
+            &ctx.accounts.vault_1_mint,
+            protocol_fees_token_1,
+        ),
+        (
+            &ctx.accounts.token_vault_0,
+            &ctx.accounts.fund_fee_recipient_0,
+            &ctx.accounts.vault_0_mint,
+            fund_fees_token_0,
+        ),
+        (
+            &ctx.accounts.token_vault_1,
+            &ctx.accounts.fund_fee_recipient_1,
+            &ctx.accounts.vault_1_mint,
+            fund_fees_token_1,
+        ),
+    ] {
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.pool_state,
+            &vault.to_account_info(),
+            &recipient.to_account_info(),
+            Some(mint.clone()),
+            &ctx.accounts.token_program,
+            token_program_2022.clone(),
+            amount,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    check_unclaimed_fees_and_vault(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.token_vault_1.to_account_info(),
+    )?;
+
+    emit!(MigratePoolConfigEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        old_amm_config,
+        new_amm_config,
+        protocol_fees_token_0,
+        protocol_fees_token_1,
+        fund_fees_token_0,
+        fund_fees_token_1,
+    });
+
+    Ok(())
+}
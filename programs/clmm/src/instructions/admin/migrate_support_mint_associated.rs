@@ -0,0 +1,134 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::util::DEFAULT_ALLOWED_EXTENSIONS;
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{token_2022, token_interface::Mint};
+use spl_token_2022::{
+    extension::{
+        default_account_state::DefaultAccountState, permanent_delegate::get_permanent_delegate,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::AccountState,
+};
+
+/// The mints which were supported regardless of their extensions before the
+/// `SupportMintAssociated` policy replaced the hard-coded whitelist
+pub const LEGACY_MINT_WHITELIST: [Pubkey; 6] = [
+    pubkey!("HVbpJAQGNpkgBaYBZQBR1t7yFdvaYVp2vCQQfKKEN4tM"),
+    pubkey!("Crn4x1Y2HUKko7ox2EZMT6N2t2ZyH7eKtwkBGVnhEq1g"),
+    pubkey!("FrBfWJ4qE5sCzKm3k3JaAtqZcXUh4LvJygDeketsrsH4"),
+    pubkey!("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo"),
+    pubkey!("DAUcJBg4jSpVoEzASxYzdqHMUN8vuTpQyG2TvDcCHfZg"),
+    pubkey!("AUSD1jCcCyPLybk1YnvPWsHQSrZ46dxwoMniN4N2UEB9"),
+];
+
+pub mod superstate_allowlist {
+    use super::{pubkey, Pubkey};
+    pub const ID: Pubkey = pubkey!("3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2");
+}
+
+#[derive(Accounts)]
+pub struct MigrateSupportMintAssociated<'info> {
+    /// The admin or the support mint owner
+    #[account(
+        mut,
+        constraint = (global_config.is_admin(owner.key()) || owner.key() == crate::create_support_mint_associated_owner::ID) @ ErrorCode::NotApproved
+    )]
+    pub owner: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The formerly whitelisted or Superstate mint
+    #[account(
+        owner = token_2022::ID @ ErrorCode::NotApproved
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The support mint state account of the mint, created if it does not exist yet
+    #[account(
+        init_if_needed,
+        seeds = [
+            SUPPORT_MINT_SEED.as_bytes(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        space = SupportMintAssociated::LEN
+    )]
+    pub support_mint_associated: Account<'info, SupportMintAssociated>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Carries the support the hard-coded rules gave a mint over to its `SupportMintAssociated`
+/// account: the whitelisted mints tolerate their own extensions and the pool vaults of the
+/// Superstate mints are created with the ImmutableOwner extension
+pub fn migrate_support_mint_associated(ctx: Context<MigrateSupportMintAssociated>) -> Result<()> {
+    let token_mint = &ctx.accounts.token_mint;
+    let whitelisted = LEGACY_MINT_WHITELIST.contains(&token_mint.key());
+    let superstate = is_superstate_token(token_mint)?;
+    require!(whitelisted || superstate, ErrorCode::NotLegacySupportMint);
+
+    let support_mint_associated = &mut ctx.accounts.support_mint_associated;
+    if support_mint_associated.mint == Pubkey::default() {
+        let default_allowed_extensions = DEFAULT_ALLOWED_EXTENSIONS
+            .into_iter()
+            .fold(0, |bits, e| bits | SupportMintAssociated::extension_bit(e));
+        support_mint_associated.initialize(
+            ctx.bumps.support_mint_associated,
+            token_mint.key(),
+            default_allowed_extensions,
+            false,
+        )?;
+    }
+    // zero already tolerates every extension
+    if whitelisted && support_mint_associated.allowed_extensions != 0 {
+        let mint_info = token_mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        for e in mint.get_extension_types()? {
+            support_mint_associated.allowed_extensions |= SupportMintAssociated::extension_bit(e);
+        }
+    }
+    if superstate {
+        support_mint_associated.vault_immutable_owner = true;
+    }
+    #[cfg(feature = "enable-log")]
+    msg!(
+        "mint:{}, allowed_extensions:{}, vault_immutable_owner:{}",
+        support_mint_associated.mint,
+        support_mint_associated.allowed_extensions,
+        support_mint_associated.vault_immutable_owner
+    );
+    Ok(())
+}
+
+/// The Superstate mints are frozen by default and share the allowlist as freeze authority
+/// and permanent delegate
+pub fn is_superstate_token(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
+    let COption::Some(freeze_authority) = mint_account.freeze_authority else {
+        return Ok(false);
+    };
+    let mint_account_info = mint_account.to_account_info();
+    let mint_data = mint_account_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let default_account_state_freeze =
+        mint_state
+            .get_extension::<DefaultAccountState>()
+            .is_ok_and(|default_account_state| {
+                default_account_state.state == (AccountState::Frozen as u8)
+            });
+    let permanent_delegate = get_permanent_delegate(&mint_state) == Some(superstate_allowlist::ID);
+
+    Ok(superstate_allowlist::ID == freeze_authority
+        && *mint_account_info.owner == spl_token_2022::ID
+        && default_account_state_freeze
+        && permanent_delegate)
+}
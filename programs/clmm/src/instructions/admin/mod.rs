@@ -1,9 +1,36 @@
+pub mod create_global_config;
+pub use create_global_config::*;
+
+pub mod propose_admin;
+pub use propose_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
 pub mod create_amm_config;
 pub use create_amm_config::*;
 
 pub mod update_amm_config;
 pub use update_amm_config::*;
 
+pub mod set_config_update_delay;
+pub use set_config_update_delay::*;
+
+pub mod schedule_amm_config_update;
+pub use schedule_amm_config_update::*;
+
+pub mod execute_amm_config_update;
+pub use execute_amm_config_update::*;
+
+pub mod cancel_amm_config_update;
+pub use cancel_amm_config_update::*;
+
+pub mod propose_amm_config_owner;
+pub use propose_amm_config_owner::*;
+
+pub mod accept_amm_config_owner;
+pub use accept_amm_config_owner::*;
+
 pub mod collect_protocol_fee;
 pub use collect_protocol_fee::*;
 
@@ -22,8 +49,44 @@ pub use transfer_reward_owner::*;
 pub mod update_pool_status;
 pub use update_pool_status::*;
 
+pub mod update_amm_config_status;
+pub use update_amm_config_status::*;
+
+pub mod set_pause_guardian;
+pub use set_pause_guardian::*;
+
+pub mod pause_pool;
+pub use pause_pool::*;
+
+pub mod pause_amm_config;
+pub use pause_amm_config::*;
+
+pub mod set_pool_fee_overrides;
+pub use set_pool_fee_overrides::*;
+
+pub mod set_twap_guard;
+pub use set_twap_guard::*;
+
+pub mod migrate_pool_config;
+pub use migrate_pool_config::*;
+
+pub mod schedule_pool_fee_overrides;
+pub use schedule_pool_fee_overrides::*;
+
+pub mod schedule_pool_config_migration;
+pub use schedule_pool_config_migration::*;
+
+pub mod cancel_pool_config_update;
+pub use cancel_pool_config_update::*;
+
 pub mod create_support_mint_associated;
 pub use create_support_mint_associated::*;
 
+pub mod update_support_mint_associated;
+pub use update_support_mint_associated::*;
+
+pub mod migrate_support_mint_associated;
+pub use migrate_support_mint_associated::*;
+
 pub mod close_protocol_position;
 pub use close_protocol_position::*;
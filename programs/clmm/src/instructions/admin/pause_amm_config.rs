@@ -0,0 +1,65 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PauseAmmConfig<'info> {
+    /// The admin or the pause guardian
+    #[account(
+        constraint = global_config.is_pause_authority(authority.key()) @ ErrorCode::NotApproved
+    )]
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin and the pause guardian
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Amm config account whose pools are paused
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+}
+
+/// Disables the operations of the set bits for every pool of the config, the other bits are kept.
+/// Swaps and flash loans read the status from the config itself, the pools of the config passed
+/// as writable remaining accounts also cache it for the instructions without the config
+pub fn pause_amm_config<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, PauseAmmConfig<'info>>,
+    status: u8,
+) -> Result<()> {
+    let amm_config = &mut ctx.accounts.amm_config;
+    amm_config.status |= status;
+
+    cache_amm_config_status(amm_config.key(), amm_config.status, ctx.remaining_accounts)?;
+    emit!(ConfigChangeEvent::new(amm_config));
+    Ok(())
+}
+
+/// Caches the amm config status on the pools of the config, the pool status is left unchanged
+/// so resuming the config does not resume a pool paused on its own
+pub fn cache_amm_config_status<'info>(
+    amm_config: Pubkey,
+    amm_config_status: u8,
+    pools: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    for account_info in pools {
+        let pool_loader = AccountLoader::<PoolState>::try_from(account_info)?;
+        let mut pool_state = pool_loader.load_mut()?;
+        require_keys_eq!(
+            pool_state.get_amm_config(),
+            amm_config,
+            ErrorCode::NotApproved
+        );
+        pool_state.set_amm_config_status(amm_config_status);
+
+        emit!(PoolStatusChangeEvent {
+            pool_state: account_info.key(),
+            status: pool_state.get_effective_status(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PausePool<'info> {
+    /// The admin or the pause guardian
+    #[account(
+        constraint = global_config.is_pause_authority(authority.key()) @ ErrorCode::NotApproved
+    )]
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin and the pause guardian
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+/// Disables the operations of the set bits, the other bits are kept
+pub fn pause_pool(ctx: Context<PausePool>, status: u8) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    let status = pool_state.status | status;
+    pool_state.set_status(status);
+
+    emit!(PoolStatusChangeEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        status,
+    });
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// The current admin
+    #[account(address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.propose_admin(new_admin);
+
+    emit!(AdminChangeEvent {
+        admin: global_config.admin,
+        pending_admin: global_config.pending_admin,
+    });
+    Ok(())
+}
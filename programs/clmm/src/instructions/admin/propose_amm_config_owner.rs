@@ -0,0 +1,60 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAmmConfigOwner<'info> {
+    /// The admin
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The amm config whose owners are proposed
+    pub amm_config: Account<'info, AmmConfig>,
+
+    /// Holds the proposed owners until they accept
+    #[account(
+        init_if_needed,
+        seeds = [
+            AMM_CONFIG_PENDING_OWNER_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = AmmConfigPendingOwner::LEN
+    )]
+    pub amm_config_pending_owner: Account<'info, AmmConfigPendingOwner>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_amm_config_owner(
+    ctx: Context<ProposeAmmConfigOwner>,
+    new_owner: Option<Pubkey>,
+    new_fund_owner: Option<Pubkey>,
+) -> Result<()> {
+    let amm_config_pending_owner = &mut ctx.accounts.amm_config_pending_owner;
+    amm_config_pending_owner.bump = ctx.bumps.amm_config_pending_owner;
+    amm_config_pending_owner.amm_config = ctx.accounts.amm_config.key();
+    if let Some(new_owner) = new_owner {
+        amm_config_pending_owner.pending_owner = new_owner;
+    }
+    if let Some(new_fund_owner) = new_fund_owner {
+        amm_config_pending_owner.pending_fund_owner = new_fund_owner;
+    }
+    #[cfg(feature = "enable-log")]
+    msg!(
+        "amm_config:{}, pending_owner:{}, pending_fund_owner:{}",
+        amm_config_pending_owner.amm_config,
+        amm_config_pending_owner.pending_owner,
+        amm_config_pending_owner.pending_fund_owner
+    );
+    Ok(())
+}
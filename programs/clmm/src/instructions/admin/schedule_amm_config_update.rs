@@ -0,0 +1,67 @@
+use super::apply_amm_config_update;
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(param: u8)]
+pub struct ScheduleAmmConfigUpdate<'info> {
+    /// The admin
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The amm config the update is scheduled for
+    pub amm_config: Account<'info, AmmConfig>,
+
+    /// Holds the update until it is executed or canceled, an update of the same param
+    /// must be canceled before scheduling another one
+    #[account(
+        init,
+        seeds = [
+            AMM_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[param],
+        ],
+        bump,
+        payer = admin,
+        space = AmmConfigPendingUpdate::LEN
+    )]
+    pub amm_config_pending_update: Account<'info, AmmConfigPendingUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn schedule_amm_config_update(
+    ctx: Context<ScheduleAmmConfigUpdate>,
+    param: u8,
+    value: u32,
+) -> Result<()> {
+    let amm_config = &ctx.accounts.amm_config;
+    // reject the invalid updates now, they are checked again at execution
+    apply_amm_config_update(&mut (**amm_config).clone(), param, value)?;
+
+    let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+    let amm_config_pending_update = &mut ctx.accounts.amm_config_pending_update;
+    amm_config_pending_update.bump = ctx.bumps.amm_config_pending_update;
+    amm_config_pending_update.amm_config = amm_config.key();
+    amm_config_pending_update.param = param;
+    amm_config_pending_update.value = value;
+    amm_config_pending_update.execute_after =
+        block_timestamp.saturating_add(ctx.accounts.global_config.config_update_delay);
+
+    emit!(ConfigChangeEvent {
+        scheduled_param: param,
+        scheduled_value: value,
+        execute_after: amm_config_pending_update.execute_after,
+        ..ConfigChangeEvent::new(amm_config)
+    });
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SchedulePoolConfigMigration<'info> {
+    /// The admin
+    #[account(mut, address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The config the pool uses now
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub old_amm_config: Account<'info, AmmConfig>,
+
+    /// The config the pool uses after the migration
+    pub new_amm_config: Account<'info, AmmConfig>,
+
+    /// Holds the migration until `migrate_pool_config` or `cancel_pool_config_update`,
+    /// a scheduled migration must be canceled before scheduling another one
+    #[account(
+        init,
+        seeds = [
+            POOL_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            &[PoolConfigUpdateKind::Migration as u8],
+        ],
+        bump,
+        payer = admin,
+        space = PoolConfigPendingUpdate::LEN
+    )]
+    pub pool_config_pending_update: Account<'info, PoolConfigPendingUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn schedule_pool_config_migration(ctx: Context<SchedulePoolConfigMigration>) -> Result<()> {
+    let new_amm_config = ctx.accounts.new_amm_config.key();
+    // reject the invalid migrations now, they are checked again at execution
+    require!(
+        new_amm_config != ctx.accounts.old_amm_config.key()
+            && ctx.accounts.new_amm_config.tick_spacing
+                == ctx.accounts.pool_state.load()?.tick_spacing,
+        ErrorCode::InvalidMigrateConfig
+    );
+
+    let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+    let pool_config_pending_update = &mut ctx.accounts.pool_config_pending_update;
+    pool_config_pending_update.bump = ctx.bumps.pool_config_pending_update;
+    pool_config_pending_update.pool_state = ctx.accounts.pool_state.key();
+    pool_config_pending_update.kind = PoolConfigUpdateKind::Migration as u8;
+    pool_config_pending_update.new_amm_config = new_amm_config;
+    pool_config_pending_update.execute_after =
+        block_timestamp.saturating_add(ctx.accounts.global_config.config_update_delay);
+
+    emit!(PoolConfigPendingUpdateEvent {
+        pool_state: pool_config_pending_update.pool_state,
+        kind: pool_config_pending_update.kind,
+        trade_fee_rate: None,
+        protocol_fee_rate: None,
+        fund_fee_rate: None,
+        new_amm_config,
+        execute_after: pool_config_pending_update.execute_after,
+        canceled: false,
+    });
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use super::check_pool_fee_overrides;
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SchedulePoolFeeOverrides<'info> {
+    /// The admin or an operation owner
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The amm config the overridden rates fall back to
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// load info from the account to judge the operation permission
+    #[account(
+        seeds = [
+            OPERATION_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub operation_state: AccountLoader<'info, OperationState>,
+
+    /// Holds the overrides until `set_pool_fee_overrides` or `cancel_pool_config_update`,
+    /// a scheduled change must be canceled before scheduling another one
+    #[account(
+        init,
+        seeds = [
+            POOL_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            &[PoolConfigUpdateKind::FeeOverrides as u8],
+        ],
+        bump,
+        payer = authority,
+        space = PoolConfigPendingUpdate::LEN
+    )]
+    pub pool_config_pending_update: Account<'info, PoolConfigPendingUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn schedule_pool_fee_overrides(
+    ctx: Context<SchedulePoolFeeOverrides>,
+    trade_fee_rate: Option<u32>,
+    protocol_fee_rate: Option<u32>,
+    fund_fee_rate: Option<u32>,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.global_config.is_admin(authority)
+            || ctx
+                .accounts
+                .operation_state
+                .load()?
+                .validate_operation_owner(authority),
+        ErrorCode::NotApproved
+    );
+    // reject the invalid overrides now, they are checked again at execution
+    check_pool_fee_overrides(
+        &ctx.accounts.amm_config,
+        trade_fee_rate,
+        protocol_fee_rate,
+        fund_fee_rate,
+    )?;
+
+    let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+    let pool_config_pending_update = &mut ctx.accounts.pool_config_pending_update;
+    pool_config_pending_update.bump = ctx.bumps.pool_config_pending_update;
+    pool_config_pending_update.pool_state = ctx.accounts.pool_state.key();
+    pool_config_pending_update.kind = PoolConfigUpdateKind::FeeOverrides as u8;
+    pool_config_pending_update.trade_fee_rate = trade_fee_rate;
+    pool_config_pending_update.protocol_fee_rate = protocol_fee_rate;
+    pool_config_pending_update.fund_fee_rate = fund_fee_rate;
+    pool_config_pending_update.execute_after =
+        block_timestamp.saturating_add(ctx.accounts.global_config.config_update_delay);
+
+    emit!(PoolConfigPendingUpdateEvent {
+        pool_state: pool_config_pending_update.pool_state,
+        kind: pool_config_pending_update.kind,
+        trade_fee_rate,
+        protocol_fee_rate,
+        fund_fee_rate,
+        new_amm_config: Pubkey::default(),
+        execute_after: pool_config_pending_update.execute_after,
+        canceled: false,
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetConfigUpdateDelay<'info> {
+    /// The admin
+    #[account(address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn set_config_update_delay(ctx: Context<SetConfigUpdateDelay>, delay: u64) -> Result<()> {
+    let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+    let global_config = &mut ctx.accounts.global_config;
+    // a lower delay only applies when it is set again after the current delay
+    global_config.set_config_update_delay(delay, block_timestamp);
+    emit!(ConfigUpdateDelayChangeEvent {
+        config_update_delay: global_config.config_update_delay,
+        pending_config_update_delay: global_config.pending_config_update_delay,
+        pending_config_update_delay_time: global_config.pending_config_update_delay_time,
+    });
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPauseGuardian<'info> {
+    /// The admin
+    #[account(address = global_config.admin @ ErrorCode::NotApproved)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn set_pause_guardian(ctx: Context<SetPauseGuardian>, pause_guardian: Pubkey) -> Result<()> {
+    ctx.accounts.global_config.pause_guardian = pause_guardian;
+    #[cfg(feature = "enable-log")]
+    msg!("pause_guardian:{}", pause_guardian);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPoolFeeOverrides<'info> {
+    /// The admin or an operation owner, receives the rent of the scheduled update
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The amm config the overridden rates fall back to
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// load info from the account to judge the operation permission
+    #[account(
+        seeds = [
+            OPERATION_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub operation_state: AccountLoader<'info, OperationState>,
+
+    /// The update scheduled by `schedule_pool_fee_overrides`, required while amm config
+    /// updates are timelocked
+    #[account(
+        mut,
+        seeds = [
+            POOL_CONFIG_PENDING_UPDATE_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            &[PoolConfigUpdateKind::FeeOverrides as u8],
+        ],
+        bump = pool_config_pending_update.bump,
+        close = authority
+    )]
+    pub pool_config_pending_update: Option<Account<'info, PoolConfigPendingUpdate>>,
+}
+
+pub fn set_pool_fee_overrides(
+    ctx: Context<SetPoolFeeOverrides>,
+    trade_fee_rate: Option<u32>,
+    protocol_fee_rate: Option<u32>,
+    fund_fee_rate: Option<u32>,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.global_config.is_admin(authority)
+            || ctx
+                .accounts
+                .operation_state
+                .load()?
+                .validate_operation_owner(authority),
+        ErrorCode::NotApproved
+    );
+    // the overrides change the pool fees like an amm config update, so they follow its timelock
+    match &ctx.accounts.pool_config_pending_update {
+        Some(pool_config_pending_update) => {
+            let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+            pool_config_pending_update.check_fee_overrides(
+                trade_fee_rate,
+                protocol_fee_rate,
+                fund_fee_rate,
+                block_timestamp,
+            )?;
+        }
+        None => require!(
+            ctx.accounts.global_config.config_update_delay == 0,
+            ErrorCode::PoolConfigUpdateTimelocked
+        ),
+    }
+    check_pool_fee_overrides(
+        &ctx.accounts.amm_config,
+        trade_fee_rate,
+        protocol_fee_rate,
+        fund_fee_rate,
+    )?;
+
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.require_no_flash_loan()?;
+    pool_state.set_fee_override(FeeOverrideBitIndex::TradeFee, trade_fee_rate);
+    pool_state.set_fee_override(FeeOverrideBitIndex::ProtocolFee, protocol_fee_rate);
+    pool_state.set_fee_override(FeeOverrideBitIndex::FundFee, fund_fee_rate);
+
+    emit!(PoolConfigChangeEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        trade_fee_rate,
+        protocol_fee_rate,
+        fund_fee_rate,
+    });
+
+    Ok(())
+}
+
+/// The rates are checked like update_amm_config does, with the amm config filling the gaps.
+/// apply_fee_overrides clamps them, so the raw overrides are checked here
+pub fn check_pool_fee_overrides(
+    amm_config: &AmmConfig,
+    trade_fee_rate: Option<u32>,
+    protocol_fee_rate: Option<u32>,
+    fund_fee_rate: Option<u32>,
+) -> Result<()> {
+    let trade_fee_rate = trade_fee_rate.unwrap_or(amm_config.trade_fee_rate);
+    let protocol_fee_rate = protocol_fee_rate.unwrap_or(amm_config.protocol_fee_rate);
+    let fund_fee_rate = fund_fee_rate.unwrap_or(amm_config.fund_fee_rate);
+    require!(
+        trade_fee_rate < FEE_RATE_DENOMINATOR_VALUE,
+        ErrorCode::InvalidFeeOverride
+    );
+    require!(
+        u64::from(protocol_fee_rate)
+            + u64::from(fund_fee_rate)
+            + u64::from(amm_config.max_referral_fee_rate)
+            <= u64::from(FEE_RATE_DENOMINATOR_VALUE),
+        ErrorCode::InvalidFeeOverride
+    );
+    Ok(())
+}
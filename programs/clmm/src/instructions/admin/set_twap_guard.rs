@@ -0,0 +1,61 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTwapGuard<'info> {
+    /// The admin or an operation owner
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// load info from the account to judge the operation permission
+    #[account(
+        seeds = [
+            OPERATION_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub operation_state: AccountLoader<'info, OperationState>,
+}
+
+pub fn set_twap_guard(
+    ctx: Context<SetTwapGuard>,
+    window: u32,
+    max_tick_deviation: u32,
+    mode: u8,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.global_config.is_admin(authority)
+            || ctx
+                .accounts
+                .operation_state
+                .load()?
+                .validate_operation_owner(authority),
+        ErrorCode::NotApproved
+    );
+
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.require_no_flash_loan()?;
+    pool_state.set_twap_guard(window, max_tick_deviation, mode)?;
+
+    emit!(TwapGuardChangeEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        window,
+        max_tick_deviation,
+        mode,
+    });
+
+    Ok(())
+}
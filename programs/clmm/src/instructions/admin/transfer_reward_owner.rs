@@ -5,10 +5,19 @@ use anchor_lang::prelude::*;
 pub struct TransferRewardOwner<'info> {
     /// Address to be set as operation account owner.
     #[account(
-        address = crate::admin::ID @ ErrorCode::NotApproved
+        address = global_config.admin @ ErrorCode::NotApproved
     )]
     pub authority: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(mut)]
     pub pool_state: AccountLoader<'info, PoolState>,
 }
@@ -4,79 +4,114 @@ use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdateAmmConfig<'info> {
-    /// The clmm config owner or admin
-    #[account(address = crate::admin::ID @ ErrorCode::NotApproved)]
+    /// The admin
+    #[account(address = global_config.admin @ ErrorCode::NotApproved)]
     pub owner: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     /// Amm config account to be changed
     #[account(mut)]
     pub amm_config: Account<'info, AmmConfig>,
 }
 
 pub fn update_amm_config(ctx: Context<UpdateAmmConfig>, param: u8, value: u32) -> Result<()> {
+    // with a delay the update must go through schedule_amm_config_update
+    require!(
+        ctx.accounts.global_config.config_update_delay == 0,
+        ErrorCode::ConfigUpdateTimelocked
+    );
     let amm_config = &mut ctx.accounts.amm_config;
+    apply_amm_config_update(amm_config, param, value)?;
+
+    emit!(ConfigChangeEvent::new(amm_config));
+
+    Ok(())
+}
+
+pub fn apply_amm_config_update(amm_config: &mut AmmConfig, param: u8, value: u32) -> Result<()> {
     let match_param = Some(param);
     match match_param {
         Some(0) => update_trade_fee_rate(amm_config, value),
         Some(1) => update_protocol_fee_rate(amm_config, value),
         Some(2) => update_fund_fee_rate(amm_config, value),
-        Some(3) => {
-            let new_owner = *ctx.remaining_accounts.iter().next().unwrap().key;
-            set_new_owner(amm_config, new_owner);
-        }
-        Some(4) => {
-            let new_fund_owner = *ctx.remaining_accounts.iter().next().unwrap().key;
-            set_new_fund_owner(amm_config, new_fund_owner);
-        }
+        // the owners are changed by propose_amm_config_owner and accept_amm_config_owner
+        Some(5) => update_dynamic_fee_min_rate(amm_config, value),
+        Some(6) => update_dynamic_fee_max_rate(amm_config, value),
+        Some(7) => update_flash_loan_fee_rate(amm_config, value),
+        Some(8) => update_max_referral_fee_rate(amm_config, value),
+        Some(9) => update_max_launch_fee_start_rate(amm_config, value),
+        Some(10) => amm_config.max_launch_fee_duration = value,
         _ => return err!(ErrorCode::InvalidUpdateConfigFlag),
     }
-
-    emit!(ConfigChangeEvent {
-        index: amm_config.index,
-        owner: amm_config.owner,
-        trade_fee_rate: amm_config.trade_fee_rate,
-        protocol_fee_rate: amm_config.protocol_fee_rate,
-        tick_spacing: amm_config.tick_spacing,
-        fund_fee_rate: amm_config.fund_fee_rate,
-        fund_owner: amm_config.fund_owner,
-    });
-
     Ok(())
 }
 
-fn update_protocol_fee_rate(amm_config: &mut Account<AmmConfig>, protocol_fee_rate: u32) {
+fn update_protocol_fee_rate(amm_config: &mut AmmConfig, protocol_fee_rate: u32) {
     assert!(protocol_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
-    assert!(protocol_fee_rate + amm_config.fund_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
+    assert!(
+        protocol_fee_rate + amm_config.fund_fee_rate + amm_config.max_referral_fee_rate
+            <= FEE_RATE_DENOMINATOR_VALUE
+    );
     amm_config.protocol_fee_rate = protocol_fee_rate;
 }
 
-fn update_trade_fee_rate(amm_config: &mut Account<AmmConfig>, trade_fee_rate: u32) {
+fn update_trade_fee_rate(amm_config: &mut AmmConfig, trade_fee_rate: u32) {
     assert!(trade_fee_rate < FEE_RATE_DENOMINATOR_VALUE);
     amm_config.trade_fee_rate = trade_fee_rate;
 }
 
-fn update_fund_fee_rate(amm_config: &mut Account<AmmConfig>, fund_fee_rate: u32) {
+fn update_fund_fee_rate(amm_config: &mut AmmConfig, fund_fee_rate: u32) {
     assert!(fund_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
-    assert!(fund_fee_rate + amm_config.protocol_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
+    assert!(
+        fund_fee_rate + amm_config.protocol_fee_rate + amm_config.max_referral_fee_rate
+            <= FEE_RATE_DENOMINATOR_VALUE
+    );
     amm_config.fund_fee_rate = fund_fee_rate;
 }
 
-fn set_new_owner(amm_config: &mut Account<AmmConfig>, new_owner: Pubkey) {
-    #[cfg(feature = "enable-log")]
-    msg!(
-        "amm_config, old_owner:{}, new_owner:{}",
-        amm_config.owner.to_string(),
-        new_owner.key().to_string()
+fn update_dynamic_fee_min_rate(amm_config: &mut AmmConfig, dynamic_fee_min_rate: u32) {
+    assert!(dynamic_fee_min_rate < FEE_RATE_DENOMINATOR_VALUE);
+    assert!(
+        amm_config.dynamic_fee_max_rate == 0
+            || dynamic_fee_min_rate <= amm_config.dynamic_fee_max_rate
     );
-    amm_config.owner = new_owner;
+    amm_config.dynamic_fee_min_rate = dynamic_fee_min_rate;
+}
+
+/// Setting the max rate to zero switches the config back to the static trade fee
+fn update_dynamic_fee_max_rate(amm_config: &mut AmmConfig, dynamic_fee_max_rate: u32) {
+    assert!(dynamic_fee_max_rate < FEE_RATE_DENOMINATOR_VALUE);
+    assert!(dynamic_fee_max_rate == 0 || dynamic_fee_max_rate >= amm_config.dynamic_fee_min_rate);
+    amm_config.dynamic_fee_max_rate = dynamic_fee_max_rate;
 }
 
-fn set_new_fund_owner(amm_config: &mut Account<AmmConfig>, new_fund_owner: Pubkey) {
-    #[cfg(feature = "enable-log")]
-    msg!(
-        "amm_config, old_fund_owner:{}, new_fund_owner:{}",
-        amm_config.fund_owner.to_string(),
-        new_fund_owner.key().to_string()
+/// Setting the rate to zero disables flash loans
+fn update_flash_loan_fee_rate(amm_config: &mut AmmConfig, flash_loan_fee_rate: u32) {
+    assert!(flash_loan_fee_rate < FEE_RATE_DENOMINATOR_VALUE);
+    amm_config.flash_loan_fee_rate = flash_loan_fee_rate;
+}
+
+/// The referral fee is split off the trade fee with the protocol and fund fees,
+/// setting the rate to zero disables referral fees
+fn update_max_referral_fee_rate(amm_config: &mut AmmConfig, max_referral_fee_rate: u32) {
+    assert!(max_referral_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
+    assert!(
+        max_referral_fee_rate + amm_config.protocol_fee_rate + amm_config.fund_fee_rate
+            <= FEE_RATE_DENOMINATOR_VALUE
     );
-    amm_config.fund_owner = new_fund_owner;
+    amm_config.max_referral_fee_rate = max_referral_fee_rate;
+}
+
+/// Only caps the pools created afterwards, setting the rate to zero disables launch fees
+fn update_max_launch_fee_start_rate(amm_config: &mut AmmConfig, max_launch_fee_start_rate: u32) {
+    assert!(max_launch_fee_start_rate < FEE_RATE_DENOMINATOR_VALUE);
+    amm_config.max_launch_fee_start_rate = max_launch_fee_start_rate;
 }
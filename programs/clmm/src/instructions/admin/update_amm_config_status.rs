@@ -0,0 +1,36 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateAmmConfigStatus<'info> {
+    /// The admin
+    #[account(address = global_config.admin @ ErrorCode::NotApproved)]
+    pub authority: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Amm config account to be changed
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+}
+
+/// The pools of the config passed as writable remaining accounts cache the new status
+pub fn update_amm_config_status<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, UpdateAmmConfigStatus<'info>>,
+    status: u8,
+) -> Result<()> {
+    let amm_config = &mut ctx.accounts.amm_config;
+    amm_config.status = status;
+
+    cache_amm_config_status(amm_config.key(), status, ctx.remaining_accounts)?;
+    emit!(ConfigChangeEvent::new(amm_config));
+    Ok(())
+}
@@ -6,10 +6,19 @@ use anchor_lang::prelude::*;
 pub struct UpdateOperationAccount<'info> {
     /// Address to be set as operation account owner.
     #[account(
-        address = crate::admin::ID @ ErrorCode::NotApproved
+        address = global_config.admin @ ErrorCode::NotApproved
     )]
     pub owner: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     /// Initialize operation state account to store operation owner address and white list mint.
     #[account(
         mut,
@@ -1,13 +1,23 @@
+use crate::error::ErrorCode;
 use crate::states::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdatePoolStatus<'info> {
     #[account(
-        address = crate::admin::ID
+        address = global_config.admin @ ErrorCode::NotApproved
     )]
     pub authority: Signer<'info>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(mut)]
     pub pool_state: AccountLoader<'info, PoolState>,
 }
@@ -16,5 +26,10 @@ pub fn update_pool_status(ctx: Context<UpdatePoolStatus>, status: u8) -> Result<
     require_gte!(255, status);
     let mut pool_state = ctx.accounts.pool_state.load_mut()?;
     pool_state.set_status(status);
+
+    emit!(PoolStatusChangeEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        status: pool_state.status,
+    });
     Ok(())
 }
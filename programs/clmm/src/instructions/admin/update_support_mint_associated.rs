@@ -0,0 +1,45 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSupportMintAssociated<'info> {
+    /// The admin or the support mint owner
+    #[account(
+        constraint = (global_config.is_admin(owner.key()) || owner.key() == crate::create_support_mint_associated_owner::ID) @ ErrorCode::NotApproved
+    )]
+    pub owner: Signer<'info>,
+
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// The support mint state account whose extension policy is updated
+    #[account(
+        mut,
+        seeds = [
+            SUPPORT_MINT_SEED.as_bytes(),
+            support_mint_associated.mint.as_ref(),
+        ],
+        bump = support_mint_associated.bump,
+    )]
+    pub support_mint_associated: Account<'info, SupportMintAssociated>,
+}
+
+pub fn update_support_mint_associated(
+    ctx: Context<UpdateSupportMintAssociated>,
+    allowed_extensions: u64,
+    vault_immutable_owner: bool,
+) -> Result<()> {
+    // zero is kept for the accounts created before the policy
+    require!(allowed_extensions != 0, ErrorCode::InvalidExtensionPolicy);
+    let support_mint_associated = &mut ctx.accounts.support_mint_associated;
+    support_mint_associated.allowed_extensions = allowed_extensions;
+    support_mint_associated.vault_immutable_owner = vault_immutable_owner;
+    Ok(())
+}
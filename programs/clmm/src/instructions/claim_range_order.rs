@@ -0,0 +1,277 @@
+use super::decrease_liquidity::{
+    check_unclaimed_fees_and_vault, claim_fees_owed, decrease_liquidity_and_update_position,
+};
+use crate::error::ErrorCode;
+use crate::libraries::{liquidity_math, tick_math};
+use crate::states::*;
+use crate::util::{get_recent_epoch, transfer_from_pool_vault_to_user};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimRangeOrder<'info> {
+    /// The position owner or delegated authority
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the tokenized position
+    #[account(
+        constraint = nft_account.mint == personal_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner,
+    )]
+    pub nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The range order to claim
+    #[account(mut, constraint = personal_position.pool_id == pool_state.key())]
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// Stores both ticks of the range order
+    #[account(mut, constraint = tick_array.load()?.pool_id == pool_state.key())]
+    pub tick_array: AccountLoader<'info, TickArrayState>,
+
+    /// Token_0 vault
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token_1 vault
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for receive amount_0
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint
+    )]
+    pub recipient_token_account_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for receive amount_1
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint
+    )]
+    pub recipient_token_account_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program to transfer out tokens
+    pub token_program: Program<'info, Token>,
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining account
+    // tick_array_bitmap_extension, required if the tick array overflows the default bitmap
+}
+
+pub fn claim_range_order<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimRangeOrder<'info>>,
+) -> Result<()> {
+    let personal_position = &mut ctx.accounts.personal_position;
+    require!(personal_position.range_order, ErrorCode::NotRangeOrder);
+    let liquidity = personal_position.liquidity;
+    let tick_lower = personal_position.tick_lower_index;
+    let tick_upper = personal_position.tick_upper_index;
+    let fill_tick = if personal_position.range_order_zero_for_one {
+        tick_upper
+    } else {
+        tick_lower
+    };
+
+    let tick_array_bitmap_extension = {
+        let pool_state = ctx.accounts.pool_state.load()?;
+        if !pool_state.get_status_by_bit(PoolStatusBitIndex::DecreaseLiquidity) {
+            return err!(ErrorCode::NotApproved);
+        }
+        if pool_state.is_overflow_default_tickarray_bitmap(vec![
+            ctx.accounts.tick_array.load()?.start_tick_index,
+        ]) {
+            require_keys_eq!(
+                ctx.remaining_accounts[0].key(),
+                TickArrayBitmapExtension::key(ctx.accounts.pool_state.key())
+            );
+            Some(&ctx.remaining_accounts[0])
+        } else {
+            None
+        }
+    };
+
+    let fill_tick_crossings = {
+        let pool_state = ctx.accounts.pool_state.load()?;
+        let tick_array = ctx.accounts.tick_array.load()?;
+        tick_array
+            .get_tick_state(fill_tick, pool_state.tick_spacing)?
+            .range_order_fill_count
+            .wrapping_sub(personal_position.range_order_fill_count)
+    };
+    let filled = fill_tick_crossings != 0;
+
+    let (amount_0, amount_1) = if filled {
+        // the order was taken out of the range when it was filled, so only the ticks still reference it
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        let mut tick_array = ctx.accounts.tick_array.load_mut()?;
+        let tick_spacing = pool_state.tick_spacing;
+        // while the fill tick has not been crossed again, the growths inside the range are still the
+        // ones of the fill and the order is credited the fees and rewards it earned until then.
+        // Once the price came back into the range they also hold what was earned without the order,
+        // which can not be told apart, so only what was settled when the order was placed is paid.
+        if fill_tick_crossings == 1 {
+            let tick_lower_state = *tick_array.get_tick_state(tick_lower, tick_spacing)?;
+            let tick_upper_state = *tick_array.get_tick_state(tick_upper, tick_spacing)?;
+            let updated_reward_infos =
+                pool_state.update_reward_infos(Clock::get()?.unix_timestamp as u64)?;
+            let (fee_growth_inside_0_x64, fee_growth_inside_1_x64) = get_fee_growth_inside(
+                &tick_lower_state,
+                &tick_upper_state,
+                pool_state.tick_current,
+                pool_state.fee_growth_global_0_x64,
+                pool_state.fee_growth_global_1_x64,
+            );
+            let reward_growths_inside = get_reward_growths_inside(
+                &tick_lower_state,
+                &tick_upper_state,
+                pool_state.tick_current,
+                &updated_reward_infos,
+            );
+            personal_position.update_fee_and_reward(
+                fee_growth_inside_0_x64,
+                fee_growth_inside_1_x64,
+                reward_growths_inside,
+                get_recent_epoch()?,
+            )?;
+        }
+        for tick_index in [tick_lower, tick_upper] {
+            let tick_state = tick_array.get_tick_state_mut(tick_index, tick_spacing)?;
+            tick_state.liquidity_gross = tick_state.liquidity_gross.checked_sub(liquidity).unwrap();
+            if tick_state.liquidity_gross == 0 {
+                tick_state.clear();
+                tick_array.update_initialized_tick_count(false)?;
+                if tick_array.initialized_tick_count == 0 {
+                    pool_state.flip_tick_array_bit(
+                        tick_array_bitmap_extension,
+                        tick_array.start_tick_index,
+                    )?;
+                }
+            }
+        }
+        personal_position.liquidity = 0;
+
+        let (fees_owed_0, fees_owed_1) =
+            if pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee) {
+                claim_fees_owed(&mut pool_state, personal_position)?
+            } else {
+                (0, 0)
+            };
+
+        let sqrt_price_lower_x64 = tick_math::get_sqrt_price_at_tick(tick_lower)?;
+        let sqrt_price_upper_x64 = tick_math::get_sqrt_price_at_tick(tick_upper)?;
+        if personal_position.range_order_zero_for_one {
+            (
+                fees_owed_0,
+                liquidity_math::get_delta_amount_1_unsigned(
+                    sqrt_price_lower_x64,
+                    sqrt_price_upper_x64,
+                    liquidity,
+                    false,
+                )?
+                .checked_add(fees_owed_1)
+                .unwrap(),
+            )
+        } else {
+            (
+                liquidity_math::get_delta_amount_0_unsigned(
+                    sqrt_price_lower_x64,
+                    sqrt_price_upper_x64,
+                    liquidity,
+                    false,
+                )?
+                .checked_add(fees_owed_0)
+                .unwrap(),
+                fees_owed_1,
+            )
+        }
+    } else {
+        // cancel the order, it is withdrawn like a normal position at the current price
+        {
+            let pool_state = ctx.accounts.pool_state.load()?;
+            let mut tick_array = ctx.accounts.tick_array.load_mut()?;
+            let fill_tick_state =
+                tick_array.get_tick_state_mut(fill_tick, pool_state.tick_spacing)?;
+            fill_tick_state.range_order_liquidity = fill_tick_state
+                .range_order_liquidity
+                .checked_sub(liquidity)
+                .unwrap();
+        }
+        let (decrease_amount_0, fees_owed_0, decrease_amount_1, fees_owed_1) =
+            decrease_liquidity_and_update_position(
+                &ctx.accounts.pool_state,
+                personal_position,
+                &ctx.accounts.tick_array,
+                &ctx.accounts.tick_array,
+                tick_array_bitmap_extension,
+                liquidity,
+            )?;
+        (
+            decrease_amount_0 + fees_owed_0,
+            decrease_amount_1 + fees_owed_1,
+        )
+    };
+
+    personal_position.range_order = false;
+    personal_position.range_order_zero_for_one = false;
+    personal_position.range_order_fill_count = 0;
+
+    let token_program_2022 = Some(ctx.accounts.token_program_2022.to_account_info());
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.recipient_token_account_0.to_account_info(),
+        Some(ctx.accounts.vault_0_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022.clone(),
+        amount_0,
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_1.to_account_info(),
+        &ctx.accounts.recipient_token_account_1.to_account_info(),
+        Some(ctx.accounts.vault_1_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022,
+        amount_1,
+        ctx.remaining_accounts,
+    )?;
+    check_unclaimed_fees_and_vault(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.token_vault_1.to_account_info(),
+    )?;
+
+    emit!(ClaimRangeOrderEvent {
+        position_nft_mint: personal_position.nft_mint,
+        filled,
+        liquidity,
+        amount_0,
+        amount_1,
+    });
+
+    Ok(())
+}
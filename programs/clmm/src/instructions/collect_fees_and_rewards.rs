@@ -0,0 +1,190 @@
+use super::decrease_liquidity::{check_unclaimed_fees_and_vault, claim_fees_owed, collect_rewards};
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::util::{get_recent_epoch, transfer_from_pool_vault_to_user};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CollectFeesAndRewards<'info> {
+    /// The position owner or delegated authority
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the tokenized position
+    #[account(
+        constraint = nft_account.mint == personal_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner,
+    )]
+    pub nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Collect fees and rewards for this position
+    #[account(mut, constraint = personal_position.pool_id == pool_state.key())]
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// Token_0 vault
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token_1 vault
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stores the lower tick of the position, only read to settle the fee growth
+    #[account(constraint = tick_array_lower.load()?.pool_id == pool_state.key())]
+    pub tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// Stores the upper tick of the position, only read to settle the fee growth
+    #[account(constraint = tick_array_upper.load()?.pool_id == pool_state.key())]
+    pub tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The destination token account for receive amount_0
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint
+    )]
+    pub recipient_token_account_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for receive amount_1
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint
+    )]
+    pub recipient_token_account_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program to transfer out tokens
+    pub token_program: Program<'info, Token>,
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining accounts, for each initialized reward
+    // reward_token_vault
+    // recipient_token_account
+    // reward_vault_mint
+    // followed by the transfer hook accounts of the vault and reward mints, if any
+}
+
+pub fn collect_fees_and_rewards<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CollectFeesAndRewards<'info>>,
+) -> Result<()> {
+    let personal_position = &mut ctx.accounts.personal_position;
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
+    let (fees_owed_0, fees_owed_1) = {
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        if !pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee)
+            && !pool_state.get_status_by_bit(PoolStatusBitIndex::CollectReward)
+        {
+            return err!(ErrorCode::NotApproved);
+        }
+        let tick_lower_state = *ctx
+            .accounts
+            .tick_array_lower
+            .load()?
+            .get_tick_state(personal_position.tick_lower_index, pool_state.tick_spacing)?;
+        let tick_upper_state = *ctx
+            .accounts
+            .tick_array_upper
+            .load()?
+            .get_tick_state(personal_position.tick_upper_index, pool_state.tick_spacing)?;
+
+        let updated_reward_infos =
+            pool_state.update_reward_infos(Clock::get()?.unix_timestamp as u64)?;
+        let (fee_growth_inside_0_x64, fee_growth_inside_1_x64) = get_fee_growth_inside(
+            &tick_lower_state,
+            &tick_upper_state,
+            pool_state.tick_current,
+            pool_state.fee_growth_global_0_x64,
+            pool_state.fee_growth_global_1_x64,
+        );
+        let reward_growths_inside = get_reward_growths_inside(
+            &tick_lower_state,
+            &tick_upper_state,
+            pool_state.tick_current,
+            &updated_reward_infos,
+        );
+        personal_position.update_fee_and_reward(
+            fee_growth_inside_0_x64,
+            fee_growth_inside_1_x64,
+            reward_growths_inside,
+            get_recent_epoch()?,
+        )?;
+
+        if pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee) {
+            claim_fees_owed(&mut pool_state, personal_position)?
+        } else {
+            (0, 0)
+        }
+    };
+
+    let token_program_2022 = Some(ctx.accounts.token_program_2022.to_account_info());
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.recipient_token_account_0.to_account_info(),
+        Some(ctx.accounts.vault_0_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022.clone(),
+        fees_owed_0,
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_1.to_account_info(),
+        &ctx.accounts.recipient_token_account_1.to_account_info(),
+        Some(ctx.accounts.vault_1_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022.clone(),
+        fees_owed_1,
+        ctx.remaining_accounts,
+    )?;
+    check_unclaimed_fees_and_vault(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.token_vault_1.to_account_info(),
+    )?;
+
+    let reward_accounts: Vec<&AccountInfo> = ctx.remaining_accounts.iter().collect();
+    collect_rewards(
+        &ctx.accounts.pool_state,
+        reward_accounts.as_slice(),
+        &ctx.accounts.token_program,
+        token_program_2022,
+        personal_position,
+        true,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(CollectPersonalFeeEvent {
+        position_nft_mint: personal_position.nft_mint,
+        recipient_token_account_0: ctx.accounts.recipient_token_account_0.key(),
+        recipient_token_account_1: ctx.accounts.recipient_token_account_1.key(),
+        amount_0: fees_owed_0,
+        amount_1: fees_owed_1,
+    });
+
+    Ok(())
+}
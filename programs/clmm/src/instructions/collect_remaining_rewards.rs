@@ -61,6 +61,7 @@ pub fn collect_remaining_rewards(
         &ctx.accounts.token_program,
         Some(ctx.accounts.token_program_2022.to_account_info()),
         amount_remaining,
+        ctx.remaining_accounts,
     )?;
 
     Ok(())
@@ -74,6 +75,7 @@ fn get_remaining_reward_amount(
 ) -> Result<u64> {
     let current_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
     let mut pool_state = pool_state_loader.load_mut()?;
+    pool_state.require_no_flash_loan()?;
     pool_state.update_reward_infos(current_timestamp)?;
 
     let reward_info = pool_state.reward_infos[reward_index as usize];
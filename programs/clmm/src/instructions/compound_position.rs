@@ -0,0 +1,261 @@
+use super::decrease_liquidity::{check_unclaimed_fees_and_vault, claim_fees_owed};
+use super::open_position::update_position_ticks;
+use crate::error::ErrorCode;
+use crate::instructions::LiquidityChangeResult;
+use crate::libraries::{liquidity_math, tick_math};
+use crate::states::*;
+use crate::util::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CompoundPosition<'info> {
+    /// The position owner or delegated authority
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the tokenized position
+    #[account(
+        constraint = nft_account.mint == personal_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner,
+    )]
+    pub nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Reinvest the fees of this position
+    #[account(mut, constraint = personal_position.pool_id == pool_state.key())]
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// Stores the lower tick of the position
+    #[account(mut, constraint = tick_array_lower.load()?.pool_id == pool_state.key())]
+    pub tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// Stores the upper tick of the position
+    #[account(mut, constraint = tick_array_upper.load()?.pool_id == pool_state.key())]
+    pub tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// Token_0 vault
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token_1 vault
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for the token_0 left over after compounding
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint
+    )]
+    pub recipient_token_account_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for the token_1 left over after compounding
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint
+    )]
+    pub recipient_token_account_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program to transfer out tokens
+    pub token_program: Program<'info, Token>,
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining account
+    // tick_array_bitmap_extension, required if the position ticks overflow the default bitmap
+}
+
+pub fn compound_position<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CompoundPosition<'info>>,
+    sqrt_price_min_x64: Option<u128>,
+    sqrt_price_max_x64: Option<u128>,
+) -> Result<()> {
+    let personal_position = &mut ctx.accounts.personal_position;
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
+    let tick_lower = personal_position.tick_lower_index;
+    let tick_upper = personal_position.tick_upper_index;
+    let (liquidity, amount_0, amount_1, dust_0, dust_1) = {
+        let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
+        if !pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee)
+            || !pool_state.get_status_by_bit(PoolStatusBitIndex::OpenPositionOrIncreaseLiquidity)
+        {
+            return err!(ErrorCode::NotApproved);
+        }
+        // the fees are reinvested at the current price, so let the owner bound it
+        pool_state.check_sqrt_price_range(sqrt_price_min_x64, sqrt_price_max_x64)?;
+        let tick_array_lower = AccountLoad::<TickArrayState>::try_from(
+            &ctx.accounts.tick_array_lower.to_account_info(),
+        )?;
+        let tick_array_upper = AccountLoad::<TickArrayState>::try_from(
+            &ctx.accounts.tick_array_upper.to_account_info(),
+        )?;
+
+        // settle the fees earned up to now before taking them out of the position
+        let tick_lower_state = *tick_array_lower
+            .load()?
+            .get_tick_state(tick_lower, pool_state.tick_spacing)?;
+        let tick_upper_state = *tick_array_upper
+            .load()?
+            .get_tick_state(tick_upper, pool_state.tick_spacing)?;
+        let updated_reward_infos =
+            pool_state.update_reward_infos(Clock::get()?.unix_timestamp as u64)?;
+        let (fee_growth_inside_0_x64, fee_growth_inside_1_x64) = get_fee_growth_inside(
+            &tick_lower_state,
+            &tick_upper_state,
+            pool_state.tick_current,
+            pool_state.fee_growth_global_0_x64,
+            pool_state.fee_growth_global_1_x64,
+        );
+        let reward_growths_inside = get_reward_growths_inside(
+            &tick_lower_state,
+            &tick_upper_state,
+            pool_state.tick_current,
+            &updated_reward_infos,
+        );
+        personal_position.update_fee_and_reward(
+            fee_growth_inside_0_x64,
+            fee_growth_inside_1_x64,
+            reward_growths_inside,
+            get_recent_epoch()?,
+        )?;
+        let (fees_owed_0, fees_owed_1) = claim_fees_owed(pool_state, personal_position)?;
+
+        // the fees never leave the vault, so no transfer fee applies to the reinvested part
+        let liquidity = liquidity_math::get_liquidity_from_amounts(
+            pool_state.sqrt_price_x64,
+            tick_math::get_sqrt_price_at_tick(tick_lower)?,
+            tick_math::get_sqrt_price_at_tick(tick_upper)?,
+            fees_owed_0,
+            fees_owed_1,
+        );
+        let mut amount_0 = 0;
+        let mut amount_1 = 0;
+        if liquidity > 0 {
+            let use_tickarray_bitmap_extension =
+                pool_state.is_overflow_default_tickarray_bitmap(vec![tick_lower, tick_upper]);
+            let tick_array_bitmap_extension = if use_tickarray_bitmap_extension {
+                require_keys_eq!(
+                    ctx.remaining_accounts[0].key(),
+                    TickArrayBitmapExtension::key(ctx.accounts.pool_state.key())
+                );
+                Some(&ctx.remaining_accounts[0])
+            } else {
+                None
+            };
+            let liquidity_before = pool_state.liquidity;
+            let LiquidityChangeResult {
+                amount_0: liquidity_amount_0,
+                amount_1: liquidity_amount_1,
+                fee_growth_inside_0_x64: fee_growth_inside_0_x64_latest,
+                fee_growth_inside_1_x64: fee_growth_inside_1_x64_latest,
+                reward_growths_inside: reward_growths_inside_latest,
+                ..
+            } = update_position_ticks(
+                pool_state,
+                &tick_array_lower,
+                &tick_array_upper,
+                tick_array_bitmap_extension,
+                liquidity,
+                tick_lower,
+                tick_upper,
+            )?;
+            // liquidity is rounded down from the fees, so the amounts it needs never exceed them
+            require_gte!(
+                fees_owed_0,
+                liquidity_amount_0,
+                ErrorCode::PriceSlippageCheck
+            );
+            require_gte!(
+                fees_owed_1,
+                liquidity_amount_1,
+                ErrorCode::PriceSlippageCheck
+            );
+            amount_0 = liquidity_amount_0;
+            amount_1 = liquidity_amount_1;
+
+            personal_position.increase_liquidity(
+                liquidity,
+                fee_growth_inside_0_x64_latest,
+                fee_growth_inside_1_x64_latest,
+                reward_growths_inside_latest,
+                get_recent_epoch()?,
+            )?;
+            emit!(LiquidityChangeEvent {
+                pool_state: ctx.accounts.pool_state.key(),
+                tick: pool_state.tick_current,
+                tick_lower,
+                tick_upper,
+                liquidity_before,
+                liquidity_after: pool_state.liquidity,
+            });
+        }
+        (
+            liquidity,
+            amount_0,
+            amount_1,
+            fees_owed_0 - amount_0,
+            fees_owed_1 - amount_1,
+        )
+    };
+
+    let token_program_2022 = Some(ctx.accounts.token_program_2022.to_account_info());
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.recipient_token_account_0.to_account_info(),
+        Some(ctx.accounts.vault_0_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022.clone(),
+        dust_0,
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_1.to_account_info(),
+        &ctx.accounts.recipient_token_account_1.to_account_info(),
+        Some(ctx.accounts.vault_1_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022,
+        dust_1,
+        ctx.remaining_accounts,
+    )?;
+    check_unclaimed_fees_and_vault(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.token_vault_1.to_account_info(),
+    )?;
+
+    emit!(CompoundPositionEvent {
+        position_nft_mint: personal_position.nft_mint,
+        liquidity,
+        amount_0,
+        amount_1,
+        dust_amount_0: dust_0,
+        dust_amount_1: dust_1,
+    });
+
+    Ok(())
+}
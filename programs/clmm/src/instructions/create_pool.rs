@@ -121,23 +121,28 @@ pub struct CreatePool<'info> {
 }
 
 pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128, open_time: u64) -> Result<()> {
-    let mint0_associated_is_initialized = util::support_mint_associated_is_initialized(
-        &ctx.remaining_accounts,
-        &ctx.accounts.token_mint_0,
-    )?;
-    let mint1_associated_is_initialized = util::support_mint_associated_is_initialized(
-        &ctx.remaining_accounts,
-        &ctx.accounts.token_mint_1,
-    )?;
-//     if !(util::is_supported_mint(&ctx.accounts.token_mint_0, mint0_associated_is_initialized)
-//         .unwrap()
-//         && util::is_supported_mint(&ctx.accounts.token_mint_1, mint1_associated_is_initialized)
-//             .unwrap())
-//     {
-//         return err!(ErrorCode::NotSupportMint);
-//     }
+    create_pool_with_launch_fee(ctx, sqrt_price_x64, open_time, 0, 0, 0)
+}
+
+pub fn create_pool_with_launch_fee(
+    ctx: Context<CreatePool>,
+    sqrt_price_x64: u128,
+    open_time: u64,
+    launch_fee_start_rate: u32,
+    launch_fee_end_rate: u32,
+    launch_fee_duration: u32,
+) -> Result<()> {
+    let mint0_associated =
+        util::get_support_mint_associated(&ctx.remaining_accounts, &ctx.accounts.token_mint_0)?;
+    let mint1_associated =
+        util::get_support_mint_associated(&ctx.remaining_accounts, &ctx.accounts.token_mint_1)?;
+    if !(util::is_supported_mint(&ctx.accounts.token_mint_0, mint0_associated.as_ref())?
+        && util::is_supported_mint(&ctx.accounts.token_mint_1, mint1_associated.as_ref())?)
+    {
+        return err!(ErrorCode::NotSupportMint);
+    }
     let block_timestamp = solana_program::clock::Clock::get()?.unix_timestamp as u64;
-    require_gt!(block_timestamp, open_time);
+    let open_time = PoolState::get_open_time(open_time, block_timestamp)?;
     let pool_id = ctx.accounts.pool_state.key();
     let mut pool_state = ctx.accounts.pool_state.load_init()?;
 
@@ -163,6 +168,7 @@ pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128, open_time: u6
             ctx.accounts.token_mint_0.key().as_ref(),
             &[ctx.bumps.token_vault_0][..],
         ],
+        mint0_associated.is_some_and(|mint_associated| mint_associated.vault_immutable_owner),
     )?;
 
     create_token_vault_account(
@@ -178,6 +184,7 @@ pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128, open_time: u6
             ctx.accounts.token_mint_1.key().as_ref(),
             &[ctx.bumps.token_vault_1][..],
         ],
+        mint1_associated.is_some_and(|mint_associated| mint_associated.vault_immutable_owner),
     )?;
 
     // init observation
@@ -190,7 +197,7 @@ pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128, open_time: u6
     pool_state.initialize(
         bump,
         sqrt_price_x64,
-        0,
+        open_time,
         tick,
         ctx.accounts.pool_creator.key(),
         ctx.accounts.token_vault_0.key(),
@@ -200,6 +207,12 @@ pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128, open_time: u6
         ctx.accounts.token_mint_1.as_ref(),
         ctx.accounts.observation_state.key(),
     )?;
+    pool_state.set_launch_fee(
+        launch_fee_start_rate,
+        launch_fee_end_rate,
+        launch_fee_duration,
+        ctx.accounts.amm_config.as_ref(),
+    )?;
 
     ctx.accounts
         .tick_array_bitmap
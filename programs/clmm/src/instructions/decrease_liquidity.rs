@@ -35,6 +35,15 @@ pub fn decrease_liquidity<'a, 'b, 'c: 'info, 'info>(
     //     invoke_memo_instruction(DECREASE_MEMO_MSG, memp_program)?;
     // }
     assert!(liquidity <= personal_position.liquidity);
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
+    // a locked position can still collect its fees and rewards by decreasing zero liquidity
+    require!(
+        liquidity == 0 || !personal_position.is_locked(Clock::get()?.unix_timestamp as u64),
+        ErrorCode::PositionLocked
+    );
     let liquidity_before;
     let pool_sqrt_price_x64;
     let pool_tick_current;
@@ -145,6 +154,7 @@ pub fn decrease_liquidity<'a, 'b, 'c: 'info, 'info>(
         token_program,
         token_2022_program_opt.clone(),
         transfer_amount_0,
+        remaining_accounts,
     )?;
 
     transfer_from_pool_vault_to_user(
@@ -155,6 +165,7 @@ pub fn decrease_liquidity<'a, 'b, 'c: 'info, 'info>(
         token_program,
         token_2022_program_opt.clone(),
         transfer_amount_1,
+        remaining_accounts,
     )?;
 
     check_unclaimed_fees_and_vault(pool_state_loader, token_vault_0, token_vault_1)?;
@@ -170,6 +181,7 @@ pub fn decrease_liquidity<'a, 'b, 'c: 'info, 'info>(
         } else {
             true
         },
+        remaining_accounts,
     )?;
     emit!(DecreaseLiquidityEvent {
         position_nft_mint: personal_position.nft_mint,
@@ -229,29 +241,8 @@ pub fn decrease_liquidity_and_update_position<'a, 'b, 'c: 'info, 'info>(
     let mut latest_fees_owed_0 = 0;
     let mut latest_fees_owed_1 = 0;
     if pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee) {
-        latest_fees_owed_0 = personal_position.token_fees_owed_0;
-        latest_fees_owed_1 = personal_position.token_fees_owed_1;
-
-        require_gte!(
-            pool_state.total_fees_token_0 - pool_state.total_fees_claimed_token_0,
-            latest_fees_owed_0
-        );
-        require_gte!(
-            pool_state.total_fees_token_1 - pool_state.total_fees_claimed_token_1,
-            latest_fees_owed_1
-        );
-
-        personal_position.token_fees_owed_0 = 0;
-        personal_position.token_fees_owed_1 = 0;
-
-        pool_state.total_fees_claimed_token_0 = pool_state
-            .total_fees_claimed_token_0
-            .checked_add(latest_fees_owed_0)
-            .unwrap();
-        pool_state.total_fees_claimed_token_1 = pool_state
-            .total_fees_claimed_token_1
-            .checked_add(latest_fees_owed_1)
-            .unwrap();
+        (latest_fees_owed_0, latest_fees_owed_1) =
+            claim_fees_owed(&mut pool_state, personal_position)?;
     }
 
     Ok((
@@ -262,6 +253,38 @@ pub fn decrease_liquidity_and_update_position<'a, 'b, 'c: 'info, 'info>(
     ))
 }
 
+/// Takes the fees owed out of the position and records them as claimed by the pool
+pub fn claim_fees_owed(
+    pool_state: &mut PoolState,
+    personal_position: &mut PersonalPositionState,
+) -> Result<(u64, u64)> {
+    let latest_fees_owed_0 = personal_position.token_fees_owed_0;
+    let latest_fees_owed_1 = personal_position.token_fees_owed_1;
+
+    require_gte!(
+        pool_state.total_fees_token_0 - pool_state.total_fees_claimed_token_0,
+        latest_fees_owed_0
+    );
+    require_gte!(
+        pool_state.total_fees_token_1 - pool_state.total_fees_claimed_token_1,
+        latest_fees_owed_1
+    );
+
+    personal_position.token_fees_owed_0 = 0;
+    personal_position.token_fees_owed_1 = 0;
+
+    pool_state.total_fees_claimed_token_0 = pool_state
+        .total_fees_claimed_token_0
+        .checked_add(latest_fees_owed_0)
+        .unwrap();
+    pool_state.total_fees_claimed_token_1 = pool_state
+        .total_fees_claimed_token_1
+        .checked_add(latest_fees_owed_1)
+        .unwrap();
+
+    Ok((latest_fees_owed_0, latest_fees_owed_1))
+}
+
 pub fn burn_liquidity<'c: 'info, 'info>(
     pool_state: &mut RefMut<PoolState>,
     tick_array_lower_loader: &AccountLoader<'info, TickArrayState>,
@@ -335,6 +358,8 @@ pub fn burn_liquidity<'c: 'info, 'info>(
     Ok(result)
 }
 
+/// Transfers the rewards owed to the position, `remaining_accounts` starts with one account group
+/// per initialized reward, the accounts after them are only used by the reward mint transfer hooks
 pub fn collect_rewards<'a, 'b, 'c, 'info>(
     pool_state_loader: &AccountLoader<'info, PoolState>,
     remaining_accounts: &[&'info AccountInfo<'info>],
@@ -342,6 +367,7 @@ pub fn collect_rewards<'a, 'b, 'c, 'info>(
     token_program_2022: Option<AccountInfo<'info>>,
     personal_position_state: &mut PersonalPositionState,
     need_reward_mint: bool,
+    transfer_hook_accounts: &[AccountInfo<'info>],
 ) -> Result<[u64; REWARD_NUM]> {
     let mut reward_amounts: [u64; REWARD_NUM] = [0, 0, 0];
     if !pool_state_loader
@@ -354,15 +380,14 @@ pub fn collect_rewards<'a, 'b, 'c, 'info>(
     if !need_reward_mint {
         reward_group_account_num = reward_group_account_num - 1
     }
-    check_required_accounts_length(
+    let reward_accounts_len = check_required_accounts_length(
         pool_state_loader,
         remaining_accounts,
         reward_group_account_num,
     )?;
 
-    let remaining_accounts_len = remaining_accounts.len();
     let mut remaining_accounts = remaining_accounts.iter();
-    for i in 0..remaining_accounts_len / reward_group_account_num {
+    for i in 0..reward_accounts_len / reward_group_account_num {
         let reward_token_vault = InterfaceAccount::<token_interface::TokenAccount>::try_from(
             remaining_accounts.next().unwrap(),
         )?;
@@ -417,6 +442,7 @@ pub fn collect_rewards<'a, 'b, 'c, 'info>(
                 &token_program,
                 token_program_2022.clone(),
                 transfer_amount,
+                transfer_hook_accounts,
             )?;
         }
         reward_amounts[i] = transfer_amount
@@ -425,11 +451,12 @@ pub fn collect_rewards<'a, 'b, 'c, 'info>(
     Ok(reward_amounts)
 }
 
+/// Returns the number of reward accounts, the transfer hook accounts may follow them
 fn check_required_accounts_length(
     pool_state_loader: &AccountLoader<PoolState>,
     remaining_accounts: &[&AccountInfo],
     reward_group_account_num: usize,
-) -> Result<()> {
+) -> Result<usize> {
     let pool_state = pool_state_loader.load()?;
     let mut valid_reward_count = 0;
     for item in pool_state.reward_infos {
@@ -437,11 +464,11 @@ fn check_required_accounts_length(
             valid_reward_count = valid_reward_count + 1;
         }
     }
-    let remaining_accounts_len = remaining_accounts.len();
-    if remaining_accounts_len != valid_reward_count * reward_group_account_num {
+    let reward_accounts_len = valid_reward_count * reward_group_account_num;
+    if remaining_accounts.len() < reward_accounts_len {
         return err!(ErrorCode::InvalidRewardInputAccountNumber);
     }
-    Ok(())
+    Ok(reward_accounts_len)
 }
 
 pub fn check_unclaimed_fees_and_vault(
@@ -96,6 +96,7 @@ pub struct DecreaseLiquidityV2<'info> {
     //     bump
     // )]
     // pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmapExtension>,
+    // followed by the reward accounts and the transfer hook accounts of the vault and reward mints, if any
 }
 
 pub fn decrease_liquidity_v2<'a, 'b, 'c: 'info, 'info>(
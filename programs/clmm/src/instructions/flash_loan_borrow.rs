@@ -0,0 +1,155 @@
+use super::flash_loan_repay::FLASH_LOAN_REPAY_POOL_STATE_INDEX;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::util::transfer_from_pool_vault_to_user;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct FlashLoanBorrow<'info> {
+    /// The user borrowing from the pool vaults
+    pub borrower: Signer<'info>,
+
+    /// The factory state to read the flash loan fee
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The pool to borrow from
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The address that holds pool tokens for token_0
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for the borrowed token_0
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint
+    )]
+    pub recipient_token_account_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The destination token account for the borrowed token_1
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint
+    )]
+    pub recipient_token_account_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: the instructions sysvar, used to find the repay instruction of this transaction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn flash_loan_borrow(
+    ctx: Context<FlashLoanBorrow>,
+    amount_0: u64,
+    amount_1: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.amm_config.is_flash_loan_enabled(),
+        ErrorCode::FlashLoanDisabled
+    );
+    require!(amount_0 > 0 || amount_1 > 0, ErrorCode::ZeroAmountSpecified);
+    {
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        pool_state.require_no_flash_loan()?;
+        pool_state.set_amm_config_status(ctx.accounts.amm_config.status);
+        if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap) {
+            return err!(ErrorCode::NotApproved);
+        }
+        // until the repay, the other instructions of the pool fail
+        pool_state.open_flash_loan(amount_0, amount_1)?;
+    }
+
+    check_repay_instruction(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        ctx.accounts.pool_state.key(),
+    )?;
+
+    let token_program_2022 = Some(ctx.accounts.token_program_2022.to_account_info());
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_0.to_account_info(),
+        &ctx.accounts.recipient_token_account_0.to_account_info(),
+        Some(ctx.accounts.vault_0_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022.clone(),
+        amount_0,
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_vault_1.to_account_info(),
+        &ctx.accounts.recipient_token_account_1.to_account_info(),
+        Some(ctx.accounts.vault_1_mint.clone()),
+        &ctx.accounts.token_program,
+        token_program_2022,
+        amount_1,
+        ctx.remaining_accounts,
+    )?;
+
+    Ok(())
+}
+
+/// The borrow must be a top level instruction of this program, followed later in the same
+/// transaction by a `flash_loan_repay` for the same pool
+fn check_repay_instruction(instructions_sysvar: &AccountInfo, pool_state: Pubkey) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let current_instruction = load_instruction_at_checked(current_index, instructions_sysvar)?;
+    require_keys_eq!(
+        current_instruction.program_id,
+        crate::id(),
+        ErrorCode::FlashLoanNotRepaid
+    );
+
+    let mut index = current_index + 1;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if instruction.program_id == crate::id()
+            && instruction
+                .data
+                .starts_with(crate::instruction::FlashLoanRepay::DISCRIMINATOR)
+            && instruction
+                .accounts
+                .get(FLASH_LOAN_REPAY_POOL_STATE_INDEX)
+                .is_some_and(|account| account.pubkey == pool_state)
+        {
+            return Ok(());
+        }
+        index += 1;
+    }
+    err!(ErrorCode::FlashLoanNotRepaid)
+}
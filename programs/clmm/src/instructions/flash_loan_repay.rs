@@ -0,0 +1,117 @@
+use crate::states::*;
+use crate::util::{get_transfer_inverse_fee, transfer_from_user_to_pool_vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+/// The position of `pool_state` in the accounts of `flash_loan_repay`, looked up by `flash_loan_borrow`
+pub const FLASH_LOAN_REPAY_POOL_STATE_INDEX: usize = 1;
+
+#[derive(Accounts)]
+pub struct FlashLoanRepay<'info> {
+    /// The user repaying the flash loan
+    pub payer: Signer<'info>,
+
+    /// The pool the flash loan was borrowed from
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The factory state to read the flash loan fee
+    #[account(address = pool_state.load()?.get_amm_config())]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The payer's token account for token_0
+    #[account(
+        mut,
+        token::mint = token_vault_0.mint
+    )]
+    pub token_account_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The payer's token account for token_1
+    #[account(
+        mut,
+        token::mint = token_vault_1.mint
+    )]
+    pub token_account_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_0
+    #[account(
+        mut,
+        constraint = token_vault_0.key() == pool_state.load()?.token_vault_0
+    )]
+    pub token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    #[account(
+        mut,
+        constraint = token_vault_1.key() == pool_state.load()?.token_vault_1
+    )]
+    pub token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// The mint of token vault 0
+    #[account(
+        address = token_vault_0.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token vault 1
+    #[account(
+        address = token_vault_1.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn flash_loan_repay(ctx: Context<FlashLoanRepay>) -> Result<()> {
+    let (amount_0, amount_1, fee_0, fee_1) = ctx
+        .accounts
+        .pool_state
+        .load_mut()?
+        .close_flash_loan(&ctx.accounts.amm_config)?;
+
+    // the vaults must receive the loan and its fee in full, the payer covers the transfer fees
+    let repay_amount_0 = amount_0.checked_add(fee_0).unwrap();
+    let repay_amount_1 = amount_1.checked_add(fee_1).unwrap();
+    let transfer_fee_0 =
+        get_transfer_inverse_fee(ctx.accounts.vault_0_mint.clone(), repay_amount_0)?;
+    let transfer_fee_1 =
+        get_transfer_inverse_fee(ctx.accounts.vault_1_mint.clone(), repay_amount_1)?;
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.token_account_0.to_account_info(),
+        &ctx.accounts.token_vault_0.to_account_info(),
+        Some(ctx.accounts.vault_0_mint.clone()),
+        &ctx.accounts.token_program,
+        Some(ctx.accounts.token_program_2022.to_account_info()),
+        repay_amount_0.checked_add(transfer_fee_0).unwrap(),
+        ctx.remaining_accounts,
+    )?;
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.token_account_1.to_account_info(),
+        &ctx.accounts.token_vault_1.to_account_info(),
+        Some(ctx.accounts.vault_1_mint.clone()),
+        &ctx.accounts.token_program,
+        Some(ctx.accounts.token_program_2022.to_account_info()),
+        repay_amount_1.checked_add(transfer_fee_1).unwrap(),
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(FlashLoanEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        amount_0,
+        amount_1,
+        fee_0,
+        fee_1,
+        transfer_fee_0,
+        transfer_fee_1,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::swap_v2::{swap_pool_v2, SwapSingleV2};
+use crate::util::transfer_from_pool_vault_to_user;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+
+/// The discriminator of the callback instruction, sha256("global:flash_swap_callback")[..8]
+pub const FLASH_SWAP_CALLBACK_DISCRIMINATOR: [u8; 8] = [225, 54, 80, 1, 45, 208, 202, 124];
+
+/// The arguments of the callback instruction, serialized after `FLASH_SWAP_CALLBACK_DISCRIMINATOR`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FlashSwapCallback {
+    /// The pool in which the swap is performed
+    pub pool_state: Pubkey,
+    /// The amount the callback must transfer to the input vault, transfer fee included
+    pub amount_in: u64,
+    /// The amount transferred from the output vault before the callback
+    pub amount_out: u64,
+    /// if true, the input token is token_0
+    pub zero_for_one: bool,
+    /// The data passed through from the flash swap caller
+    pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct FlashSwap<'info> {
+    /// The accounts of the swap, the input token account is only reported in the swap event
+    /// because the callback pays the input, no referral fee is charged on a flash swap
+    pub swap: SwapSingleV2<'info>,
+
+    /// CHECK: The program called back with the owed input amount, it can not be this program
+    #[account(executable)]
+    pub callback_program: UncheckedAccount<'info>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // tick_array_account_1
+    // tick_array_account_2
+    // tick_array_account_...
+    // transfer hook accounts of the output vault mint, if any
+    // callback accounts, the last `callback_account_count` accounts
+}
+
+pub fn flash_swap<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, FlashSwap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    callback_account_count: u8,
+    callback_data: Vec<u8>,
+) -> Result<()> {
+    let callback_program = ctx.accounts.callback_program.to_account_info();
+    require_keys_neq!(
+        callback_program.key(),
+        crate::id(),
+        ErrorCode::InvalidFlashSwapCallback
+    );
+    let remaining_accounts = ctx.remaining_accounts;
+    require_gte!(remaining_accounts.len(), callback_account_count as usize);
+    let (swap_accounts, callback_accounts) =
+        remaining_accounts.split_at(remaining_accounts.len() - callback_account_count as usize);
+
+    let swap = &mut ctx.accounts.swap;
+    let amounts = swap_pool_v2(
+        swap,
+        swap_accounts,
+        amount,
+        sqrt_price_limit_x64,
+        is_base_input,
+        None,
+    )?;
+    let zero_for_one = amounts.zero_for_one;
+    let (amount_in, amount_in_without_fee, amount_out, amount_out_without_fee) = if zero_for_one {
+        (
+            amounts.transfer_amount_0,
+            amounts.amount_0_without_fee,
+            amounts.transfer_amount_1,
+            amounts.amount_1_without_fee,
+        )
+    } else {
+        (
+            amounts.transfer_amount_1,
+            amounts.amount_1_without_fee,
+            amounts.transfer_amount_0,
+            amounts.amount_0_without_fee,
+        )
+    };
+
+    // transfer the output first, the callback can use it to source the input
+    if swap.output_vault.amount <= amount_out {
+        // freeze pool, disable all instructions
+        swap.pool_state.load_mut()?.set_status(255);
+    }
+    transfer_from_pool_vault_to_user(
+        &swap.pool_state,
+        &swap.output_vault.to_account_info(),
+        &swap.output_token_account.to_account_info(),
+        Some(swap.output_vault_mint.clone()),
+        &swap.token_program,
+        Some(swap.token_program_2022.to_account_info()),
+        amount_out,
+        swap_accounts,
+    )?;
+
+    // the callback can not re-enter the pool, the runtime only allows a program to call itself directly
+    let input_vault_balance_before = swap.input_vault.amount;
+    let mut data = FLASH_SWAP_CALLBACK_DISCRIMINATOR.to_vec();
+    FlashSwapCallback {
+        pool_state: swap.pool_state.key(),
+        amount_in,
+        amount_out,
+        zero_for_one,
+        data: callback_data,
+    }
+    .serialize(&mut data)?;
+    let instruction = Instruction {
+        program_id: callback_program.key(),
+        accounts: callback_accounts
+            .iter()
+            .map(|account| {
+                if account.is_writable {
+                    AccountMeta::new(account.key(), account.is_signer)
+                } else {
+                    AccountMeta::new_readonly(account.key(), account.is_signer)
+                }
+            })
+            .collect(),
+        data,
+    };
+    let mut callback_account_infos = callback_accounts.to_vec();
+    callback_account_infos.push(callback_program);
+    invoke(&instruction, &callback_account_infos)?;
+
+    // the input vault must have received the input amount without the transfer fee
+    swap.input_vault.reload()?;
+    require_gte!(
+        swap.input_vault.amount,
+        input_vault_balance_before
+            .checked_add(amount_in_without_fee)
+            .unwrap(),
+        ErrorCode::FlashSwapNotPaid
+    );
+
+    let pool_state = swap.pool_state.load()?;
+    let (token_account_0, token_account_1) = if zero_for_one {
+        (
+            swap.input_token_account.key(),
+            swap.output_token_account.key(),
+        )
+    } else {
+        (
+            swap.output_token_account.key(),
+            swap.input_token_account.key(),
+        )
+    };
+    emit!(SwapEvent {
+        pool_state: pool_state.key(),
+        sender: swap.payer.key(),
+        token_account_0,
+        token_account_1,
+        amount_0: amounts.amount_0_without_fee,
+        transfer_fee_0: amounts.transfer_fee_0,
+        amount_1: amounts.amount_1_without_fee,
+        transfer_fee_1: amounts.transfer_fee_1,
+        zero_for_one,
+        sqrt_price_x64: pool_state.sqrt_price_x64,
+        liquidity: pool_state.liquidity,
+        tick: pool_state.tick_current,
+        referral_fee: amounts.referral_fee,
+    });
+    if zero_for_one {
+        require_gt!(amounts.swap_price_before, pool_state.sqrt_price_x64);
+    } else {
+        require_gt!(pool_state.sqrt_price_x64, amounts.swap_price_before);
+    }
+    if sqrt_price_limit_x64 == 0 {
+        // Does't allow partial filled without specified limit_price.
+        if is_base_input {
+            require_eq!(amount, amount_in);
+        } else {
+            require_eq!(amount, amount_out_without_fee);
+        }
+    }
+
+    if is_base_input {
+        require_gte!(
+            amount_out_without_fee,
+            other_amount_threshold,
+            ErrorCode::TooLittleOutputReceived
+        );
+    } else {
+        require_gte!(
+            other_amount_threshold,
+            amount_in,
+            ErrorCode::TooMuchInputPaid
+        );
+    }
+
+    Ok(())
+}
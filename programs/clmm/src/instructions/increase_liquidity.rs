@@ -29,6 +29,10 @@ pub fn increase_liquidity<'a, 'b, 'c: 'info, 'info>(
     amount_1_max: u64,
     base_flag: Option<bool>,
 ) -> Result<()> {
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
     let mut liquidity = liquidity;
     let pool_state = &mut pool_state_loader.load_mut()?;
     if !pool_state.get_status_by_bit(PoolStatusBitIndex::OpenPositionOrIncreaseLiquidity) {
@@ -70,6 +74,7 @@ pub fn increase_liquidity<'a, 'b, 'c: 'info, 'info>(
         } else {
             None
         },
+        remaining_accounts,
         pool_state,
         &mut liquidity,
         amount_0_max,
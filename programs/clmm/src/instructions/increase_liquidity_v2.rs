@@ -89,6 +89,7 @@ pub struct IncreaseLiquidityV2<'info> {
     //     bump
     // )]
     // pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmapExtension>,
+    // followed by the transfer hook accounts of the vault mints, if any
 }
 
 pub fn increase_liquidity_v2<'a, 'b, 'c: 'info, 'info>(
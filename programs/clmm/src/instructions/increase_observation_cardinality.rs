@@ -0,0 +1,68 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::{prelude::*, system_program};
+
+#[derive(Accounts)]
+pub struct IncreaseObservationCardinality<'info> {
+    /// Pays the rent of the enlarged observation account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The pool whose observation account is enlarged
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The program account for the oracle observations of the pool
+    #[account(mut, address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn increase_observation_cardinality(
+    ctx: Context<IncreaseObservationCardinality>,
+    observation_cardinality_next: u16,
+) -> Result<()> {
+    let observation_account_info = ctx.accounts.observation_state.to_account_info();
+    let space_old = observation_account_info.data_len();
+    let space_next = ObservationState::space(observation_cardinality_next as usize);
+    require_gte!(
+        OBSERVATION_MAX_NUM,
+        observation_cardinality_next as usize,
+        ErrorCode::InvalidObservationCardinality
+    );
+    require_gt!(
+        space_next,
+        space_old,
+        ErrorCode::InvalidObservationCardinality
+    );
+    // the account data can only grow by MAX_PERMITTED_DATA_INCREASE in one instruction
+    require_gte!(
+        MAX_PERMITTED_DATA_INCREASE,
+        space_next - space_old,
+        ErrorCode::InvalidObservationCardinality
+    );
+
+    let required_lamports = Rent::get()?
+        .minimum_balance(space_next)
+        .saturating_sub(observation_account_info.lamports());
+    if required_lamports > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: observation_account_info.clone(),
+        };
+        let cpi_context =
+            CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_context, required_lamports)?;
+    }
+    // new observations must be zeroed, an observation with zero timestamp is treated as not written
+    observation_account_info.realloc(space_next, true)?;
+
+    emit!(IncreaseObservationCardinalityEvent {
+        pool_state: ctx.accounts.pool_state.key(),
+        observation_cardinality_old: ((space_old - ObservationState::LEN) / Observation::LEN
+            + OBSERVATION_NUM) as u16,
+        observation_cardinality_new: observation_cardinality_next,
+    });
+    Ok(())
+}
@@ -19,7 +19,7 @@ pub struct InitializeReward<'info> {
     pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// For check the reward_funder authority
-    #[account(address = pool_state.load()?.amm_config)]
+    #[account(address = pool_state.load()?.get_amm_config())]
     pub amm_config: Box<Account<'info, AmmConfig>>,
 
     /// Set reward for this pool
@@ -35,6 +35,15 @@ pub struct InitializeReward<'info> {
     )]
     pub operation_state: AccountLoader<'info, OperationState>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     /// Reward mint
     pub reward_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -97,16 +106,11 @@ pub fn initialize_reward(
     ctx: Context<InitializeReward>,
     param: InitializeRewardParam,
 ) -> Result<()> {
-    let mint_associated_is_initialized = util::support_mint_associated_is_initialized(
+    let mint_associated = util::get_support_mint_associated(
         &ctx.remaining_accounts,
         &ctx.accounts.reward_token_mint,
     )?;
-    if !util::is_supported_mint(
-        &ctx.accounts.reward_token_mint,
-        mint_associated_is_initialized,
-    )
-    .unwrap()
-    {
+    if !util::is_supported_mint(&ctx.accounts.reward_token_mint, mint_associated.as_ref())? {
         return err!(ErrorCode::NotSupportMint);
     }
 
@@ -124,11 +128,14 @@ pub fn initialize_reward(
             ctx.accounts.reward_token_mint.key().as_ref(),
             &[ctx.bumps.reward_token_vault][..],
         ],
+        mint_associated.is_some_and(|mint_associated| mint_associated.vault_immutable_owner),
     )?;
 
     let operation_state = ctx.accounts.operation_state.load()?;
     require!(
-        ctx.accounts.reward_funder.key() == crate::admin::ID
+        ctx.accounts
+            .global_config
+            .is_admin(ctx.accounts.reward_funder.key())
             || ctx.accounts.reward_funder.key() == ctx.accounts.pool_state.load()?.owner
             || operation_state.validate_operation_owner(ctx.accounts.reward_funder.key()),
         ErrorCode::NotApproved
@@ -157,6 +164,7 @@ pub fn initialize_reward(
     );
 
     let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.require_no_flash_loan()?;
     pool_state.initialize_reward(
         param.open_time,
         param.end_time,
@@ -166,6 +174,7 @@ pub fn initialize_reward(
         &ctx.accounts.reward_token_vault.key(),
         &ctx.accounts.reward_funder.key(),
         &operation_state,
+        &ctx.accounts.global_config,
     )?;
 
     transfer_from_user_to_pool_vault(
@@ -176,6 +185,7 @@ pub fn initialize_reward(
         &ctx.accounts.reward_token_program.to_account_info(),
         Some(ctx.accounts.reward_token_program.to_account_info()),
         reward_amount_with_transfer_fee,
+        ctx.remaining_accounts,
     )?;
 
     Ok(())
@@ -0,0 +1,44 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    /// The position owner or delegated authority
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the tokenized position
+    #[account(
+        constraint = nft_account.mint == personal_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner,
+    )]
+    pub nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The position to lock
+    #[account(mut)]
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+}
+
+pub fn lock_position(ctx: Context<LockPosition>, locked_until: u64) -> Result<()> {
+    let personal_position = &mut ctx.accounts.personal_position;
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
+    // a lock can never be shortened, only extended
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    require!(
+        locked_until > block_timestamp && locked_until >= personal_position.locked_until,
+        ErrorCode::InvalidLockTime
+    );
+    personal_position.locked_until = locked_until;
+
+    emit!(LockPositionEvent {
+        position_nft_mint: personal_position.nft_mint,
+        locked_until,
+    });
+
+    Ok(())
+}
@@ -22,15 +22,51 @@ pub use decrease_liquidity::*;
 pub mod decrease_liquidity_v2;
 pub use decrease_liquidity_v2::*;
 
+pub mod collect_fees_and_rewards;
+pub use collect_fees_and_rewards::*;
+
+pub mod compound_position;
+pub use compound_position::*;
+
+pub mod place_range_order;
+pub use place_range_order::*;
+
+pub mod claim_range_order;
+pub use claim_range_order::*;
+
+pub mod flash_loan_borrow;
+pub use flash_loan_borrow::*;
+
+pub mod flash_loan_repay;
+pub use flash_loan_repay::*;
+
+pub mod lock_position;
+pub use lock_position::*;
+
 pub mod swap;
 pub use swap::*;
 
 pub mod swap_v2;
 pub use swap_v2::*;
 
+pub mod flash_swap;
+pub use flash_swap::*;
+
 pub mod swap_router_base_in;
 pub use swap_router_base_in::*;
 
+pub mod swap_router_base_out;
+pub use swap_router_base_out::*;
+
+pub mod observe;
+pub use observe::*;
+
+pub mod increase_observation_cardinality;
+pub use increase_observation_cardinality::*;
+
+pub mod snapshot_cumulatives_inside;
+pub use snapshot_cumulatives_inside::*;
+
 pub mod update_reward_info;
 pub use update_reward_info::*;
 
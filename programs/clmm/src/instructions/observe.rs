@@ -0,0 +1,50 @@
+use crate::states::*;
+use crate::util::AccountLoad;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct Observe<'info> {
+    /// The pool whose oracle is read
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The program account for the oracle observations of the pool
+    #[account(address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+}
+
+/// The return data of the observe instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ObserveResult {
+    /// The tick cumulatives as of each `seconds_agos` from the current block timestamp
+    pub tick_cumulatives: Vec<i64>,
+    /// The seconds per liquidity cumulatives as of each `seconds_agos` from the current block timestamp
+    pub seconds_per_liquidity_cumulative_x64s: Vec<u128>,
+}
+
+pub fn observe(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> Result<()> {
+    let pool_state = ctx.accounts.pool_state.load()?;
+    let observation_account_info = ctx.accounts.observation_state.to_account_info();
+    let (observation_state, observation_extension) =
+        AccountLoad::<ObservationState>::load_data_with_trailing::<Observation>(
+            &observation_account_info,
+        )?;
+    require_keys_eq!(observation_state.pool_id, ctx.accounts.pool_state.key());
+
+    let block_timestamp = oracle::block_timestamp();
+    let (tick_cumulatives, seconds_per_liquidity_cumulative_x64s) = observation_state.observe(
+        &observation_extension,
+        block_timestamp,
+        &seconds_agos,
+        pool_state.tick_current,
+        pool_state.seconds_per_liquidity_cumulative_x64(u64::from(block_timestamp)),
+    )?;
+    let mut data = Vec::new();
+    ObserveResult {
+        tick_cumulatives,
+        seconds_per_liquidity_cumulative_x64s,
+    }
+    .serialize(&mut data)?;
+    set_return_data(&data);
+    Ok(())
+}
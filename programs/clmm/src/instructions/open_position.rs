@@ -140,6 +140,7 @@ pub fn open_position<'a, 'b, 'c: 'info, 'info>(
             } else {
                 None
             },
+            remaining_accounts,
             pool_state,
             &mut liquidity,
             amount_0_max,
@@ -222,6 +223,7 @@ pub fn add_liquidity<'b, 'c: 'info, 'info>(
     vault_0_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
     vault_1_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
     tick_array_bitmap_extension: Option<&'c AccountInfo<'info>>,
+    transfer_hook_accounts: &[AccountInfo<'info>],
     pool_state: &mut RefMut<PoolState>,
     liquidity: &mut u128,
     amount_0_max: u64,
@@ -275,69 +277,16 @@ pub fn add_liquidity<'b, 'c: 'info, 'info>(
     }
     assert!(*liquidity > 0);
     let liquidity_before = pool_state.liquidity;
-    require_keys_eq!(tick_array_lower_loader.load()?.pool_id, pool_state.key());
-    require_keys_eq!(tick_array_upper_loader.load()?.pool_id, pool_state.key());
-
-    // get tick_state
-    let mut tick_lower_state = *tick_array_lower_loader
-        .load_mut()?
-        .get_tick_state_mut(tick_lower_index, pool_state.tick_spacing)?;
-    let mut tick_upper_state = *tick_array_upper_loader
-        .load_mut()?
-        .get_tick_state_mut(tick_upper_index, pool_state.tick_spacing)?;
-    // If the tickState is not initialized, assign a value to tickState.tick here
-    if tick_lower_state.tick == 0 {
-        tick_lower_state.tick = tick_lower_index;
-    }
-    if tick_upper_state.tick == 0 {
-        tick_upper_state.tick = tick_upper_index;
-    }
-    let clock = Clock::get()?;
-    let mut result = modify_position(
-        i128::try_from(*liquidity).unwrap(),
+    let mut result = update_position_ticks(
         pool_state,
-        &mut tick_lower_state,
-        &mut tick_upper_state,
-        clock.unix_timestamp as u64,
-    )?;
-
-    // update tick_state
-    tick_array_lower_loader.load_mut()?.update_tick_state(
+        tick_array_lower_loader,
+        tick_array_upper_loader,
+        tick_array_bitmap_extension,
+        *liquidity,
         tick_lower_index,
-        pool_state.tick_spacing,
-        tick_lower_state,
-    )?;
-    tick_array_upper_loader.load_mut()?.update_tick_state(
         tick_upper_index,
-        pool_state.tick_spacing,
-        tick_upper_state,
     )?;
 
-    if result.tick_lower_flipped {
-        let mut tick_array_lower = tick_array_lower_loader.load_mut()?;
-        let before_init_tick_count = tick_array_lower.initialized_tick_count;
-        tick_array_lower.update_initialized_tick_count(true)?;
-
-        if before_init_tick_count == 0 {
-            pool_state.flip_tick_array_bit(
-                tick_array_bitmap_extension,
-                tick_array_lower.start_tick_index,
-            )?;
-        }
-    }
-    if result.tick_upper_flipped {
-        let mut tick_array_upper = tick_array_upper_loader.load_mut()?;
-        let before_init_tick_count = tick_array_upper.initialized_tick_count;
-        tick_array_upper.update_initialized_tick_count(true)?;
-
-        if before_init_tick_count == 0 {
-            pool_state.flip_tick_array_bit(
-                tick_array_bitmap_extension,
-                tick_array_upper.start_tick_index,
-            )?;
-        }
-    }
-
     let amount_0 = result.amount_0;
     let amount_1 = result.amount_1;
     require!(
@@ -398,6 +347,7 @@ pub fn add_liquidity<'b, 'c: 'info, 'info>(
         &token_program,
         token_2022_program_opt.clone(),
         amount_0 + amount_0_transfer_fee,
+        transfer_hook_accounts,
     )?;
     transfer_from_user_to_pool_vault(
         payer,
@@ -407,6 +357,7 @@ pub fn add_liquidity<'b, 'c: 'info, 'info>(
         &token_program,
         token_2022_program_opt.clone(),
         amount_1 + amount_1_transfer_fee,
+        transfer_hook_accounts,
     )?;
     emit!(LiquidityChangeEvent {
         pool_state: pool_state.key(),
@@ -419,6 +370,83 @@ pub fn add_liquidity<'b, 'c: 'info, 'info>(
     Ok(result)
 }
 
+/// Adds liquidity to the ticks of a position and flips the tick array bits when a tick is initialized,
+/// without moving any tokens
+pub fn update_position_ticks<'b, 'c: 'info, 'info>(
+    pool_state: &mut RefMut<PoolState>,
+    tick_array_lower_loader: &'b AccountLoad<'info, TickArrayState>,
+    tick_array_upper_loader: &'b AccountLoad<'info, TickArrayState>,
+    tick_array_bitmap_extension: Option<&'c AccountInfo<'info>>,
+    liquidity: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<LiquidityChangeResult> {
+    require_keys_eq!(tick_array_lower_loader.load()?.pool_id, pool_state.key());
+    require_keys_eq!(tick_array_upper_loader.load()?.pool_id, pool_state.key());
+
+    // get tick_state
+    let mut tick_lower_state = *tick_array_lower_loader
+        .load_mut()?
+        .get_tick_state_mut(tick_lower_index, pool_state.tick_spacing)?;
+    let mut tick_upper_state = *tick_array_upper_loader
+        .load_mut()?
+        .get_tick_state_mut(tick_upper_index, pool_state.tick_spacing)?;
+    // If the tickState is not initialized, assign a value to tickState.tick here
+    if tick_lower_state.tick == 0 {
+        tick_lower_state.tick = tick_lower_index;
+    }
+    if tick_upper_state.tick == 0 {
+        tick_upper_state.tick = tick_upper_index;
+    }
+    let clock = Clock::get()?;
+    let result = modify_position(
+        i128::try_from(liquidity).unwrap(),
+        pool_state,
+        &mut tick_lower_state,
+        &mut tick_upper_state,
+        clock.unix_timestamp as u64,
+    )?;
+
+    // update tick_state
+    tick_array_lower_loader.load_mut()?.update_tick_state(
+        tick_lower_index,
+        pool_state.tick_spacing,
+        tick_lower_state,
+    )?;
+    tick_array_upper_loader.load_mut()?.update_tick_state(
+        tick_upper_index,
+        pool_state.tick_spacing,
+        tick_upper_state,
+    )?;
+
+    if result.tick_lower_flipped {
+        let mut tick_array_lower = tick_array_lower_loader.load_mut()?;
+        let before_init_tick_count = tick_array_lower.initialized_tick_count;
+        tick_array_lower.update_initialized_tick_count(true)?;
+
+        if before_init_tick_count == 0 {
+            pool_state.flip_tick_array_bit(
+                tick_array_bitmap_extension,
+                tick_array_lower.start_tick_index,
+            )?;
+        }
+    }
+    if result.tick_upper_flipped {
+        let mut tick_array_upper = tick_array_upper_loader.load_mut()?;
+        let before_init_tick_count = tick_array_upper.initialized_tick_count;
+        tick_array_upper.update_initialized_tick_count(true)?;
+
+        if before_init_tick_count == 0 {
+            pool_state.flip_tick_array_bit(
+                tick_array_bitmap_extension,
+                tick_array_upper.start_tick_index,
+            )?;
+        }
+    }
+
+    Ok(result)
+}
+
 pub fn modify_position(
     liquidity_delta: i128,
     pool_state: &mut RefMut<PoolState>,
@@ -439,6 +467,7 @@ pub fn modify_position(
             liquidity_delta,
             pool_state.fee_growth_global_0_x64,
             pool_state.fee_growth_global_1_x64,
+            pool_state.seconds_per_liquidity_global_x64,
             false,
             &updated_reward_infos,
         )?;
@@ -447,6 +476,7 @@ pub fn modify_position(
             liquidity_delta,
             pool_state.fee_growth_global_0_x64,
             pool_state.fee_growth_global_1_x64,
+            pool_state.seconds_per_liquidity_global_x64,
             true,
             &updated_reward_infos,
         )?;
@@ -128,6 +128,7 @@ pub struct OpenPositionWithToken22Nft<'info> {
     //     bump
     // )]
     // pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmapExtension>,
+    // followed by the transfer hook accounts of the vault mints, if any
 }
 
 pub fn open_position_with_token22_nft<'a, 'b, 'c: 'info, 'info>(
@@ -0,0 +1,113 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::util::get_recent_epoch;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct PlaceRangeOrder<'info> {
+    /// The position owner or delegated authority
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the tokenized position
+    #[account(
+        constraint = nft_account.mint == personal_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner,
+    )]
+    pub nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The position to place as a range order
+    #[account(mut, constraint = personal_position.pool_id == pool_state.key())]
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// Stores both ticks of the position
+    #[account(mut, constraint = tick_array.load()?.pool_id == pool_state.key())]
+    pub tick_array: AccountLoader<'info, TickArrayState>,
+}
+
+pub fn place_range_order(ctx: Context<PlaceRangeOrder>) -> Result<()> {
+    let personal_position = &mut ctx.accounts.personal_position;
+    require!(
+        !personal_position.range_order,
+        ErrorCode::RangeOrderPosition
+    );
+    // a filled order is withdrawn when claimed, which a lock would not allow
+    require!(
+        !personal_position.is_locked(Clock::get()?.unix_timestamp as u64),
+        ErrorCode::PositionLocked
+    );
+    require_gt!(personal_position.liquidity, 0);
+
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.require_no_flash_loan()?;
+    let mut tick_array = ctx.accounts.tick_array.load_mut()?;
+    let tick_lower = personal_position.tick_lower_index;
+    let tick_upper = personal_position.tick_upper_index;
+    let tick_spacing = pool_state.tick_spacing;
+    // both ticks must be in the same tick array, so a swap crossing the order can remove it from the range
+    require!(
+        tick_upper - tick_lower == i32::from(tick_spacing)
+            && TickArrayState::get_array_start_index(tick_lower, tick_spacing)
+                == tick_array.start_tick_index
+            && TickArrayState::get_array_start_index(tick_upper, tick_spacing)
+                == tick_array.start_tick_index,
+        ErrorCode::InvalidRangeOrder
+    );
+    // the position must hold a single token, so it is fully converted once the price crosses the range
+    let zero_for_one = if pool_state.tick_current < tick_lower {
+        true
+    } else if pool_state.tick_current >= tick_upper {
+        false
+    } else {
+        return err!(ErrorCode::InvalidRangeOrder);
+    };
+
+    // settle what the position earned so far, a filled order does not earn fees or rewards
+    let tick_lower_state = *tick_array.get_tick_state(tick_lower, tick_spacing)?;
+    let tick_upper_state = *tick_array.get_tick_state(tick_upper, tick_spacing)?;
+    let updated_reward_infos =
+        pool_state.update_reward_infos(Clock::get()?.unix_timestamp as u64)?;
+    let (fee_growth_inside_0_x64, fee_growth_inside_1_x64) = get_fee_growth_inside(
+        &tick_lower_state,
+        &tick_upper_state,
+        pool_state.tick_current,
+        pool_state.fee_growth_global_0_x64,
+        pool_state.fee_growth_global_1_x64,
+    );
+    let reward_growths_inside = get_reward_growths_inside(
+        &tick_lower_state,
+        &tick_upper_state,
+        pool_state.tick_current,
+        &updated_reward_infos,
+    );
+    personal_position.update_fee_and_reward(
+        fee_growth_inside_0_x64,
+        fee_growth_inside_1_x64,
+        reward_growths_inside,
+        get_recent_epoch()?,
+    )?;
+
+    let fill_tick = if zero_for_one { tick_upper } else { tick_lower };
+    let fill_tick_state = tick_array.get_tick_state_mut(fill_tick, tick_spacing)?;
+    fill_tick_state.range_order_liquidity = fill_tick_state
+        .range_order_liquidity
+        .checked_add(personal_position.liquidity)
+        .unwrap();
+
+    personal_position.range_order = true;
+    personal_position.range_order_zero_for_one = zero_for_one;
+    personal_position.range_order_fill_count = fill_tick_state.range_order_fill_count;
+
+    emit!(PlaceRangeOrderEvent {
+        position_nft_mint: personal_position.nft_mint,
+        zero_for_one,
+        fill_tick,
+        liquidity: personal_position.liquidity,
+    });
+
+    Ok(())
+}
@@ -13,13 +13,13 @@ pub struct SetRewardParams<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        address = pool_state.load()?.amm_config
+        address = pool_state.load()?.get_amm_config()
     )]
     pub amm_config: Account<'info, AmmConfig>,
 
     #[account(
         mut,
-        constraint = pool_state.load()?.amm_config == amm_config.key()
+        constraint = pool_state.load()?.get_amm_config() == amm_config.key()
     )]
     pub pool_state: AccountLoader<'info, PoolState>,
 
@@ -32,6 +32,15 @@ pub struct SetRewardParams<'info> {
     )]
     pub operation_state: AccountLoader<'info, OperationState>,
 
+    /// The global config holding the admin
+    #[account(
+        seeds = [
+            GLOBAL_CONFIG_SEED.as_bytes(),
+        ],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
     /// Token program 2022
@@ -52,12 +61,16 @@ pub fn set_reward_params<'a, 'b, 'c: 'info, 'info>(
     let admin_keys = operation_state.operation_owners.to_vec();
     require_neq!(ctx.accounts.authority.key(), Pubkey::default());
     let admin_operator = admin_keys.contains(&ctx.accounts.authority.key())
-        || ctx.accounts.authority.key() == crate::admin::ID;
+        || ctx
+            .accounts
+            .global_config
+            .is_admin(ctx.accounts.authority.key());
 
     let current_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
     require_gt!(open_time, current_timestamp);
 
     let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.require_no_flash_loan()?;
 
     if !admin_operator {
         require_keys_eq!(
@@ -124,6 +137,7 @@ pub fn set_reward_params<'a, 'b, 'c: 'info, 'info>(
             &ctx.accounts.token_program,
             Some(ctx.accounts.token_program_2022.to_account_info()),
             reward_amount_with_transfer_fee,
+            ctx.remaining_accounts,
         )?;
     }
 
@@ -0,0 +1,59 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct SnapshotCumulativesInside<'info> {
+    /// The pool of the range
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The tick array containing the lower tick of the range
+    #[account(constraint = tick_array_lower.load()?.pool_id == pool_state.key())]
+    pub tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// The tick array containing the upper tick of the range
+    #[account(constraint = tick_array_upper.load()?.pool_id == pool_state.key())]
+    pub tick_array_upper: AccountLoader<'info, TickArrayState>,
+}
+
+/// The return data of the snapshot_cumulatives_inside instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotCumulativesInsideResult {
+    /// The seconds per liquidity inside the range as of the current block timestamp,
+    /// only comparable with snapshots taken for the same range while it stays initialized
+    pub seconds_per_liquidity_inside_x64: u128,
+}
+
+pub fn snapshot_cumulatives_inside(
+    ctx: Context<SnapshotCumulativesInside>,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<()> {
+    check_ticks_order(tick_lower_index, tick_upper_index)?;
+    let pool_state = ctx.accounts.pool_state.load()?;
+    let tick_array_lower = ctx.accounts.tick_array_lower.load()?;
+    let tick_array_upper = ctx.accounts.tick_array_upper.load()?;
+    let tick_lower_state =
+        tick_array_lower.get_tick_state(tick_lower_index, pool_state.tick_spacing)?;
+    let tick_upper_state =
+        tick_array_upper.get_tick_state(tick_upper_index, pool_state.tick_spacing)?;
+    require!(
+        tick_lower_state.is_initialized() && tick_upper_state.is_initialized(),
+        ErrorCode::TickNotInitialized
+    );
+
+    let seconds_per_liquidity_inside_x64 = get_seconds_per_liquidity_inside(
+        tick_lower_state,
+        tick_upper_state,
+        pool_state.tick_current,
+        pool_state.seconds_per_liquidity_cumulative_x64(u64::from(oracle::block_timestamp())),
+    );
+    let mut data = Vec::new();
+    SnapshotCumulativesInsideResult {
+        seconds_per_liquidity_inside_x64,
+    }
+    .serialize(&mut data)?;
+    set_return_data(&data);
+    Ok(())
+}
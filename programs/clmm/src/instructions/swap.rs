@@ -31,6 +31,8 @@ pub struct SwapState {
     pub protocol_fee: u64,
     // amount of input token paid as fund fee
     pub fund_fee: u64,
+    // amount of input token paid as referral fee
+    pub referral_fee: u64,
     // the current liquidity in range
     pub liquidity: u128,
 }
@@ -53,11 +55,13 @@ struct StepComputations {
     fee_amount: u64,
 }
 
+/// Swaps in the pool without a referrer, returns the amounts of token_0 and token_1
 pub fn swap_internal<'b, 'info>(
     amm_config: &AmmConfig,
     pool_state: &mut RefMut<PoolState>,
     tick_array_states: &mut VecDeque<RefMut<TickArrayState>>,
     observation_state: &mut RefMut<ObservationState>,
+    observation_extension: &mut [Observation],
     tickarray_bitmap_extension: &Option<TickArrayBitmapExtension>,
     amount_specified: u64,
     sqrt_price_limit_x64: u128,
@@ -65,10 +69,51 @@ pub fn swap_internal<'b, 'info>(
     is_base_input: bool,
     block_timestamp: u32,
 ) -> Result<(u64, u64)> {
+    let (amount_0, amount_1, _) = swap_internal_with_referral(
+        amm_config,
+        pool_state,
+        tick_array_states,
+        observation_state,
+        observation_extension,
+        tickarray_bitmap_extension,
+        amount_specified,
+        sqrt_price_limit_x64,
+        zero_for_one,
+        is_base_input,
+        block_timestamp,
+        0,
+    )?;
+    Ok((amount_0, amount_1))
+}
+
+/// Swaps in the pool, `referral_fee_rate` of the trade fee is split off for the referrer like
+/// the protocol and fund fees. Returns the amounts of token_0 and token_1 and the referral fee
+/// in the input token, which is left in the input vault for the caller to pay out
+pub fn swap_internal_with_referral<'b, 'info>(
+    amm_config: &AmmConfig,
+    pool_state: &mut RefMut<PoolState>,
+    tick_array_states: &mut VecDeque<RefMut<TickArrayState>>,
+    observation_state: &mut RefMut<ObservationState>,
+    observation_extension: &mut [Observation],
+    tickarray_bitmap_extension: &Option<TickArrayBitmapExtension>,
+    amount_specified: u64,
+    sqrt_price_limit_x64: u128,
+    zero_for_one: bool,
+    is_base_input: bool,
+    block_timestamp: u32,
+    referral_fee_rate: u32,
+) -> Result<(u64, u64, u64)> {
     require!(amount_specified != 0, ErrorCode::ZeroAmountSpecified);
+    // the config status is read from the config, so a config pause reaches every pool
+    pool_state.set_amm_config_status(amm_config.status);
     if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap) {
         return err!(ErrorCode::NotApproved);
     }
+    require_gte!(
+        u64::from(block_timestamp),
+        pool_state.open_time,
+        ErrorCode::NotOpenYet
+    );
     require!(
         if zero_for_one {
             sqrt_price_limit_x64 < pool_state.sqrt_price_x64
@@ -81,9 +126,24 @@ pub fn swap_internal<'b, 'info>(
     );
 
     let liquidity_start = pool_state.liquidity;
+    // the fee rates overridden by the pool take precedence over the amm config
+    let amm_config = &pool_state.apply_fee_overrides(amm_config);
+    require!(
+        referral_fee_rate <= amm_config.max_referral_fee_rate
+            && amm_config.protocol_fee_rate + amm_config.fund_fee_rate + referral_fee_rate
+                <= FEE_RATE_DENOMINATOR_VALUE,
+        ErrorCode::InvalidReferralFeeRate
+    );
 
     let updated_reward_infos = pool_state.update_reward_infos(block_timestamp as u64)?;
 
+    let dynamic_fee_enabled = amm_config.is_dynamic_fee_enabled();
+    if dynamic_fee_enabled {
+        pool_state.update_volatility_reference(block_timestamp);
+    }
+    // the launch fee only raises the trade fee during the first seconds of trading
+    let launch_fee_rate = pool_state.get_launch_fee_rate(u64::from(block_timestamp));
+
     let mut state = SwapState {
         amount_specified_remaining: amount_specified,
         amount_calculated: 0,
@@ -97,12 +157,27 @@ pub fn swap_internal<'b, 'info>(
         fee_amount: 0,
         protocol_fee: 0,
         fund_fee: 0,
+        referral_fee: 0,
         liquidity: liquidity_start,
     };
 
     // check observation account is owned by the pool
     require_keys_eq!(observation_state.pool_id, pool_state.key());
 
+    // the twap guard bounds how far the swap can move the price from the time-weighted average
+    let twap_guard_sqrt_price_x64 = pool_state.get_twap_guard_sqrt_price(
+        observation_state,
+        observation_extension,
+        block_timestamp,
+        zero_for_one,
+    )?;
+    let sqrt_price_limit_x64 = pool_state.apply_twap_guard_to_price_limit(
+        twap_guard_sqrt_price_x64,
+        sqrt_price_limit_x64,
+        zero_for_one,
+    )?;
+    pool_state.update_twap_guard_anchor(block_timestamp);
+
     let (mut is_match_pool_current_tick_array, first_valid_tick_array_start_index) =
         pool_state.get_first_initialized_tick_array(&tickarray_bitmap_extension, zero_for_one)?;
     let mut current_valid_tick_array_start_index = first_valid_tick_array_start_index;
@@ -225,12 +300,17 @@ pub fn swap_internal<'b, 'info>(
             state.liquidity,
             state.amount_specified_remaining
         );
+        // the dynamic fee grows with the distance the price has moved from the volatility reference
+        let trade_fee_rate =
+            amm_config.get_trade_fee_rate(pool_state.get_volatility_accumulator(state.tick));
+        let trade_fee_rate =
+            launch_fee_rate.map_or(trade_fee_rate, |rate| rate.max(trade_fee_rate));
         let swap_step = swap_math::compute_swap_step(
             step.sqrt_price_start_x64,
             target_price,
             state.liquidity,
             state.amount_specified_remaining,
-            amm_config.trade_fee_rate,
+            trade_fee_rate,
             is_base_input,
             zero_for_one,
             block_timestamp,
@@ -295,6 +375,17 @@ pub fn swap_internal<'b, 'info>(
             step.fee_amount = step.fee_amount.checked_sub(delta).unwrap();
             state.fund_fee = state.fund_fee.checked_add(delta).unwrap();
         }
+        // if there is a referrer, calculate its share, decrement fee_amount, and increment referral_fee
+        if referral_fee_rate > 0 {
+            let delta = U128::from(step_fee_amount)
+                .checked_mul(referral_fee_rate.into())
+                .unwrap()
+                .checked_div(FEE_RATE_DENOMINATOR_VALUE.into())
+                .unwrap()
+                .as_u64();
+            step.fee_amount = step.fee_amount.checked_sub(delta).unwrap();
+            state.referral_fee = state.referral_fee.checked_add(delta).unwrap();
+        }
 
         // update global fee tracker
         if state.liquidity > 0 {
@@ -333,8 +424,15 @@ pub fn swap_internal<'b, 'info>(
                     } else {
                         state.fee_growth_global_x64
                     },
+                    pool_state.seconds_per_liquidity_global_x64,
                     &updated_reward_infos,
                 );
+                // the range orders waiting on this tick are filled by the crossing
+                tick_array_current.fill_range_orders(
+                    &mut next_initialized_tick,
+                    zero_for_one,
+                    pool_state.tick_spacing,
+                )?;
                 // update tick_state to tick_array account
                 tick_array_current.update_tick_state(
                     next_initialized_tick.tick,
@@ -388,13 +486,26 @@ pub fn swap_internal<'b, 'info>(
         //     zero_for_one,
         // });
     }
+    PoolState::check_twap_guard(
+        twap_guard_sqrt_price_x64,
+        state.sqrt_price_x64,
+        zero_for_one,
+    )?;
     // update tick
     if state.tick != pool_state.tick_current {
         // update the previous tick to the observation
-        observation_state.update(block_timestamp, pool_state.tick_current);
+        observation_state.update(
+            observation_extension,
+            block_timestamp,
+            pool_state.tick_current,
+            pool_state.seconds_per_liquidity_global_x64,
+        );
         pool_state.tick_current = state.tick;
     }
     pool_state.sqrt_price_x64 = state.sqrt_price_x64;
+    if dynamic_fee_enabled {
+        pool_state.update_volatility_accumulator(state.tick, block_timestamp);
+    }
 
     if liquidity_start != state.liquidity {
         pool_state.liquidity = state.liquidity;
@@ -472,7 +583,7 @@ pub fn swap_internal<'b, 'info>(
             .unwrap();
     }
 
-    Ok((amount_0, amount_1))
+    Ok((amount_0, amount_1, state.referral_fee))
 }
 
 #[cfg(test)]
@@ -639,6 +750,7 @@ mod swap_test {
                             i128::try_from(liquidity).unwrap(),
                             0,
                             0,
+                            0,
                             false,
                             &[RewardInfo::default(); 3],
                         )
@@ -660,6 +772,7 @@ mod swap_test {
                             i128::try_from(liquidity).unwrap(),
                             0,
                             0,
+                            0,
                             false,
                             &[RewardInfo::default(); 3],
                         )
@@ -687,6 +800,7 @@ mod swap_test {
                             i128::try_from(liquidity).unwrap(),
                             0,
                             0,
+                            0,
                             true,
                             &[RewardInfo::default(); 3],
                         )
@@ -709,6 +823,7 @@ mod swap_test {
                             i128::try_from(liquidity).unwrap(),
                             0,
                             0,
+                            0,
                             true,
                             &[RewardInfo::default(); 3],
                         )
@@ -804,6 +919,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 12188240002,
                 3049500711113990606,
@@ -835,6 +951,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 121882400020,
                 3049500711113990606,
@@ -863,6 +980,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 60941200010,
                 3049500711113990606,
@@ -920,6 +1038,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 477470480,
                 3049500711113990606,
@@ -951,6 +1070,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 4751002622,
                 3049500711113990606,
@@ -979,6 +1099,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 2358130642,
                 3049500711113990606,
@@ -1036,6 +1157,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 887470480,
                 5882283448660210779,
@@ -1066,6 +1188,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 3087470480,
                 5882283448660210779,
@@ -1095,6 +1218,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 200941200010,
                 5882283448660210779,
@@ -1152,6 +1276,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 22796232052,
                 5882283448660210779,
@@ -1182,6 +1307,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 79023558189,
                 5882283448660210779,
@@ -1211,6 +1337,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 4315086194758,
                 5882283448660210779,
@@ -1261,6 +1388,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 12188240002,
                 tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1306,6 +1434,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 12188240002,
                 tick_math::get_sqrt_price_at_tick(-28860).unwrap(),
@@ -1351,6 +1480,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 12188240002,
                 tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1392,6 +1522,7 @@ mod swap_test {
             &mut pool_state.borrow_mut(),
             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
             &mut observation_state.borrow_mut(),
+            &mut [],
             &None,
             25,
             tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1415,6 +1546,7 @@ mod swap_test {
             &mut pool_state.borrow_mut(),
             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
             &mut observation_state.borrow_mut(),
+            &mut [],
             &None,
             3,
             tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1441,6 +1573,7 @@ mod swap_test {
             &mut pool_state.borrow_mut(),
             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
             &mut observation_state.borrow_mut(),
+            &mut [],
             &None,
             50,
             tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1494,6 +1627,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 27,
                 tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1521,6 +1655,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 1,
                 tick_math::get_sqrt_price_at_tick(-32400).unwrap(),
@@ -1546,6 +1681,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &None,
                 10,
                 tick_math::get_sqrt_price_at_tick(-28800).unwrap(),
@@ -1565,6 +1701,429 @@ mod swap_test {
     }
 
     #[cfg(test)]
+    mod dynamic_fee_test {
+        use super::*;
+        use std::convert::identity;
+
+        fn swap_twice(dynamic_fee_enabled: bool) -> (u64, u64, PoolState) {
+            let tick_spacing = 10;
+            let (
+                mut amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension_state,
+                _,
+                _,
+            ) = setup_swap_test(
+                0,
+                tick_spacing,
+                vec![OpenPositionParam {
+                    amount_0: 1_000_000_000,
+                    amount_1: 1_000_000_000,
+                    tick_lower: -1000,
+                    tick_upper: 1000,
+                }],
+                true,
+            );
+            if dynamic_fee_enabled {
+                amm_config.dynamic_fee_min_rate = amm_config.trade_fee_rate;
+                amm_config.dynamic_fee_max_rate = 100_000;
+            }
+
+            let mut fees = [0u64; 2];
+            for (i, block_timestamp) in [1000, 1010].into_iter().enumerate() {
+                let fees_before = pool_state.borrow().total_fees_token_0;
+                swap_internal(
+                    &amm_config,
+                    &mut pool_state.borrow_mut(),
+                    &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                    &mut observation_state.borrow_mut(),
+                    &mut [],
+                    &Some(bitmap_extension_state),
+                    50_000_000,
+                    tick_math::get_sqrt_price_at_tick(-1000).unwrap(),
+                    true,
+                    true,
+                    block_timestamp,
+                )
+                .unwrap();
+                fees[i] = pool_state.borrow().total_fees_token_0 - fees_before;
+            }
+            let pool = *pool_state.borrow();
+            (fees[0], fees[1], pool)
+        }
+
+        #[test]
+        fn dynamic_fee_grows_with_volatility_test() {
+            let (static_fee_0, static_fee_1, static_pool) = swap_twice(false);
+            // the static mode does not touch the volatility accumulator
+            assert_eq!(identity(static_pool.volatility_accumulator), 0);
+            assert_eq!(identity(static_pool.volatility_update_time), 0);
+
+            let (dynamic_fee_0, dynamic_fee_1, dynamic_pool) = swap_twice(true);
+            // the first swap starts from the reference tick and pays the min rate
+            assert_eq!(dynamic_fee_0, static_fee_0);
+            // the second swap pays for the movement of the first one
+            assert!(dynamic_fee_1 > static_fee_1);
+            assert_eq!(identity(dynamic_pool.tick_reference), 0);
+            assert_eq!(
+                identity(dynamic_pool.volatility_accumulator),
+                dynamic_pool.tick_current.unsigned_abs()
+            );
+            assert_eq!(identity(dynamic_pool.volatility_update_time), 1010);
+        }
+    }
+
+    mod twap_guard_test {
+        use super::*;
+        use std::convert::identity;
+
+        fn swap_with_twap_guard(mode: TwapGuardMode) -> (Result<(u64, u64)>, PoolState) {
+            let (
+                amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension,
+                _,
+                _,
+            ) = setup_swap_test(
+                0,
+                10,
+                vec![OpenPositionParam {
+                    amount_0: 1_000_000_000,
+                    amount_1: 1_000_000_000,
+                    tick_lower: -1000,
+                    tick_upper: 1000,
+                }],
+                true,
+            );
+            // without any observation the bound is 10 ticks below the current tick
+            pool_state
+                .borrow_mut()
+                .set_twap_guard(600, 10, mode as u8)
+                .unwrap();
+            let result = swap_internal(
+                &amm_config,
+                &mut pool_state.borrow_mut(),
+                &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                &mut observation_state.borrow_mut(),
+                &mut [],
+                &Some(bitmap_extension),
+                50_000_000,
+                tick_math::get_sqrt_price_at_tick(-1000).unwrap(),
+                true,
+                true,
+                1000,
+            );
+            let pool = *pool_state.borrow();
+            (result, pool)
+        }
+
+        #[test]
+        fn reject_mode_test() {
+            let (result, _) = swap_with_twap_guard(TwapGuardMode::Reject);
+            assert_eq!(result.unwrap_err(), ErrorCode::TwapDeviationExceeded.into());
+        }
+
+        #[test]
+        fn cap_mode_test() {
+            let (result, pool) = swap_with_twap_guard(TwapGuardMode::Cap);
+            let (amount_0, _) = result.unwrap();
+            // the swap stops at the bound like at a price limit
+            assert!(amount_0 < 50_000_000);
+            assert_eq!(
+                identity(pool.sqrt_price_x64),
+                tick_math::get_sqrt_price_at_tick(-10).unwrap()
+            );
+        }
+    }
+
+    mod referral_fee_test {
+        use super::*;
+
+        fn swap_with_referral(
+            max_referral_fee_rate: u32,
+            referral_fee_rate: u32,
+        ) -> (Result<(u64, u64, u64)>, PoolState) {
+            let (
+                mut amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension,
+                _,
+                _,
+            ) = setup_swap_test(
+                0,
+                10,
+                vec![OpenPositionParam {
+                    amount_0: 1_000_000_000,
+                    amount_1: 1_000_000_000,
+                    tick_lower: -1000,
+                    tick_upper: 1000,
+                }],
+                true,
+            );
+            amm_config.protocol_fee_rate = 120_000;
+            amm_config.max_referral_fee_rate = max_referral_fee_rate;
+            let result = swap_internal_with_referral(
+                &amm_config,
+                &mut pool_state.borrow_mut(),
+                &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                &mut observation_state.borrow_mut(),
+                &mut [],
+                &Some(bitmap_extension),
+                1_000_000,
+                tick_math::get_sqrt_price_at_tick(-1000).unwrap(),
+                true,
+                true,
+                1000,
+                referral_fee_rate,
+            );
+            let pool = *pool_state.borrow();
+            (result, pool)
+        }
+
+        #[test]
+        fn referral_fee_split_test() {
+            let (result, pool) = swap_with_referral(100_000, 0);
+            let (amount_0, amount_1, referral_fee) = result.unwrap();
+            assert_eq!(referral_fee, 0);
+            let protocol_fee = pool.protocol_fees_token_0;
+
+            let (result, referral_pool) = swap_with_referral(100_000, 100_000);
+            let (referral_amount_0, referral_amount_1, referral_fee) = result.unwrap();
+            // the referral fee is split off the trade fee, the trader pays the same
+            assert_eq!(referral_amount_0, amount_0);
+            assert_eq!(referral_amount_1, amount_1);
+            // the trade fee of 1000 is split 120 for the protocol and 100 for the referrer
+            assert_eq!(referral_fee, 100);
+            assert_eq!(referral_pool.protocol_fees_token_0, protocol_fee);
+            assert_eq!(
+                referral_pool.total_fees_token_0,
+                pool.total_fees_token_0 - referral_fee
+            );
+        }
+
+        #[test]
+        fn referral_fee_rate_exceeds_max_test() {
+            let (result, _) = swap_with_referral(100_000, 100_001);
+            assert_eq!(
+                result.unwrap_err(),
+                ErrorCode::InvalidReferralFeeRate.into()
+            );
+        }
+    }
+
+    mod flash_loan_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn borrow_swap_repay_test() {
+            let (
+                mut amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension,
+                _,
+                _,
+            ) = setup_swap_test(
+                0,
+                10,
+                vec![OpenPositionParam {
+                    amount_0: 1_000_000_000,
+                    amount_1: 1_000_000_000,
+                    tick_lower: -1000,
+                    tick_upper: 1000,
+                }],
+                true,
+            );
+            amm_config.flash_loan_fee_rate = 2000;
+            let swap = || {
+                swap_internal(
+                    &amm_config,
+                    &mut pool_state.borrow_mut(),
+                    &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                    &mut observation_state.borrow_mut(),
+                    &mut [],
+                    &Some(bitmap_extension),
+                    1_000_000,
+                    tick_math::get_sqrt_price_at_tick(-1000).unwrap(),
+                    true,
+                    true,
+                    oracle::block_timestamp_mock() as u32,
+                )
+            };
+            let liquidity = pool_state.borrow().liquidity;
+            let fee_growth_global_0_x64 = pool_state.borrow().fee_growth_global_0_x64;
+
+            // borrow, the vaults are short of the loan until it is repaid
+            pool_state
+                .borrow_mut()
+                .open_flash_loan(500_000_000, 0)
+                .unwrap();
+            assert_eq!(
+                identity(pool_state.borrow().flash_loan_liquidity),
+                liquidity
+            );
+
+            // the swap fails instead of seeing the emptied vaults
+            assert_eq!(swap().unwrap_err(), ErrorCode::NotApproved.into());
+            assert_eq!(identity(pool_state.borrow().liquidity), liquidity);
+            assert_eq!(identity(pool_state.borrow().status), 0);
+
+            // repay, the fee goes to the liquidity of the borrow
+            assert_eq!(
+                pool_state
+                    .borrow_mut()
+                    .close_flash_loan(&amm_config)
+                    .unwrap(),
+                (500_000_000, 0, 1_000_000, 0)
+            );
+            assert!(
+                identity(pool_state.borrow().fee_growth_global_0_x64) > fee_growth_global_0_x64
+            );
+
+            // the pool works again once the loan is repaid
+            let (amount_0, _) = swap().unwrap();
+            assert_eq!(amount_0, 1_000_000);
+        }
+    }
+
+    mod range_order_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn range_order_fees_before_fill_test() {
+            let tick_spacing = 10;
+            let order_liquidity = liquidity_math::get_liquidity_from_amounts(
+                tick_math::get_sqrt_price_at_tick(5).unwrap(),
+                tick_math::get_sqrt_price_at_tick(10).unwrap(),
+                tick_math::get_sqrt_price_at_tick(20).unwrap(),
+                1_000_000,
+                0,
+            );
+            let (
+                amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension,
+                _,
+                _,
+            ) = setup_swap_test(
+                5,
+                tick_spacing,
+                vec![
+                    OpenPositionParam {
+                        amount_0: 1_000_000_000,
+                        amount_1: 1_000_000_000,
+                        tick_lower: 0,
+                        tick_upper: 500,
+                    },
+                    OpenPositionParam {
+                        amount_0: 1_000_000,
+                        amount_1: 0,
+                        tick_lower: 10,
+                        tick_upper: 20,
+                    },
+                ],
+                false,
+            );
+            let swap_to_tick = |tick: i32, zero_for_one: bool| {
+                swap_internal(
+                    &amm_config,
+                    &mut pool_state.borrow_mut(),
+                    &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                    &mut observation_state.borrow_mut(),
+                    &mut [],
+                    &Some(bitmap_extension),
+                    1_000_000_000,
+                    tick_math::get_sqrt_price_at_tick(tick).unwrap(),
+                    zero_for_one,
+                    true,
+                    oracle::block_timestamp_mock() as u32,
+                )
+                .unwrap();
+            };
+            let fee_growth_inside = || {
+                let pool = pool_state.borrow();
+                let tick_array = tick_array_states[0].borrow();
+                get_fee_growth_inside(
+                    tick_array.get_tick_state(10, tick_spacing).unwrap(),
+                    tick_array.get_tick_state(20, tick_spacing).unwrap(),
+                    pool.tick_current,
+                    pool.fee_growth_global_0_x64,
+                    pool.fee_growth_global_1_x64,
+                )
+            };
+            let fill_tick = || {
+                *tick_array_states[0]
+                    .borrow()
+                    .get_tick_state(20, tick_spacing)
+                    .unwrap()
+            };
+
+            // the order sells token_0 on [10, 20] and is filled when the price crosses tick 20 upward
+            tick_array_states[0]
+                .borrow_mut()
+                .get_tick_state_mut(20, tick_spacing)
+                .unwrap()
+                .range_order_liquidity = order_liquidity;
+            let (fee_growth_inside_0_x64, fee_growth_inside_1_x64) = fee_growth_inside();
+            let mut personal_position = PersonalPositionState {
+                tick_lower_index: 10,
+                tick_upper_index: 20,
+                liquidity: order_liquidity,
+                fee_growth_inside_0_last_x64: fee_growth_inside_0_x64,
+                fee_growth_inside_1_last_x64: fee_growth_inside_1_x64,
+                range_order: true,
+                range_order_zero_for_one: true,
+                range_order_fill_count: fill_tick().range_order_fill_count,
+                ..Default::default()
+            };
+
+            // the price moves into the range, the order earns fees but is not filled
+            swap_to_tick(15, false);
+            assert_eq!(identity(fill_tick().range_order_fill_count), 0);
+            assert_eq!(identity(fill_tick().range_order_liquidity), order_liquidity);
+
+            // the price crosses tick 20, the order is filled
+            swap_to_tick(30, false);
+            let filled_tick = fill_tick();
+            assert_eq!(identity(filled_tick.range_order_fill_count), 1);
+            assert_eq!(identity(filled_tick.range_order_liquidity), 0);
+            assert_eq!(identity(filled_tick.liquidity_net), 0);
+            let fee_growth_inside_at_fill = fee_growth_inside();
+
+            // the price keeps moving without crossing the fill tick, the growths inside do not change
+            swap_to_tick(40, false);
+            assert_eq!(identity(fill_tick().range_order_fill_count), 1);
+            assert_eq!(fee_growth_inside(), fee_growth_inside_at_fill);
+
+            // the order is settled against the growths of the fill
+            personal_position
+                .update_fee_and_reward(
+                    fee_growth_inside_at_fill.0,
+                    fee_growth_inside_at_fill.1,
+                    [0; REWARD_NUM],
+                    0,
+                )
+                .unwrap();
+            assert_eq!(identity(personal_position.token_fees_owed_0), 0);
+            assert!(identity(personal_position.token_fees_owed_1) > 0);
+
+            // crossing the fill tick again is counted, the growths inside no longer match the fill
+            swap_to_tick(5, true);
+            assert_eq!(identity(fill_tick().range_order_fill_count), 2);
+        }
+    }
+
     mod sqrt_price_limit_optimization_min_specified_test {
         use super::*;
         #[test]
@@ -1607,6 +2166,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -1661,6 +2221,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -1715,6 +2276,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -1768,6 +2330,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -1825,6 +2388,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -1879,6 +2443,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -1933,6 +2498,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -1986,6 +2552,7 @@ mod swap_test {
                 &mut pool_state.borrow_mut(),
                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                 &mut observation_state.borrow_mut(),
+                &mut [],
                 &Some(bitmap_extension_state),
                 amount_specified,
                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2039,6 +2606,7 @@ mod swap_test {
                         &mut pool_state.borrow_mut(),
                         &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                         &mut observation_state.borrow_mut(),
+                        &mut [],
                         &Some(bitmap_extension_state),
                         amount_specified,
                         tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2061,6 +2629,7 @@ mod swap_test {
                             &mut pool_state.borrow_mut(),
                             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                             &mut observation_state.borrow_mut(),
+                            &mut [],
                             &Some(bitmap_extension_state),
                             amount_specified,
                             tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2085,6 +2654,7 @@ mod swap_test {
                                 &mut pool_state.borrow_mut(),
                                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                                 &mut observation_state.borrow_mut(),
+                                &mut [],
                                 &Some(bitmap_extension_state),
                                 amount_specified,
                                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2130,6 +2700,7 @@ mod swap_test {
                         &mut pool_state.borrow_mut(),
                         &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                         &mut observation_state.borrow_mut(),
+                        &mut [],
                         &Some(bitmap_extension_state),
                         amount_specified,
                         tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2152,6 +2723,7 @@ mod swap_test {
                             &mut pool_state.borrow_mut(),
                             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                             &mut observation_state.borrow_mut(),
+                            &mut [],
                             &Some(bitmap_extension_state),
                             amount_specified,
                             tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2176,6 +2748,7 @@ mod swap_test {
                                 &mut pool_state.borrow_mut(),
                                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                                 &mut observation_state.borrow_mut(),
+                                &mut [],
                                 &Some(bitmap_extension_state),
                                 amount_specified,
                                 tick_math::MIN_SQRT_PRICE_X64 + 1,
@@ -2222,6 +2795,7 @@ mod swap_test {
                         &mut pool_state.borrow_mut(),
                         &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                         &mut observation_state.borrow_mut(),
+                        &mut [],
                         &Some(bitmap_extension_state),
                         amount_specified,
                         tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2245,6 +2819,7 @@ mod swap_test {
                             &mut pool_state.borrow_mut(),
                             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                             &mut observation_state.borrow_mut(),
+                            &mut [],
                             &Some(bitmap_extension_state),
                             amount_specified,
                             tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2269,6 +2844,7 @@ mod swap_test {
                                 &mut pool_state.borrow_mut(),
                                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                                 &mut observation_state.borrow_mut(),
+                                &mut [],
                                 &Some(bitmap_extension_state),
                                 amount_specified,
                                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2313,6 +2889,7 @@ mod swap_test {
                         &mut pool_state.borrow_mut(),
                         &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                         &mut observation_state.borrow_mut(),
+                        &mut [],
                         &Some(bitmap_extension_state),
                         amount_specified,
                         tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2335,6 +2912,7 @@ mod swap_test {
                             &mut pool_state.borrow_mut(),
                             &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                             &mut observation_state.borrow_mut(),
+                            &mut [],
                             &Some(bitmap_extension_state),
                             amount_specified,
                             tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2359,6 +2937,7 @@ mod swap_test {
                                 &mut pool_state.borrow_mut(),
                                 &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
                                 &mut observation_state.borrow_mut(),
+                                &mut [],
                                 &Some(bitmap_extension_state),
                                 amount_specified,
                                 tick_math::MAX_SQRT_PRICE_X64 - 1,
@@ -2374,4 +2953,318 @@ mod swap_test {
             }
         }
     }
+
+    #[cfg(test)]
+    mod quote_test {
+        use super::*;
+        use crate::quote::{compute_swap, quote_swap, QuoteSwapParams};
+        use proptest::prelude::*;
+        use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+        use std::convert::identity;
+
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(512))]
+
+            #[test]
+            fn compute_swap_matches_swap_internal_test(
+                tick_current in -20000..20000i32,
+                ranges in prop::collection::vec((-300..300i32, 1..200i32), 1..4),
+                amount in 1_000_000..1_000_000_000_000u64,
+                amount_specified in 1..100_000_000_000u64,
+                zero_for_one in any::<bool>(),
+                is_base_input in any::<bool>(),
+                protocol_fee_rate in 0..=120_000u32,
+                fund_fee_rate in 0..=40_000u32,
+                dynamic_fee_enabled in any::<bool>(),
+            ) {
+                let tick_spacing = 10;
+                let position_params = ranges
+                    .iter()
+                    .map(|(lower, width)| {
+                        let tick_lower = (tick_current / tick_spacing + lower) * tick_spacing;
+                        OpenPositionParam {
+                            amount_0: amount,
+                            amount_1: amount,
+                            tick_lower,
+                            tick_upper: tick_lower + width * tick_spacing,
+                        }
+                    })
+                    .collect();
+                let (
+                    mut amm_config,
+                    pool_state,
+                    tick_array_states,
+                    observation_state,
+                    bitmap_extension_state,
+                    _,
+                    _,
+                ) = setup_swap_test(tick_current, tick_spacing as u16, position_params, zero_for_one);
+                amm_config.protocol_fee_rate = protocol_fee_rate;
+                amm_config.fund_fee_rate = fund_fee_rate;
+                if dynamic_fee_enabled {
+                    amm_config.dynamic_fee_min_rate = amm_config.trade_fee_rate;
+                    amm_config.dynamic_fee_max_rate = 50_000;
+                }
+                let sqrt_price_limit_x64 = if zero_for_one {
+                    tick_math::MIN_SQRT_PRICE_X64 + 1
+                } else {
+                    tick_math::MAX_SQRT_PRICE_X64 - 1
+                };
+
+                let pool_before = *pool_state.borrow();
+                let tick_arrays: Vec<TickArrayState> =
+                    tick_array_states.iter().map(|tick_array| *tick_array.borrow()).collect();
+                let quote = compute_swap(
+                    &amm_config,
+                    &pool_before,
+                    &Some(bitmap_extension_state),
+                    &tick_arrays,
+                    amount_specified,
+                    sqrt_price_limit_x64,
+                    zero_for_one,
+                    is_base_input,
+                    1000,
+                );
+                let result = swap_internal(
+                    &amm_config,
+                    &mut pool_state.borrow_mut(),
+                    &mut get_tick_array_states_mut(&tick_array_states).borrow_mut(),
+                    &mut observation_state.borrow_mut(),
+                    &mut [],
+                    &Some(bitmap_extension_state),
+                    amount_specified,
+                    sqrt_price_limit_x64,
+                    zero_for_one,
+                    is_base_input,
+                    1000,
+                );
+
+                match (quote, result) {
+                    (Ok(quote), Ok((amount_0, amount_1))) => {
+                        let pool = pool_state.borrow();
+                        let (amount_in, amount_out) = if zero_for_one {
+                            (amount_0, amount_1)
+                        } else {
+                            (amount_1, amount_0)
+                        };
+                        prop_assert_eq!(quote.amount_in, amount_in);
+                        prop_assert_eq!(quote.amount_out, amount_out);
+                        prop_assert_eq!(quote.sqrt_price_x64_after, identity(pool.sqrt_price_x64));
+                        prop_assert_eq!(quote.tick_after, identity(pool.tick_current));
+                        prop_assert_eq!(quote.liquidity_after, identity(pool.liquidity));
+                        let (protocol_fee, fund_fee, lp_fee) = if zero_for_one {
+                            (
+                                pool.protocol_fees_token_0 - pool_before.protocol_fees_token_0,
+                                pool.fund_fees_token_0 - pool_before.fund_fees_token_0,
+                                pool.total_fees_token_0 - pool_before.total_fees_token_0,
+                            )
+                        } else {
+                            (
+                                pool.protocol_fees_token_1 - pool_before.protocol_fees_token_1,
+                                pool.fund_fees_token_1 - pool_before.fund_fees_token_1,
+                                pool.total_fees_token_1 - pool_before.total_fees_token_1,
+                            )
+                        };
+                        prop_assert_eq!(quote.protocol_fee, protocol_fee);
+                        prop_assert_eq!(quote.fund_fee, fund_fee);
+                        prop_assert_eq!(quote.lp_fee, lp_fee);
+                        // the reported tick arrays are enough to execute the swap in order
+                        let used_tick_arrays: Vec<RefCell<TickArrayState>> = quote
+                            .tick_array_start_indexes
+                            .iter()
+                            .map(|start_index| {
+                                RefCell::new(
+                                    *tick_arrays
+                                        .iter()
+                                        .find(|tick_array| tick_array.start_tick_index == *start_index)
+                                        .unwrap(),
+                                )
+                            })
+                            .collect();
+                        let used_tick_arrays: VecDeque<RefCell<TickArrayState>> =
+                            used_tick_arrays.into_iter().collect();
+                        let replay_pool_state = RefCell::new(pool_before);
+                        let replay_observation_state = RefCell::new(ObservationState::default());
+                        replay_observation_state.borrow_mut().pool_id = pool_before.key();
+                        let replay = swap_internal(
+                            &amm_config,
+                            &mut replay_pool_state.borrow_mut(),
+                            &mut get_tick_array_states_mut(&used_tick_arrays).borrow_mut(),
+                            &mut replay_observation_state.borrow_mut(),
+                            &mut [],
+                            &Some(bitmap_extension_state),
+                            amount_specified,
+                            sqrt_price_limit_x64,
+                            zero_for_one,
+                            is_base_input,
+                            1000,
+                        );
+                        prop_assert_eq!(replay.unwrap(), (amount_0, amount_1));
+                    }
+                    (Err(_), Err(_)) => {}
+                    (quote, result) => {
+                        prop_assert!(false, "quote {:?} does not match swap {:?}", quote, result);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn quote_swap_with_transfer_fee_test() {
+            let tick_spacing = 10;
+            let (amm_config, pool_state, tick_array_states, _, bitmap_extension_state, _, _) =
+                setup_swap_test(
+                    0,
+                    tick_spacing,
+                    vec![OpenPositionParam {
+                        amount_0: 1_000_000_000,
+                        amount_1: 1_000_000_000,
+                        tick_lower: -1000,
+                        tick_upper: 1000,
+                    }],
+                    true,
+                );
+            let tick_arrays: Vec<TickArrayState> = tick_array_states
+                .iter()
+                .map(|tick_array| *tick_array.borrow())
+                .collect();
+            // 1% transfer fee on both mints
+            let mut transfer_fee_config = TransferFeeConfig::default();
+            transfer_fee_config
+                .newer_transfer_fee
+                .transfer_fee_basis_points = 100u16.into();
+            transfer_fee_config.newer_transfer_fee.maximum_fee = u64::MAX.into();
+
+            let params = QuoteSwapParams {
+                amount_specified: 1_000_000,
+                zero_for_one: true,
+                is_base_input: true,
+                block_timestamp: 1000,
+                input_transfer_fee_config: Some(&transfer_fee_config),
+                output_transfer_fee_config: Some(&transfer_fee_config),
+                ..Default::default()
+            };
+            let quote = quote_swap(
+                &amm_config,
+                &pool_state.borrow(),
+                &Some(bitmap_extension_state),
+                &tick_arrays,
+                &params,
+            )
+            .unwrap();
+            assert_eq!(quote.transfer_fee_in, 10_000);
+            assert_eq!(quote.computation.amount_in, 990_000);
+            assert_eq!(quote.transfer_amount_in, 1_000_000);
+            assert_eq!(
+                quote.transfer_fee_out,
+                (quote.computation.amount_out + 99) / 100
+            );
+            assert_eq!(
+                quote.transfer_amount_out,
+                quote.computation.amount_out - quote.transfer_fee_out
+            );
+
+            // the user receives exactly the specified amount in the base output mode
+            let params = QuoteSwapParams {
+                amount_specified: 1_000_000,
+                is_base_input: false,
+                ..params
+            };
+            let quote = quote_swap(
+                &amm_config,
+                &pool_state.borrow(),
+                &Some(bitmap_extension_state),
+                &tick_arrays,
+                &params,
+            )
+            .unwrap();
+            assert_eq!(quote.transfer_amount_out, 1_000_000);
+            assert_eq!(
+                quote.transfer_amount_in,
+                quote.computation.amount_in + quote.transfer_fee_in
+            );
+        }
+
+        #[test]
+        fn quote_swap_with_twap_guard_test() {
+            let (
+                amm_config,
+                pool_state,
+                tick_array_states,
+                observation_state,
+                bitmap_extension,
+                _,
+                _,
+            ) = setup_swap_test(
+                0,
+                10,
+                vec![OpenPositionParam {
+                    amount_0: 1_000_000_000,
+                    amount_1: 1_000_000_000,
+                    tick_lower: -1000,
+                    tick_upper: 1000,
+                }],
+                true,
+            );
+            let tick_arrays: Vec<TickArrayState> = tick_array_states
+                .iter()
+                .map(|tick_array| *tick_array.borrow())
+                .collect();
+            let observation_state = observation_state.borrow();
+            let params = QuoteSwapParams {
+                amount_specified: 50_000_000,
+                sqrt_price_limit_x64: tick_math::get_sqrt_price_at_tick(-1000).unwrap(),
+                zero_for_one: true,
+                is_base_input: true,
+                block_timestamp: 1000,
+                observation_state: Some(&observation_state),
+                ..Default::default()
+            };
+
+            // without any observation the bound is 10 ticks below the current tick
+            let mut pool = *pool_state.borrow();
+            pool.set_twap_guard(600, 10, TwapGuardMode::Reject as u8)
+                .unwrap();
+            let result = quote_swap(
+                &amm_config,
+                &pool,
+                &Some(bitmap_extension),
+                &tick_arrays,
+                &params,
+            );
+            assert_eq!(result.unwrap_err(), ErrorCode::TwapDeviationExceeded.into());
+
+            // the swap stops at the bound like the program does
+            pool.set_twap_guard(600, 10, TwapGuardMode::Cap as u8)
+                .unwrap();
+            let quote = quote_swap(
+                &amm_config,
+                &pool,
+                &Some(bitmap_extension),
+                &tick_arrays,
+                &params,
+            )
+            .unwrap();
+            assert!(quote.computation.amount_in < 50_000_000);
+            assert_eq!(
+                quote.computation.sqrt_price_x64_after,
+                tick_math::get_sqrt_price_at_tick(-10).unwrap()
+            );
+
+            // the twap guard is skipped without the observation account
+            let params = QuoteSwapParams {
+                observation_state: None,
+                ..params
+            };
+            let quote = quote_swap(
+                &amm_config,
+                &pool,
+                &Some(bitmap_extension),
+                &tick_arrays,
+                &params,
+            )
+            .unwrap();
+            assert_eq!(quote.computation.amount_in, 50_000_000);
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use crate::error::ErrorCode;
 use crate::states::*;
-use crate::swap_v2::{exact_internal_v2, SwapSingleV2};
+use crate::swap_v2::{exact_internal_v2, SwapReferral, SwapSingleV2};
 use anchor_lang::prelude::*;
 use anchor_spl::memo::Memo;
 use anchor_spl::{
@@ -30,21 +30,63 @@ pub struct SwapRouterBaseIn<'info> {
     pub memo_program: Program<'info, Memo>,
 }
 
+#[derive(Accounts)]
+pub struct SwapRouterBaseInWithOptions<'info> {
+    /// The accounts of `swap_router_base_in`
+    pub router: SwapRouterBaseIn<'info>,
+
+    /// The token account of the referrer for the input token, receives the referral fee of
+    /// the first hop
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
 pub fn swap_router_base_in<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseIn<'info>>,
     amount_in: u64,
     amount_out_minimum: u64,
+) -> Result<()> {
+    swap_router_base_in_internal(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        amount_in,
+        amount_out_minimum,
+        None,
+    )
+}
+
+/// Swaps like `swap_router_base_in` and pays `referral_fee_rate` of the trade fee of the first
+/// hop to the referrer, if any
+pub fn swap_router_base_in_with_options<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseInWithOptions<'info>>,
+    amount_in: u64,
+    amount_out_minimum: u64,
+    referral_fee_rate: Option<u32>,
+) -> Result<()> {
+    swap_router_base_in_internal(
+        &ctx.accounts.router,
+        ctx.remaining_accounts,
+        amount_in,
+        amount_out_minimum,
+        SwapReferral::from_options(&ctx.accounts.referrer_token_account, referral_fee_rate)?,
+    )
+}
+
+fn swap_router_base_in_internal<'c: 'info, 'info>(
+    router: &SwapRouterBaseIn<'info>,
+    path_accounts: &'c [AccountInfo<'info>],
+    amount_in: u64,
+    amount_out_minimum: u64,
+    mut referral: Option<SwapReferral<'info>>,
 ) -> Result<()> {
     let mut amount_in_internal = amount_in;
-    let mut input_token_account = Box::new(ctx.accounts.input_token_account.clone());
-    let mut input_token_mint = Box::new(ctx.accounts.input_token_mint.clone());
-    let mut accounts: &[AccountInfo] = ctx.remaining_accounts;
+    let mut input_token_account = Box::new(router.input_token_account.clone());
+    let mut input_token_mint = Box::new(router.input_token_mint.clone());
+    let mut accounts: &[AccountInfo] = path_accounts;
     while !accounts.is_empty() {
         let mut remaining_accounts = accounts.iter();
         let account_info = remaining_accounts.next().unwrap();
-        if accounts.len() != ctx.remaining_accounts.len()
-            && account_info.data_len() != AmmConfig::LEN
-        {
+        if accounts.len() != path_accounts.len() && account_info.data_len() != AmmConfig::LEN {
             accounts = remaining_accounts.as_slice();
             continue;
         }
@@ -71,14 +113,14 @@ pub fn swap_router_base_in<'a, 'b, 'c: 'info, 'info>(
             // check observation account is owned by the pool
             require_keys_eq!(pool_state.observation_key, observation_state.key());
             // check ammConfig account is associate with the pool
-            require_keys_eq!(pool_state.amm_config, amm_config.key());
+            require_keys_eq!(pool_state.get_amm_config(), amm_config.key());
         }
 
         // solana_program::log::sol_log_compute_units();
         accounts = remaining_accounts.as_slice();
         amount_in_internal = exact_internal_v2(
             &mut SwapSingleV2 {
-                payer: ctx.accounts.payer.clone(),
+                payer: router.payer.clone(),
                 amm_config,
                 input_token_account: input_token_account.clone(),
                 pool_state: pool_state_loader,
@@ -88,14 +130,16 @@ pub fn swap_router_base_in<'a, 'b, 'c: 'info, 'info>(
                 input_vault_mint: input_token_mint.clone(),
                 output_vault_mint: output_token_mint.clone(),
                 observation_state,
-                token_program: ctx.accounts.token_program.clone(),
-                token_program_2022: ctx.accounts.token_program_2022.clone(),
-                memo_program: ctx.accounts.memo_program.clone(),
+                token_program: router.token_program.clone(),
+                token_program_2022: router.token_program_2022.clone(),
+                memo_program: router.memo_program.clone(),
             },
             accounts,
             amount_in_internal,
             0,
             true,
+            // only the first hop is charged the referral fee
+            referral.take().as_ref(),
         )?;
         // output token is the new swap input token
         input_token_account = output_token_account;
@@ -0,0 +1,180 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::swap_v2::{settle_swap_v2, swap_pool_v2, SwapReferral, SwapSingleV2};
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, Token2022, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct SwapRouterBaseOut<'info> {
+    /// The user performing the swap
+    pub payer: Signer<'info>,
+
+    /// The token account that pays input tokens for the swap
+    #[account(mut)]
+    pub input_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint of input token
+    #[account(mut)]
+    pub input_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// Memo program
+    pub memo_program: Program<'info, Memo>,
+}
+
+#[derive(Accounts)]
+pub struct SwapRouterBaseOutWithOptions<'info> {
+    /// The accounts of `swap_router_base_out`
+    pub router: SwapRouterBaseOut<'info>,
+
+    /// The token account of the referrer for the input token, receives the referral fee of
+    /// the first hop
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+pub fn swap_router_base_out<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOut<'info>>,
+    amount_out: u64,
+    amount_in_maximum: u64,
+) -> Result<()> {
+    swap_router_base_out_internal(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        amount_out,
+        amount_in_maximum,
+        None,
+    )
+}
+
+/// Swaps like `swap_router_base_out` and pays `referral_fee_rate` of the trade fee of the first
+/// hop to the referrer, if any
+pub fn swap_router_base_out_with_options<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOutWithOptions<'info>>,
+    amount_out: u64,
+    amount_in_maximum: u64,
+    referral_fee_rate: Option<u32>,
+) -> Result<()> {
+    swap_router_base_out_internal(
+        &ctx.accounts.router,
+        ctx.remaining_accounts,
+        amount_out,
+        amount_in_maximum,
+        SwapReferral::from_options(&ctx.accounts.referrer_token_account, referral_fee_rate)?,
+    )
+}
+
+fn swap_router_base_out_internal<'c: 'info, 'info>(
+    router: &SwapRouterBaseOut<'info>,
+    path_accounts: &'c [AccountInfo<'info>],
+    amount_out: u64,
+    amount_in_maximum: u64,
+    referral: Option<SwapReferral<'info>>,
+) -> Result<()> {
+    let mut hops: Vec<(SwapSingleV2<'info>, &'c [AccountInfo<'info>])> = Vec::new();
+    let mut input_token_account = Box::new(router.input_token_account.clone());
+    let mut input_token_mint = Box::new(router.input_token_mint.clone());
+    let mut accounts: &'c [AccountInfo<'info>] = path_accounts;
+    while !accounts.is_empty() {
+        let mut remaining_accounts = accounts.iter();
+        let account_info = remaining_accounts.next().unwrap();
+        if accounts.len() != path_accounts.len() && account_info.data_len() != AmmConfig::LEN {
+            accounts = remaining_accounts.as_slice();
+            continue;
+        }
+        let amm_config = Box::new(Account::<AmmConfig>::try_from(account_info)?);
+        let pool_state_loader =
+            AccountLoader::<PoolState>::try_from(remaining_accounts.next().unwrap())?;
+        let output_token_account = Box::new(InterfaceAccount::<TokenAccount>::try_from(
+            &remaining_accounts.next().unwrap(),
+        )?);
+        let input_vault = Box::new(InterfaceAccount::<TokenAccount>::try_from(
+            remaining_accounts.next().unwrap(),
+        )?);
+        let output_vault = Box::new(InterfaceAccount::<TokenAccount>::try_from(
+            remaining_accounts.next().unwrap(),
+        )?);
+        let output_token_mint = Box::new(InterfaceAccount::<Mint>::try_from(
+            remaining_accounts.next().unwrap(),
+        )?);
+        let observation_state =
+            AccountLoader::<ObservationState>::try_from(remaining_accounts.next().unwrap())?;
+
+        {
+            let pool_state = pool_state_loader.load()?;
+            // check observation account is owned by the pool
+            require_keys_eq!(pool_state.observation_key, observation_state.key());
+            // check ammConfig account is associate with the pool
+            require_keys_eq!(pool_state.get_amm_config(), amm_config.key());
+        }
+
+        accounts = remaining_accounts.as_slice();
+        hops.push((
+            SwapSingleV2 {
+                payer: router.payer.clone(),
+                amm_config,
+                input_token_account: input_token_account.clone(),
+                pool_state: pool_state_loader,
+                output_token_account: output_token_account.clone(),
+                input_vault,
+                output_vault,
+                input_vault_mint: input_token_mint.clone(),
+                output_vault_mint: output_token_mint.clone(),
+                observation_state,
+                token_program: router.token_program.clone(),
+                token_program_2022: router.token_program_2022.clone(),
+                memo_program: router.memo_program.clone(),
+            },
+            accounts,
+        ));
+        // output token is the new swap input token
+        input_token_account = output_token_account;
+        input_token_mint = output_token_mint;
+    }
+
+    // walk the pools in reverse, every hop must output exactly what the next hop pays in
+    let mut amount_out_internal = amount_out;
+    let mut hop_amounts = Vec::with_capacity(hops.len());
+    for (index, (swap_accounts, remaining_accounts)) in hops.iter_mut().enumerate().rev() {
+        let amounts = swap_pool_v2(
+            swap_accounts,
+            *remaining_accounts,
+            amount_out_internal,
+            0,
+            false,
+            // only the first hop is charged the referral fee
+            referral.as_ref().filter(|_| index == 0),
+        )?;
+        amount_out_internal = amounts.transfer_amount_in();
+        hop_amounts.push(amounts);
+    }
+    require_gte!(
+        amount_in_maximum,
+        amount_out_internal,
+        ErrorCode::TooMuchInputPaid
+    );
+
+    // settle the hops in order, so the intermediate tokens are received before they are paid
+    for (index, ((swap_accounts, _), amounts)) in
+        hops.iter_mut().zip(hop_amounts.iter().rev()).enumerate()
+    {
+        swap_accounts.input_token_account.reload()?;
+        swap_accounts.output_token_account.reload()?;
+        settle_swap_v2(
+            swap_accounts,
+            amounts,
+            referral.as_ref().filter(|_| index == 0),
+            path_accounts,
+        )?;
+    }
+
+    Ok(())
+}
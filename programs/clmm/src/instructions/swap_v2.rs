@@ -3,10 +3,10 @@ use std::ops::Deref;
 
 use crate::error::ErrorCode;
 use crate::libraries::tick_math;
-use crate::swap::swap_internal;
+use crate::swap::swap_internal_with_referral;
 use crate::util::*;
 use crate::{states::*, util};
-use anchor_lang::{prelude::*, solana_program};
+use anchor_lang::prelude::*;
 use anchor_spl::memo::Memo;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
@@ -19,7 +19,7 @@ pub struct SwapSingleV2<'info> {
     pub payer: Signer<'info>,
 
     /// The factory state to read protocol fees
-    #[account(address = pool_state.load()?.amm_config)]
+    #[account(address = pool_state.load()?.get_amm_config())]
     pub amm_config: Box<Account<'info, AmmConfig>>,
 
     /// The program account of the pool in which the swap will be performed
@@ -71,6 +71,74 @@ pub struct SwapSingleV2<'info> {
     // tick_array_account_1
     // tick_array_account_2
     // tick_array_account_...
+    // transfer hook accounts of the vault mints, if any
+}
+
+#[derive(Accounts)]
+pub struct SwapSingleV2WithOptions<'info> {
+    /// The accounts of `swap_v2`
+    pub swap: SwapSingleV2<'info>,
+
+    /// The token account of the referrer for the input token, receives the referral fee
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// The referrer of a swap, paid its share of the trade fee out of the input vault
+pub struct SwapReferral<'info> {
+    /// The token account of the referrer for the input token
+    pub referrer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The share of the trade fee paid to the referrer, capped by the amm config
+    pub referral_fee_rate: u32,
+}
+
+impl<'info> SwapReferral<'info> {
+    /// The referral of a swap with options, the rate and the referrer token account
+    /// must be given together
+    pub fn from_options(
+        referrer_token_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        referral_fee_rate: Option<u32>,
+    ) -> Result<Option<Self>> {
+        match (referrer_token_account, referral_fee_rate) {
+            (Some(referrer_token_account), Some(referral_fee_rate)) => Ok(Some(Self {
+                referrer_token_account: referrer_token_account.clone(),
+                referral_fee_rate,
+            })),
+            (None, None) => Ok(None),
+            _ => err!(ErrorCode::InvalidReferral),
+        }
+    }
+}
+
+/// The amounts of a swap which has been applied to the pool but not transferred yet
+pub struct SwapV2Amounts {
+    pub zero_for_one: bool,
+    pub is_base_input: bool,
+    pub amount_specified: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub swap_price_before: u128,
+    // user or pool real amount delta without tranfer fee
+    pub amount_0_without_fee: u64,
+    pub amount_1_without_fee: u64,
+    // the transfer fee amount charged by withheld_amount
+    pub transfer_fee_0: u64,
+    pub transfer_fee_1: u64,
+    // transfer amount
+    pub transfer_amount_0: u64,
+    pub transfer_amount_1: u64,
+    // the part of the input amount paid to the referrer
+    pub referral_fee: u64,
+}
+
+impl SwapV2Amounts {
+    /// The amount the user sends, transfer fee included
+    pub fn transfer_amount_in(&self) -> u64 {
+        if self.zero_for_one {
+            self.transfer_amount_0
+        } else {
+            self.transfer_amount_1
+        }
+    }
 }
 
 /// Performs a single exact input/output swap
@@ -81,18 +149,54 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
     amount_specified: u64,
     sqrt_price_limit_x64: u128,
     is_base_input: bool,
+    referral: Option<&SwapReferral<'info>>,
 ) -> Result<u64> {
-    // invoke_memo_instruction(SWAP_MEMO_MSG, ctx.memo_program.to_account_info())?;
+    let amounts = swap_pool_v2(
+        ctx,
+        remaining_accounts,
+        amount_specified,
+        sqrt_price_limit_x64,
+        is_base_input,
+        referral,
+    )?;
+    settle_swap_v2(ctx, &amounts, referral, remaining_accounts)
+}
 
-    let block_timestamp = solana_program::clock::Clock::get()?.unix_timestamp as u64;
+/// Applies the swap to the pool state and calculates the transfer amounts, no token is transferred
+/// The referral fee is only charged if a referral is provided
+pub fn swap_pool_v2<'c: 'info, 'info>(
+    ctx: &mut SwapSingleV2<'info>,
+    remaining_accounts: &'c [AccountInfo<'info>],
+    amount_specified: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    referral: Option<&SwapReferral<'info>>,
+) -> Result<SwapV2Amounts> {
+    // invoke_memo_instruction(SWAP_MEMO_MSG, ctx.memo_program.to_account_info())?;
 
     let amount_0;
     let amount_1;
     let zero_for_one;
     let swap_price_before;
-
-    let input_balance_before = ctx.input_token_account.amount;
-    let output_balance_before = ctx.output_token_account.amount;
+    let referral_fee;
+
+    let referral_fee_rate = match referral {
+        Some(referral) => {
+            require_keys_eq!(
+                referral.referrer_token_account.mint,
+                ctx.input_vault.mint,
+                ErrorCode::InvalidReferrerTokenAccount
+            );
+            // a referral fee paid back into the input vault would stay in the pool unaccounted
+            require_keys_neq!(
+                referral.referrer_token_account.key(),
+                ctx.input_vault.key(),
+                ErrorCode::InvalidReferrerTokenAccount
+            );
+            referral.referral_fee_rate
+        }
+        None => 0,
+    };
 
     // calculate specified amount because the amount includes transfer_fee as input and without transfer_fee as output
     let (amount_calculate_specified, transfer_fee) = if is_base_input {
@@ -111,8 +215,6 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
         let pool_state = &mut ctx.pool_state.load_mut()?;
         zero_for_one = ctx.input_vault.mint == pool_state.token_mint_0;
 
-        require_gt!(block_timestamp, pool_state.open_time);
-
         require!(
             if zero_for_one {
                 ctx.input_vault.key() == pool_state.token_vault_0
@@ -123,6 +225,12 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             },
             ErrorCode::InvalidInputPoolVault
         );
+        // the routers build the accounts by hand, the transfer fee must be read from the vault mints
+        require!(
+            ctx.input_vault_mint.key() == ctx.input_vault.mint
+                && ctx.output_vault_mint.key() == ctx.output_vault.mint,
+            ErrorCode::InvalidInputPoolVault
+        );
 
         let mut tickarray_bitmap_extension = None;
         let tick_array_states = &mut VecDeque::new();
@@ -143,11 +251,17 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             tick_array_states.push_back(AccountLoad::load_data_mut(account_info)?);
         }
 
-        (amount_0, amount_1) = swap_internal(
+        let observation_account_info = ctx.observation_state.to_account_info();
+        let (mut observation_state, mut observation_extension) =
+            AccountLoad::<ObservationState>::load_data_with_trailing_mut::<Observation>(
+                &observation_account_info,
+            )?;
+        (amount_0, amount_1, referral_fee) = swap_internal_with_referral(
             &ctx.amm_config,
             pool_state,
             tick_array_states,
-            &mut ctx.observation_state.load_mut()?,
+            &mut observation_state,
+            &mut observation_extension,
             &tickarray_bitmap_extension,
             amount_calculate_specified,
             if sqrt_price_limit_x64 == 0 {
@@ -162,6 +276,7 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             zero_for_one,
             is_base_input,
             oracle::block_timestamp(),
+            referral_fee_rate,
         )?;
 
         #[cfg(feature = "enable-log")]
@@ -176,6 +291,96 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             ErrorCode::TooSmallInputOrOutputAmount
         );
     }
+
+    let (vault_0_mint, vault_1_mint) = if zero_for_one {
+        (ctx.input_vault_mint.clone(), ctx.output_vault_mint.clone())
+    } else {
+        (ctx.output_vault_mint.clone(), ctx.input_vault_mint.clone())
+    };
+
+    // user or pool real amount delta without tranfer fee
+    let amount_0_without_fee;
+    let amount_1_without_fee;
+    // the transfer fee amount charged by withheld_amount
+    let transfer_fee_0;
+    let transfer_fee_1;
+    // transfer amount
+    let transfer_amount_0;
+    let transfer_amount_1;
+    if zero_for_one {
+        transfer_fee_0 = if is_base_input && amount_0 == amount_calculate_specified {
+            transfer_fee
+        } else {
+            util::get_transfer_inverse_fee(vault_0_mint, amount_0).unwrap()
+        };
+        transfer_fee_1 = util::get_transfer_fee(vault_1_mint, amount_1).unwrap();
+
+        amount_0_without_fee = amount_0;
+        amount_1_without_fee = amount_1.checked_sub(transfer_fee_1).unwrap();
+        (transfer_amount_0, transfer_amount_1) = (amount_0 + transfer_fee_0, amount_1);
+    } else {
+        transfer_fee_0 = util::get_transfer_fee(vault_0_mint, amount_0).unwrap();
+        transfer_fee_1 = if is_base_input && amount_1 == amount_calculate_specified {
+            transfer_fee
+        } else {
+            util::get_transfer_inverse_fee(vault_1_mint, amount_1).unwrap()
+        };
+
+        amount_0_without_fee = amount_0.checked_sub(transfer_fee_0).unwrap();
+        amount_1_without_fee = amount_1;
+        (transfer_amount_0, transfer_amount_1) = (amount_0, amount_1 + transfer_fee_1);
+    }
+    #[cfg(feature = "enable-log")]
+    msg!(
+        "amount_0:{}, transfer_fee_0:{}, amount_1:{}, transfer_fee_1:{}",
+        amount_0,
+        transfer_fee_0,
+        amount_1,
+        transfer_fee_1
+    );
+
+    Ok(SwapV2Amounts {
+        zero_for_one,
+        is_base_input,
+        amount_specified,
+        sqrt_price_limit_x64,
+        swap_price_before,
+        amount_0_without_fee,
+        amount_1_without_fee,
+        transfer_fee_0,
+        transfer_fee_1,
+        transfer_amount_0,
+        transfer_amount_1,
+        referral_fee,
+    })
+}
+
+/// Transfers the tokens of a swap applied by `swap_pool_v2` and emits the `SwapEvent`,
+/// the transfer hook accounts of the pool mints are resolved from `remaining_accounts`.
+/// `referral` must be the one the swap was applied with
+/// if is_base_input = true, return value is the max_amount_out, otherwise is min_amount_in
+pub fn settle_swap_v2<'info>(
+    ctx: &mut SwapSingleV2<'info>,
+    amounts: &SwapV2Amounts,
+    referral: Option<&SwapReferral<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let zero_for_one = amounts.zero_for_one;
+    let is_base_input = amounts.is_base_input;
+    let amount_specified = amounts.amount_specified;
+    let sqrt_price_limit_x64 = amounts.sqrt_price_limit_x64;
+    let swap_price_before = amounts.swap_price_before;
+    let amount_0_without_fee = amounts.amount_0_without_fee;
+    let amount_1_without_fee = amounts.amount_1_without_fee;
+    let transfer_fee_0 = amounts.transfer_fee_0;
+    let transfer_fee_1 = amounts.transfer_fee_1;
+    let transfer_amount_0 = amounts.transfer_amount_0;
+    let transfer_amount_1 = amounts.transfer_amount_1;
+    let referral_fee = amounts.referral_fee;
+
+    let input_balance_before = ctx.input_token_account.amount;
+    let output_balance_before = ctx.output_token_account.amount;
+
     let (token_account_0, token_account_1, vault_0, vault_1, vault_0_mint, vault_1_mint) =
         if zero_for_one {
             (
@@ -197,34 +402,7 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             )
         };
 
-    // user or pool real amount delta without tranfer fee
-    let amount_0_without_fee;
-    let amount_1_without_fee;
-    // the transfer fee amount charged by withheld_amount
-    let transfer_fee_0;
-    let transfer_fee_1;
-    // transfer amount
-    let transfer_amount_0;
-    let transfer_amount_1;
     if zero_for_one {
-        transfer_fee_0 = if is_base_input && amount_0 == amount_calculate_specified {
-            transfer_fee
-        } else {
-            util::get_transfer_inverse_fee(vault_0_mint.clone(), amount_0).unwrap()
-        };
-        transfer_fee_1 = util::get_transfer_fee(vault_1_mint.clone(), amount_1).unwrap();
-
-        amount_0_without_fee = amount_0;
-        amount_1_without_fee = amount_1.checked_sub(transfer_fee_1).unwrap();
-        (transfer_amount_0, transfer_amount_1) = (amount_0 + transfer_fee_0, amount_1);
-        #[cfg(feature = "enable-log")]
-        msg!(
-            "amount_0:{}, transfer_fee_0:{}, amount_1:{}, transfer_fee_1:{}",
-            amount_0,
-            transfer_fee_0,
-            amount_1,
-            transfer_fee_1
-        );
         //  x -> y, deposit x token from user to pool vault.
         transfer_from_user_to_pool_vault(
             &ctx.payer,
@@ -234,6 +412,7 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             &ctx.token_program,
             Some(ctx.token_program_2022.to_account_info()),
             transfer_amount_0,
+            remaining_accounts,
         )?;
         if vault_1.amount <= transfer_amount_1 {
             // freeze pool, disable all instructions
@@ -248,26 +427,9 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             &ctx.token_program,
             Some(ctx.token_program_2022.to_account_info()),
             transfer_amount_1,
+            remaining_accounts,
         )?;
     } else {
-        transfer_fee_0 = util::get_transfer_fee(vault_0_mint.clone(), amount_0).unwrap();
-        transfer_fee_1 = if is_base_input && amount_1 == amount_calculate_specified {
-            transfer_fee
-        } else {
-            util::get_transfer_inverse_fee(vault_1_mint.clone(), amount_1).unwrap()
-        };
-
-        amount_0_without_fee = amount_0.checked_sub(transfer_fee_0).unwrap();
-        amount_1_without_fee = amount_1;
-        (transfer_amount_0, transfer_amount_1) = (amount_0, amount_1 + transfer_fee_1);
-        #[cfg(feature = "enable-log")]
-        msg!(
-            "amount_0:{}, transfer_fee_0:{}, amount_1:{}, transfer_fee_1:{}",
-            amount_0,
-            transfer_fee_0,
-            amount_1,
-            transfer_fee_1
-        );
         transfer_from_user_to_pool_vault(
             &ctx.payer,
             &token_account_1.to_account_info(),
@@ -276,6 +438,7 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             &ctx.token_program,
             Some(ctx.token_program_2022.to_account_info()),
             transfer_amount_1,
+            remaining_accounts,
         )?;
         if vault_0.amount <= transfer_amount_0 {
             // freeze pool, disable all instructions
@@ -289,6 +452,21 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
             &ctx.token_program,
             Some(ctx.token_program_2022.to_account_info()),
             transfer_amount_0,
+            remaining_accounts,
+        )?;
+    }
+    // the referral fee has been deposited with the input amount, pay it out of the input vault
+    if referral_fee > 0 {
+        let referral = referral.unwrap();
+        transfer_from_pool_vault_to_user(
+            &ctx.pool_state,
+            &ctx.input_vault.to_account_info(),
+            &referral.referrer_token_account.to_account_info(),
+            Some(ctx.input_vault_mint.clone()),
+            &ctx.token_program,
+            Some(ctx.token_program_2022.to_account_info()),
+            referral_fee,
+            remaining_accounts,
         )?;
     }
     ctx.output_token_account.reload()?;
@@ -307,7 +485,8 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
         zero_for_one,
         sqrt_price_x64: pool_state.sqrt_price_x64,
         liquidity: pool_state.liquidity,
-        tick: pool_state.tick_current
+        tick: pool_state.tick_current,
+        referral_fee,
     });
     if zero_for_one {
         require_gt!(swap_price_before, pool_state.sqrt_price_x64);
@@ -323,10 +502,11 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
                 require_eq!(amount_specified, transfer_amount_1);
             }
         } else {
+            // the output amount received by the user excludes the transfer fee
             if zero_for_one {
-                require_eq!(amount_specified, transfer_amount_1);
+                require_eq!(amount_specified, amount_1_without_fee);
             } else {
-                require_eq!(amount_specified, transfer_amount_0);
+                require_eq!(amount_specified, amount_0_without_fee);
             }
         }
     }
@@ -357,7 +537,40 @@ pub fn swap_v2<'a, 'b, 'c: 'info, 'info>(
         amount,
         sqrt_price_limit_x64,
         is_base_input,
+        None,
     )?;
+    check_other_amount_threshold(amount_result, other_amount_threshold, is_base_input)
+}
+
+/// Swaps like `swap_v2` and pays `referral_fee_rate` of the trade fee to the referrer, if any
+pub fn swap_v2_with_options<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapSingleV2WithOptions<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    referral_fee_rate: Option<u32>,
+) -> Result<()> {
+    let referral =
+        SwapReferral::from_options(&ctx.accounts.referrer_token_account, referral_fee_rate)?;
+    let amount_result = exact_internal_v2(
+        &mut ctx.accounts.swap,
+        ctx.remaining_accounts,
+        amount,
+        sqrt_price_limit_x64,
+        is_base_input,
+        referral.as_ref(),
+    )?;
+    check_other_amount_threshold(amount_result, other_amount_threshold, is_base_input)
+}
+
+/// Checks the slippage of a single pool swap, `amount_result` is the amount returned by
+/// `exact_internal_v2`
+pub fn check_other_amount_threshold(
+    amount_result: u64,
+    other_amount_threshold: u64,
+    is_base_input: bool,
+) -> Result<()> {
     if is_base_input {
         require_gte!(
             amount_result,
@@ -1,6 +1,8 @@
 pub mod error;
 pub mod instructions;
 pub mod libraries;
+#[cfg(any(feature = "client", test))]
+pub mod quote;
 pub mod states;
 pub mod util;
 
@@ -17,6 +19,7 @@ solana_security_txt::security_txt! {
 }
 
 declare_id!("6dMXqGZ3ga2dikrYS9ovDXgHGh5RUsb2RTUj6hrQXhk6");
+/// The deployment admin, only allowed to create the global config which holds the admin
 pub mod admin {
     use super::{pubkey, Pubkey};
     pub const ID: Pubkey = pubkey!("3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2");
@@ -27,6 +30,38 @@ pub mod amm_v3 {
 
     use super::*;
 
+    /// Creates the global config holding the admin, must be called by the deployment admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn create_global_config(ctx: Context<CreateGlobalConfig>) -> Result<()> {
+        instructions::create_global_config(ctx)
+    }
+
+    /// Proposes a new admin, must be called by the admin.
+    /// The admin changes when the proposed key calls `accept_admin`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `new_admin` - The proposed admin, the default pubkey cancels the proposal
+    ///
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    /// Makes the pending admin the admin, must be called by the pending admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
     // The configuration of AMM protocol, include trade fee and protocol fee
     /// # Arguments
     ///
@@ -59,13 +94,56 @@ pub mod amm_v3 {
         )
     }
 
-    /// Create support token22 mint account which can create pool and send rewards with ignoring the not support extensions.
-    pub fn create_support_mint_associated(ctx: Context<CreateSupportMintAssociated>) -> Result<()> {
-        instructions::create_support_mint_associated(ctx)
+    /// Create support token22 mint account which can create pool and send rewards with the
+    /// extensions tolerated by its policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `allowed_extensions` - The tolerated extensions, bit n is the token22 extension type n
+    /// * `vault_immutable_owner` - Whether the pool vaults of the mint are created with the ImmutableOwner extension
+    ///
+    pub fn create_support_mint_associated(
+        ctx: Context<CreateSupportMintAssociated>,
+        allowed_extensions: u64,
+        vault_immutable_owner: bool,
+    ) -> Result<()> {
+        instructions::create_support_mint_associated(ctx, allowed_extensions, vault_immutable_owner)
     }
 
-    /// Updates the owner of the clmm config
-    /// Must be called by the current owner or admin
+    /// Updates the extensions tolerated for a support token22 mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `allowed_extensions` - The tolerated extensions, bit n is the token22 extension type n
+    /// * `vault_immutable_owner` - Whether the pool vaults of the mint are created with the ImmutableOwner extension
+    ///
+    pub fn update_support_mint_associated(
+        ctx: Context<UpdateSupportMintAssociated>,
+        allowed_extensions: u64,
+        vault_immutable_owner: bool,
+    ) -> Result<()> {
+        instructions::update_support_mint_associated(ctx, allowed_extensions, vault_immutable_owner)
+    }
+
+    /// Migrates a mint supported by the removed hard-coded rules to its support mint account.
+    /// The formerly whitelisted mints tolerate their own extensions, the pool vaults of the
+    /// Superstate mints are created with the ImmutableOwner extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn migrate_support_mint_associated(
+        ctx: Context<MigrateSupportMintAssociated>,
+    ) -> Result<()> {
+        instructions::migrate_support_mint_associated(ctx)
+    }
+
+    /// Updates the fee rates of the clmm config
+    /// Must be called by the admin, the owners are changed by `propose_amm_config_owner`.
+    /// Only allowed without a config update delay, otherwise use `schedule_amm_config_update`
     ///
     /// # Arguments
     ///
@@ -73,21 +151,105 @@ pub mod amm_v3 {
     /// * `trade_fee_rate`- The new trade fee rate of clmm config, be set when `param` is 0
     /// * `protocol_fee_rate`- The new protocol fee rate of clmm config, be set when `param` is 1
     /// * `fund_fee_rate`- The new fund fee rate of clmm config, be set when `param` is 2
-    /// * `new_owner`- The config's new owner, be set when `param` is 3
-    /// * `new_fund_owner`- The config's new fund owner, be set when `param` is 4
-    /// * `param`- The value can be 0 | 1 | 2 | 3 | 4, otherwise will report a error
+    /// * `dynamic_fee_min_rate`- The lower bound of the dynamic trade fee, be set when `param` is 5
+    /// * `dynamic_fee_max_rate`- The upper bound of the dynamic trade fee, be set when `param` is 6, zero disables the dynamic fee mode
+    /// * `flash_loan_fee_rate`- The flash loan fee rate, be set when `param` is 7, zero disables flash loans
+    /// * `max_referral_fee_rate`- The max referral share of the trade fee, be set when `param` is 8
+    /// * `max_launch_fee_start_rate`- The max launch fee start rate of new pools, be set when `param` is 9, zero disables launch fees
+    /// * `max_launch_fee_duration`- The max launch fee duration in seconds of new pools, be set when `param` is 10
+    /// * `param`- The value can be 0 | 1 | 2 | 5 | 6 | 7 | 8 | 9 | 10, otherwise will report a error
     ///
     pub fn update_amm_config(ctx: Context<UpdateAmmConfig>, param: u8, value: u32) -> Result<()> {
         instructions::update_amm_config(ctx, param, value)
     }
 
+    /// Sets the minimum delay between scheduling and executing an amm config update,
+    /// must be called by the admin. A lower delay only applies when it is set again
+    /// after the current delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `delay` - The delay in seconds
+    ///
+    pub fn set_config_update_delay(ctx: Context<SetConfigUpdateDelay>, delay: u64) -> Result<()> {
+        instructions::set_config_update_delay(ctx, delay)
+    }
+
+    /// Schedules an update of the clmm config, executable after the config update delay.
+    /// Must be called by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `param`- The `update_amm_config` param to update
+    /// * `value`- The new value of the param
+    ///
+    pub fn schedule_amm_config_update(
+        ctx: Context<ScheduleAmmConfigUpdate>,
+        param: u8,
+        value: u32,
+    ) -> Result<()> {
+        instructions::schedule_amm_config_update(ctx, param, value)
+    }
+
+    /// Applies a scheduled update of the clmm config once its delay has passed.
+    /// Must be called by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn execute_amm_config_update(ctx: Context<ExecuteAmmConfigUpdate>) -> Result<()> {
+        instructions::execute_amm_config_update(ctx)
+    }
+
+    /// Cancels a scheduled update of the clmm config, must be called by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn cancel_amm_config_update(ctx: Context<CancelAmmConfigUpdate>) -> Result<()> {
+        instructions::cancel_amm_config_update(ctx)
+    }
+
+    /// Proposes new owners of the clmm config, must be called by the admin.
+    /// An owner changes when the proposed key calls `accept_amm_config_owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `new_owner` - The proposed protocol owner, `None` keeps the current proposal
+    /// * `new_fund_owner` - The proposed fund owner, `None` keeps the current proposal
+    ///
+    pub fn propose_amm_config_owner(
+        ctx: Context<ProposeAmmConfigOwner>,
+        new_owner: Option<Pubkey>,
+        new_fund_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_amm_config_owner(ctx, new_owner, new_fund_owner)
+    }
+
+    /// Makes the signer the protocol owner and/or fund owner of the clmm config it was proposed for.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn accept_amm_config_owner(ctx: Context<AcceptAmmConfigOwner>) -> Result<()> {
+        instructions::accept_amm_config_owner(ctx)
+    }
+
     /// Creates a pool for the given token pair and the initial price
     ///
     /// # Arguments
     ///
     /// * `ctx`- The context of accounts
     /// * `sqrt_price_x64` - the initial sqrt price (amount_token_1 / amount_token_0) of the pool as a Q64.64
-    /// Note: The open_time must be smaller than the current block_timestamp on chain.
+    /// * `open_time` - swaps are rejected before this timestamp, a past open_time opens the pool at creation,
+    /// at most `MAX_OPEN_DELAY` seconds after creation
+    ///
     pub fn create_pool(
         ctx: Context<CreatePool>,
         sqrt_price_x64: u128,
@@ -96,6 +258,36 @@ pub mod amm_v3 {
         instructions::create_pool(ctx, sqrt_price_x64, open_time)
     }
 
+    /// Creates a pool like `create_pool`, with a trade fee decaying after open_time
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `sqrt_price_x64` - the initial sqrt price (amount_token_1 / amount_token_0) of the pool as a Q64.64
+    /// * `open_time` - swaps are rejected before this timestamp, a past open_time opens the pool at creation,
+    /// at most `MAX_OPEN_DELAY` seconds after creation
+    /// * `launch_fee_start_rate` - the trade fee rate charged at open_time, at most the max launch fee start rate of the config
+    /// * `launch_fee_end_rate` - the launch fee rate reached at the end of the schedule, not above the start rate
+    /// * `launch_fee_duration` - the seconds the launch fee decays over, zero disables the launch fee, at most the max launch fee duration of the config
+    ///
+    pub fn create_pool_with_launch_fee(
+        ctx: Context<CreatePool>,
+        sqrt_price_x64: u128,
+        open_time: u64,
+        launch_fee_start_rate: u32,
+        launch_fee_end_rate: u32,
+        launch_fee_duration: u32,
+    ) -> Result<()> {
+        instructions::create_pool_with_launch_fee(
+            ctx,
+            sqrt_price_x64,
+            open_time,
+            launch_fee_start_rate,
+            launch_fee_end_rate,
+            launch_fee_duration,
+        )
+    }
+
     /// Update pool status for given value
     ///
     /// # Arguments
@@ -107,6 +299,166 @@ pub mod amm_v3 {
         instructions::update_pool_status(ctx, status)
     }
 
+    /// Update the status of the amm config for given value, must be called by the admin.
+    /// Swaps and flash loans read the status from the config, the pools passed as writable
+    /// remaining accounts cache it for the other instructions. The pool status is unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `status` - The value of status
+    ///
+    pub fn update_amm_config_status<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, UpdateAmmConfigStatus<'info>>,
+        status: u8,
+    ) -> Result<()> {
+        instructions::update_amm_config_status(ctx, status)
+    }
+
+    /// Sets the pause guardian, must be called by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `pause_guardian` - The new pause guardian, the default pubkey removes the role
+    ///
+    pub fn set_pause_guardian(
+        ctx: Context<SetPauseGuardian>,
+        pause_guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_pause_guardian(ctx, pause_guardian)
+    }
+
+    /// Disables the operations of the set status bits for a pool, must be called by the admin
+    /// or the pause guardian. It can not enable an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `status` - The status bits to disable
+    ///
+    pub fn pause_pool(ctx: Context<PausePool>, status: u8) -> Result<()> {
+        instructions::pause_pool(ctx, status)
+    }
+
+    /// Disables the operations of the set status bits for every pool of the amm config,
+    /// must be called by the admin or the pause guardian. It can not enable an operation.
+    /// Swaps and flash loans read the status from the config, the pools passed as writable
+    /// remaining accounts cache it for the other instructions.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `status` - The status bits to disable
+    ///
+    pub fn pause_amm_config<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, PauseAmmConfig<'info>>,
+        status: u8,
+    ) -> Result<()> {
+        instructions::pause_amm_config(ctx, status)
+    }
+
+    /// Overrides the fee rates of the amm config for a single pool, must be called by the admin
+    /// or an operation owner. A `None` rate falls back to the amm config, an overridden trade fee
+    /// is static even if the amm config uses the dynamic fee mode. If a later amm config change
+    /// leaves no room for the overridden protocol and fund rates, swaps clamp them.
+    /// With a config update delay the overrides must be scheduled by `schedule_pool_fee_overrides`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `trade_fee_rate` - The trade fee rate of the pool
+    /// * `protocol_fee_rate` - The protocol fee rate of the pool
+    /// * `fund_fee_rate` - The fund fee rate of the pool
+    ///
+    pub fn set_pool_fee_overrides(
+        ctx: Context<SetPoolFeeOverrides>,
+        trade_fee_rate: Option<u32>,
+        protocol_fee_rate: Option<u32>,
+        fund_fee_rate: Option<u32>,
+    ) -> Result<()> {
+        instructions::set_pool_fee_overrides(ctx, trade_fee_rate, protocol_fee_rate, fund_fee_rate)
+    }
+
+    /// Sets the twap guard of a pool, must be called by the admin or an operation owner.
+    /// A swap moving the price more than `max_tick_deviation` ticks away from the
+    /// time-weighted average tick over `window` seconds is rejected or capped at the bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `window` - The seconds of the time-weighted average tick, zero disables the guard
+    /// * `max_tick_deviation` - The ticks the price can move away from the average tick
+    /// * `mode` - 0 rejects the swaps moving the price beyond the bound, 1 caps them at it
+    ///
+    pub fn set_twap_guard(
+        ctx: Context<SetTwapGuard>,
+        window: u32,
+        max_tick_deviation: u32,
+        mode: u8,
+    ) -> Result<()> {
+        instructions::set_twap_guard(ctx, window, max_tick_deviation, mode)
+    }
+
+    /// Moves a pool to another amm config with the same tick spacing, must be called by the admin.
+    /// The protocol and fund fees accrued under the old config are paid to its owners first.
+    /// The pool address stays seeded with the config the pool was created with.
+    /// With a config update delay the migration must be scheduled by `schedule_pool_config_migration`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn migrate_pool_config(ctx: Context<MigratePoolConfig>) -> Result<()> {
+        instructions::migrate_pool_config(ctx)
+    }
+
+    /// Schedules a change of the pool fee overrides, applied by `set_pool_fee_overrides` with the
+    /// same rates after the config update delay. Must be called by the admin or an operation owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `trade_fee_rate` - The trade fee rate of the pool
+    /// * `protocol_fee_rate` - The protocol fee rate of the pool
+    /// * `fund_fee_rate` - The fund fee rate of the pool
+    ///
+    pub fn schedule_pool_fee_overrides(
+        ctx: Context<SchedulePoolFeeOverrides>,
+        trade_fee_rate: Option<u32>,
+        protocol_fee_rate: Option<u32>,
+        fund_fee_rate: Option<u32>,
+    ) -> Result<()> {
+        instructions::schedule_pool_fee_overrides(
+            ctx,
+            trade_fee_rate,
+            protocol_fee_rate,
+            fund_fee_rate,
+        )
+    }
+
+    /// Schedules a migration of the pool to another amm config, applied by `migrate_pool_config`
+    /// after the config update delay. Must be called by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn schedule_pool_config_migration(ctx: Context<SchedulePoolConfigMigration>) -> Result<()> {
+        instructions::schedule_pool_config_migration(ctx)
+    }
+
+    /// Cancels a scheduled pool fee override change or migration, must be called by the admin.
+    /// An operation owner can also cancel the fee override changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn cancel_pool_config_update(ctx: Context<CancelPoolConfigUpdate>) -> Result<()> {
+        instructions::cancel_pool_config_update(ctx)
+    }
+
     /// Creates an operation account for the program
     ///
     /// # Arguments
@@ -293,6 +645,59 @@ pub mod amm_v3 {
         )
     }
 
+    /// Opens a position like `open_position_with_token22_nft`, rejected once `deadline` has passed
+    /// or while the pool price is outside of the given sqrt price range, each check is optional
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `tick_lower_index` - The low boundary of market
+    /// * `tick_upper_index` - The upper boundary of market
+    /// * `tick_array_lower_start_index` - The start index of tick array which include tick low
+    /// * `tick_array_upper_start_index` - The start index of tick array which include tick upper
+    /// * `liquidity` - The liquidity to be added, if zero, and the base_flag is specified, calculate liquidity base amount_0_max or amount_1_max according base_flag, otherwise open position with zero liquidity
+    /// * `amount_0_max` - The max amount of token_0 to spend, which serves as a slippage check
+    /// * `amount_1_max` - The max amount of token_1 to spend, which serves as a slippage check
+    /// * `with_metadata` - The flag indicating whether to create NFT mint metadata
+    /// * `base_flag` - if the liquidity specified as zero, true: calculate liquidity base amount_0_max otherwise base amount_1_max
+    /// * `sqrt_price_min_x64` - The min Q64.64 sqrt price of the pool to open the position at, if any
+    /// * `sqrt_price_max_x64` - The max Q64.64 sqrt price of the pool to open the position at, if any
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn open_position_with_token22_nft_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, OpenPositionWithToken22Nft<'info>>,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        with_metadata: bool,
+        base_flag: Option<bool>,
+        sqrt_price_min_x64: Option<u128>,
+        sqrt_price_max_x64: Option<u128>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        util::check_deadline(deadline)?;
+        ctx.accounts
+            .pool_state
+            .load()?
+            .check_sqrt_price_range(sqrt_price_min_x64, sqrt_price_max_x64)?;
+        instructions::open_position_with_token22_nft(
+            ctx,
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            tick_lower_index,
+            tick_upper_index,
+            tick_array_lower_start_index,
+            tick_array_upper_start_index,
+            with_metadata,
+            base_flag,
+        )
+    }
+
     /// Close the user's position and NFT account. If the NFT mint belongs to token2022, it will also be closed and the funds returned to the NFT owner.
     ///
     /// # Arguments
@@ -329,6 +734,41 @@ pub mod amm_v3 {
         instructions::increase_liquidity_v2(ctx, liquidity, amount_0_max, amount_1_max, base_flag)
     }
 
+    /// Increases liquidity like `increase_liquidity_v2`, rejected once `deadline` has passed or
+    /// while the pool price is outside of the given sqrt price range, each check is optional
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `liquidity` - The desired liquidity to be added, if zero, calculate liquidity base amount_0 or amount_1 according base_flag
+    /// * `amount_0_max` - The max amount of token_0 to spend, which serves as a slippage check
+    /// * `amount_1_max` - The max amount of token_1 to spend, which serves as a slippage check
+    /// * `base_flag` - must be specified if liquidity is zero, true: calculate liquidity base amount_0_max otherwise base amount_1_max
+    /// * `sqrt_price_min_x64` - The min Q64.64 sqrt price of the pool to add liquidity at, if any
+    /// * `sqrt_price_max_x64` - The max Q64.64 sqrt price of the pool to add liquidity at, if any
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn increase_liquidity_v2_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, IncreaseLiquidityV2<'info>>,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        base_flag: Option<bool>,
+        sqrt_price_min_x64: Option<u128>,
+        sqrt_price_max_x64: Option<u128>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        if liquidity == 0 {
+            assert!(base_flag.is_some());
+        }
+        util::check_deadline(deadline)?;
+        ctx.accounts
+            .pool_state
+            .load()?
+            .check_sqrt_price_range(sqrt_price_min_x64, sqrt_price_max_x64)?;
+        instructions::increase_liquidity_v2(ctx, liquidity, amount_0_max, amount_1_max, base_flag)
+    }
+
 
     /// Decreases liquidity for an existing position, support Token2022
     ///
@@ -348,6 +788,103 @@ pub mod amm_v3 {
         instructions::decrease_liquidity_v2(ctx, liquidity, amount_0_min, amount_1_min)
     }
 
+    /// Decreases liquidity like `decrease_liquidity_v2`, rejected once `deadline` has passed or
+    /// while the pool price is outside of the given sqrt price range, each check is optional
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    /// * `liquidity` - The amount by which liquidity will be decreased
+    /// * `amount_0_min` - The minimum amount of token_0 that should be accounted for the burned liquidity
+    /// * `amount_1_min` - The minimum amount of token_1 that should be accounted for the burned liquidity
+    /// * `sqrt_price_min_x64` - The min Q64.64 sqrt price of the pool to remove liquidity at, if any
+    /// * `sqrt_price_max_x64` - The max Q64.64 sqrt price of the pool to remove liquidity at, if any
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn decrease_liquidity_v2_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DecreaseLiquidityV2<'info>>,
+        liquidity: u128,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        sqrt_price_min_x64: Option<u128>,
+        sqrt_price_max_x64: Option<u128>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        util::check_deadline(deadline)?;
+        ctx.accounts
+            .pool_state
+            .load()?
+            .check_sqrt_price_range(sqrt_price_min_x64, sqrt_price_max_x64)?;
+        instructions::decrease_liquidity_v2(ctx, liquidity, amount_0_min, amount_1_min)
+    }
+
+    /// Collects the fees and rewards owed to a position without changing its liquidity, support Token2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    ///
+    pub fn collect_fees_and_rewards<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CollectFeesAndRewards<'info>>,
+    ) -> Result<()> {
+        instructions::collect_fees_and_rewards(ctx)
+    }
+
+    /// Reinvests the fees owed to a position into the same range at the current price,
+    /// the fees that can not be added are returned to the owner, support Token2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    /// * `sqrt_price_min_x64` - The min Q64.64 sqrt price of the pool to reinvest the fees at, if any
+    /// * `sqrt_price_max_x64` - The max Q64.64 sqrt price of the pool to reinvest the fees at, if any
+    ///
+    pub fn compound_position<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CompoundPosition<'info>>,
+        sqrt_price_min_x64: Option<u128>,
+        sqrt_price_max_x64: Option<u128>,
+    ) -> Result<()> {
+        instructions::compound_position(ctx, sqrt_price_min_x64, sqrt_price_max_x64)
+    }
+
+    /// Places a position as a range order. The position must span one tick spacing inside a single
+    /// tick array and hold a single token, the order is filled once a swap moves the price through it
+    /// and can be claimed fully converted even if the price comes back.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    ///
+    pub fn place_range_order(ctx: Context<PlaceRangeOrder>) -> Result<()> {
+        instructions::place_range_order(ctx)
+    }
+
+    /// Claims the converted tokens of a filled range order, or cancels the order and withdraws the
+    /// position at the current price if it was not filled, support Token2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    ///
+    pub fn claim_range_order<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimRangeOrder<'info>>,
+    ) -> Result<()> {
+        instructions::claim_range_order(ctx)
+    }
+
+    /// Locks the liquidity of a position until a unix timestamp, or forever with `PERMANENT_LOCK`.
+    /// A locked position can not decrease liquidity or be closed, its fees and rewards can still
+    /// be collected. The lock can only be extended.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` -  The context of accounts
+    /// * `locked_until` - The unix timestamp until which the liquidity is locked
+    ///
+    pub fn lock_position(ctx: Context<LockPosition>, locked_until: u64) -> Result<()> {
+        instructions::lock_position(ctx, locked_until)
+    }
+
     /// Swaps one token for as much as possible of another token across a single pool, support token program 2022
     ///
     /// # Arguments
@@ -374,7 +911,85 @@ pub mod amm_v3 {
         )
     }
 
+    /// Swaps like `swap_v2`, paying a share of the trade fee to the referrer token account
+    /// and rejected once `deadline` has passed, each option can be used on its own.
+    /// The accounts are the ones of `swap_v2` followed by the referrer token account, which is
+    /// the program id without a referral
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount` - Arranged in pairs with other_amount_threshold. (amount_in, amount_out_minimum) or (amount_out, amount_in_maximum)
+    /// * `other_amount_threshold` - For slippage check
+    /// * `sqrt_price_limit` - The Q64.64 sqrt price √P limit. If zero for one, the price cannot
+    /// * `is_base_input` - swap base input or swap base output
+    /// * `referral_fee_rate` - The share of the trade fee paid to the referrer token account, in
+    /// hundredths of a bip (10^-6), capped by the max referral fee rate of the amm config
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn swap_v2_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapSingleV2WithOptions<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+        referral_fee_rate: Option<u32>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        util::check_deadline(deadline)?;
+        instructions::swap_v2_with_options(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit_x64,
+            is_base_input,
+            referral_fee_rate,
+        )
+    }
+
+    /// Swaps across a single pool paying the output first, then calls `callback_program` with a
+    /// `FlashSwapCallback` instruction which must transfer the owed input to the input vault.
+    /// The remaining accounts are the tick arrays of `swap_v2` followed by the accounts passed
+    /// to the callback, support token program 2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount` - Arranged in pairs with other_amount_threshold. (amount_in, amount_out_minimum) or (amount_out, amount_in_maximum)
+    /// * `other_amount_threshold` - For slippage check
+    /// * `sqrt_price_limit` - The Q64.64 sqrt price √P limit. If zero for one, the price cannot
+    /// * `is_base_input` - swap base input or swap base output
+    /// * `callback_account_count` - The number of remaining accounts at the end passed to the callback
+    /// * `callback_data` - Passed through to the callback
+    ///
+    pub fn flash_swap<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FlashSwap<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+        callback_account_count: u8,
+        callback_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::flash_swap(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit_x64,
+            is_base_input,
+            callback_account_count,
+            callback_data,
+        )
+    }
+
     /// Swap token for as much as possible of another token across the path provided, base input
+    /// Every hop is executed by `swap_v2`, so both SPL Token and Token-2022 mints are supported and
+    /// the transfer fee of each hop is charged on its own, one `SwapEvent` is emitted per hop.
+    ///
+    /// The remaining accounts of each hop are laid out as
+    /// `amm_config, pool_state, output_token_account, input_vault, output_vault, output_token_mint,
+    /// observation_state, [tickarray_bitmap_extension], tick_array...`
+    /// followed by the transfer hook accounts of the mints on the path, if any.
     ///
     /// # Arguments
     ///
@@ -390,6 +1005,160 @@ pub mod amm_v3 {
         instructions::swap_router_base_in(ctx, amount_in, amount_out_minimum)
     }
 
+    /// Swaps like `swap_router_base_in`, paying a share of the trade fee of the first hop to the
+    /// referrer token account and rejected once `deadline` has passed, each option can be used
+    /// on its own. The referrer token account follows the accounts of `swap_router_base_in`,
+    /// it is the program id without a referral
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_in` - Token amount to be swapped in
+    /// * `amount_out_minimum` - Panic if output amount is below minimum amount. For slippage.
+    /// * `referral_fee_rate` - The share of the trade fee of the first hop paid to the referrer
+    /// token account, as in `swap_v2_with_options`
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn swap_router_base_in_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseInWithOptions<'info>>,
+        amount_in: u64,
+        amount_out_minimum: u64,
+        referral_fee_rate: Option<u32>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        util::check_deadline(deadline)?;
+        instructions::swap_router_base_in_with_options(
+            ctx,
+            amount_in,
+            amount_out_minimum,
+            referral_fee_rate,
+        )
+    }
+
+    /// Swap as little as possible of one token for an exact amount of another token across the path provided, base output
+    /// The remaining accounts are laid out as in `swap_router_base_in`, the amounts are computed from
+    /// the last hop backwards and the transfers are settled from the first hop onwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_out` - Token amount to be received from the last pool of the path
+    /// * `amount_in_maximum` - Panic if input amount is above maximum amount. For slippage.
+    ///
+    pub fn swap_router_base_out<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOut<'info>>,
+        amount_out: u64,
+        amount_in_maximum: u64,
+    ) -> Result<()> {
+        instructions::swap_router_base_out(ctx, amount_out, amount_in_maximum)
+    }
+
+    /// Swaps like `swap_router_base_out`, paying a share of the trade fee of the first hop to the
+    /// referrer token account and rejected once `deadline` has passed, each option can be used
+    /// on its own. The referrer token account follows the accounts of `swap_router_base_out`,
+    /// it is the program id without a referral
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_out` - Token amount to be received from the last pool of the path
+    /// * `amount_in_maximum` - Panic if input amount is above maximum amount. For slippage.
+    /// * `referral_fee_rate` - The share of the trade fee of the first hop paid to the referrer
+    /// token account, as in `swap_v2_with_options`
+    /// * `deadline` - The unix timestamp after which the transaction is rejected, if any
+    ///
+    pub fn swap_router_base_out_with_options<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOutWithOptions<'info>>,
+        amount_out: u64,
+        amount_in_maximum: u64,
+        referral_fee_rate: Option<u32>,
+        deadline: Option<u64>,
+    ) -> Result<()> {
+        util::check_deadline(deadline)?;
+        instructions::swap_router_base_out_with_options(
+            ctx,
+            amount_out,
+            amount_in_maximum,
+            referral_fee_rate,
+        )
+    }
+
+    /// Lends tokens from the pool vaults for the rest of the transaction. The transaction must call
+    /// `flash_loan_repay` for the same pool after this instruction, and the borrow can not be
+    /// invoked through CPI. The fee is earned by the liquidity in range at the borrow, it goes to
+    /// the protocol fees when there is none.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_0` - The amount of token_0 to borrow
+    /// * `amount_1` - The amount of token_1 to borrow
+    ///
+    pub fn flash_loan_borrow(
+        ctx: Context<FlashLoanBorrow>,
+        amount_0: u64,
+        amount_1: u64,
+    ) -> Result<()> {
+        instructions::flash_loan_borrow(ctx, amount_0, amount_1)
+    }
+
+    /// Repays the outstanding flash loan of a pool plus the flash loan fee of the amm config,
+    /// the fee is credited to the liquidity providers in range at the borrow, or added to the
+    /// protocol fees when no liquidity was in range, support Token2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn flash_loan_repay(ctx: Context<FlashLoanRepay>) -> Result<()> {
+        instructions::flash_loan_repay(ctx)
+    }
+
+    /// Returns the tick cumulatives of the pool oracle as of each `seconds_agos` from the current
+    /// block timestamp, the result `ObserveResult` is written by `set_return_data`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `seconds_agos` - From how long ago each cumulative tick value should be returned
+    ///
+    pub fn observe(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> Result<()> {
+        instructions::observe(ctx, seconds_agos)
+    }
+
+    /// Enlarges the observation account of the pool so it can store more observations,
+    /// the rent of the enlarged account is paid by `payer`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `observation_cardinality_next` - The number of observations the account can store after enlarging
+    ///
+    pub fn increase_observation_cardinality(
+        ctx: Context<IncreaseObservationCardinality>,
+        observation_cardinality_next: u16,
+    ) -> Result<()> {
+        instructions::increase_observation_cardinality(ctx, observation_cardinality_next)
+    }
+
+    /// Returns the seconds per liquidity inside a tick range as of the current block timestamp,
+    /// the result `SnapshotCumulativesInsideResult` is written by `set_return_data`.
+    /// Only the difference of two snapshots of the same range is meaningful.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `tick_lower_index` - The lower tick of the range, must be initialized
+    /// * `tick_upper_index` - The upper tick of the range, must be initialized
+    ///
+    pub fn snapshot_cumulatives_inside(
+        ctx: Context<SnapshotCumulativesInside>,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        instructions::snapshot_cumulatives_inside(ctx, tick_lower_index, tick_upper_index)
+    }
+
     /// The CLMM protocol decides to discard the protocol position account, which can reduce users' opening costs.
     /// After the original protocol position account is closed, the gas fee will be refunded to the user who created it.
     ///
@@ -0,0 +1,375 @@
+//! Off-chain swap quotes which run the same step math as `swap_internal`
+//! over deserialized pool, tick array and bitmap extension accounts.
+use crate::error::ErrorCode;
+use crate::libraries::{big_num::U128, full_math::MulDiv, liquidity_math, swap_math, tick_math};
+use crate::states::*;
+use crate::util::{calculate_transfer_fee, calculate_transfer_inverse_fee};
+use anchor_lang::prelude::*;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use std::ops::Neg;
+
+/// The inputs of a swap quote
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuoteSwapParams<'a> {
+    /// The amount of the input token with transfer fee if `is_base_input`,
+    /// otherwise the amount of the output token the user expects to receive
+    pub amount_specified: u64,
+    /// The price limit of the swap, zero means no limit
+    pub sqrt_price_limit_x64: u128,
+    pub zero_for_one: bool,
+    pub is_base_input: bool,
+    /// The block timestamp used by the dynamic fee mode
+    pub block_timestamp: u32,
+    /// The epoch used to pick the Token-2022 transfer fee
+    pub epoch: u64,
+    /// The transfer fee config of the input mint, `None` if the mint charges no transfer fee
+    pub input_transfer_fee_config: Option<&'a TransferFeeConfig>,
+    /// The transfer fee config of the output mint, `None` if the mint charges no transfer fee
+    pub output_transfer_fee_config: Option<&'a TransferFeeConfig>,
+    /// The observation account of the pool, used by the twap guard.
+    /// `None` quotes the swap without the twap guard
+    pub observation_state: Option<&'a ObservationState>,
+    /// The observations stored after the observation account, see `load_observation_account`
+    pub observation_extension: &'a [Observation],
+}
+
+/// The result of the swap loop, all amounts are the pool side amounts without transfer fee
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SwapComputation {
+    /// The amount of the input token received by the pool, trade fee included
+    pub amount_in: u64,
+    /// The amount of the output token sent by the pool
+    pub amount_out: u64,
+    /// The trade fee paid in the input token, protocol and fund fee included
+    pub fee_amount: u64,
+    pub protocol_fee: u64,
+    pub fund_fee: u64,
+    /// The part of the trade fee accrued to the liquidity providers
+    pub lp_fee: u64,
+    pub sqrt_price_x64_after: u128,
+    pub tick_after: i32,
+    pub liquidity_after: u128,
+    /// The number of initialized ticks crossed
+    pub ticks_crossed: u32,
+    /// The start indexes of the tick arrays the swap touches, in the order the program expects them
+    pub tick_array_start_indexes: Vec<i32>,
+}
+
+/// A swap quote, including the Token-2022 transfer fees of both sides
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub computation: SwapComputation,
+    /// The amount the user sends, transfer fee included
+    pub transfer_amount_in: u64,
+    /// The amount the user receives, transfer fee excluded
+    pub transfer_amount_out: u64,
+    pub transfer_fee_in: u64,
+    pub transfer_fee_out: u64,
+}
+
+/// Quotes a swap the way `swap_v2` executes it, including the transfer fees of both mints
+pub fn quote_swap(
+    amm_config: &AmmConfig,
+    pool_state: &PoolState,
+    tickarray_bitmap_extension: &Option<TickArrayBitmapExtension>,
+    tick_arrays: &[TickArrayState],
+    params: &QuoteSwapParams,
+) -> Result<SwapQuote> {
+    let (amount_calculate_specified, transfer_fee) = if params.is_base_input {
+        let transfer_fee = params
+            .input_transfer_fee_config
+            .map(|config| calculate_transfer_fee(config, params.epoch, params.amount_specified))
+            .unwrap_or(0);
+        (params.amount_specified - transfer_fee, transfer_fee)
+    } else {
+        let transfer_fee = match params.output_transfer_fee_config {
+            Some(config) => {
+                calculate_transfer_inverse_fee(config, params.epoch, params.amount_specified)?
+            }
+            None => 0,
+        };
+        (params.amount_specified + transfer_fee, transfer_fee)
+    };
+
+    let sqrt_price_limit_x64 = if params.sqrt_price_limit_x64 == 0 {
+        if params.zero_for_one {
+            tick_math::MIN_SQRT_PRICE_X64 + 1
+        } else {
+            tick_math::MAX_SQRT_PRICE_X64 - 1
+        }
+    } else {
+        params.sqrt_price_limit_x64
+    };
+    let twap_guard_sqrt_price_x64 = match params.observation_state {
+        Some(observation_state) => pool_state.get_twap_guard_sqrt_price(
+            observation_state,
+            params.observation_extension,
+            params.block_timestamp,
+            params.zero_for_one,
+        )?,
+        None => None,
+    };
+    let sqrt_price_limit_x64 = pool_state.apply_twap_guard_to_price_limit(
+        twap_guard_sqrt_price_x64,
+        sqrt_price_limit_x64,
+        params.zero_for_one,
+    )?;
+    let computation = compute_swap(
+        amm_config,
+        pool_state,
+        tickarray_bitmap_extension,
+        tick_arrays,
+        amount_calculate_specified,
+        sqrt_price_limit_x64,
+        params.zero_for_one,
+        params.is_base_input,
+        params.block_timestamp,
+    )?;
+    PoolState::check_twap_guard(
+        twap_guard_sqrt_price_x64,
+        computation.sqrt_price_x64_after,
+        params.zero_for_one,
+    )?;
+    require!(
+        computation.amount_in != 0 && computation.amount_out != 0,
+        ErrorCode::TooSmallInputOrOutputAmount
+    );
+
+    let transfer_fee_in =
+        if params.is_base_input && computation.amount_in == amount_calculate_specified {
+            transfer_fee
+        } else {
+            match params.input_transfer_fee_config {
+                Some(config) => {
+                    calculate_transfer_inverse_fee(config, params.epoch, computation.amount_in)?
+                }
+                None => 0,
+            }
+        };
+    let transfer_fee_out = params
+        .output_transfer_fee_config
+        .map(|config| calculate_transfer_fee(config, params.epoch, computation.amount_out))
+        .unwrap_or(0);
+
+    Ok(SwapQuote {
+        transfer_amount_in: computation.amount_in + transfer_fee_in,
+        transfer_amount_out: computation.amount_out - transfer_fee_out,
+        transfer_fee_in,
+        transfer_fee_out,
+        computation,
+    })
+}
+
+/// Runs the swap loop of `swap_internal` without writing any account,
+/// `tick_arrays` may be passed in any order and only the ones the swap touches are reported.
+/// The twap guard is applied by `quote_swap`
+pub fn compute_swap(
+    amm_config: &AmmConfig,
+    pool_state: &PoolState,
+    tickarray_bitmap_extension: &Option<TickArrayBitmapExtension>,
+    tick_arrays: &[TickArrayState],
+    amount_specified: u64,
+    sqrt_price_limit_x64: u128,
+    zero_for_one: bool,
+    is_base_input: bool,
+    block_timestamp: u32,
+) -> Result<SwapComputation> {
+    require!(amount_specified != 0, ErrorCode::ZeroAmountSpecified);
+    if !pool_state.get_status_by_bit_with_config(PoolStatusBitIndex::Swap, amm_config.status) {
+        return err!(ErrorCode::NotApproved);
+    }
+    require_gte!(
+        u64::from(block_timestamp),
+        pool_state.open_time,
+        ErrorCode::NotOpenYet
+    );
+    require!(
+        if zero_for_one {
+            sqrt_price_limit_x64 < pool_state.sqrt_price_x64
+                && sqrt_price_limit_x64 > tick_math::MIN_SQRT_PRICE_X64
+        } else {
+            sqrt_price_limit_x64 > pool_state.sqrt_price_x64
+                && sqrt_price_limit_x64 < tick_math::MAX_SQRT_PRICE_X64
+        },
+        ErrorCode::SqrtPriceLimitOverflow
+    );
+
+    let amm_config = &pool_state.apply_fee_overrides(amm_config);
+    // the volatility reference is refreshed before the swap like the program does
+    let mut volatility_pool_state = *pool_state;
+    if amm_config.is_dynamic_fee_enabled() {
+        volatility_pool_state.update_volatility_reference(block_timestamp);
+    }
+    let launch_fee_rate = pool_state.get_launch_fee_rate(u64::from(block_timestamp));
+
+    let mut amount_specified_remaining = amount_specified;
+    let mut amount_calculated: u64 = 0;
+    let mut sqrt_price_x64 = pool_state.sqrt_price_x64;
+    let mut tick = pool_state.tick_current;
+    let mut liquidity = pool_state.liquidity;
+    let mut result = SwapComputation::default();
+
+    let (mut is_match_pool_current_tick_array, first_valid_tick_array_start_index) =
+        pool_state.get_first_initialized_tick_array(tickarray_bitmap_extension, zero_for_one)?;
+    let mut current_valid_tick_array_start_index = first_valid_tick_array_start_index;
+    let mut tick_array_current =
+        find_tick_array(tick_arrays, current_valid_tick_array_start_index)?;
+    result
+        .tick_array_start_indexes
+        .push(current_valid_tick_array_start_index);
+
+    while amount_specified_remaining != 0 && sqrt_price_x64 != sqrt_price_limit_x64 {
+        let sqrt_price_start_x64 = sqrt_price_x64;
+
+        let mut next_initialized_tick = if let Some(tick_state) =
+            tick_array_current.next_initialized_tick(tick, pool_state.tick_spacing, zero_for_one)?
+        {
+            *tick_state
+        } else if !is_match_pool_current_tick_array {
+            is_match_pool_current_tick_array = true;
+            *tick_array_current.first_initialized_tick(zero_for_one)?
+        } else {
+            TickState::default()
+        };
+        if !next_initialized_tick.is_initialized() {
+            let next_initialized_tickarray_index = pool_state
+                .next_initialized_tick_array_start_index(
+                    tickarray_bitmap_extension,
+                    current_valid_tick_array_start_index,
+                    zero_for_one,
+                )?
+                .ok_or(ErrorCode::LiquidityInsufficient)?;
+            current_valid_tick_array_start_index = next_initialized_tickarray_index;
+            tick_array_current =
+                find_tick_array(tick_arrays, current_valid_tick_array_start_index)?;
+            result
+                .tick_array_start_indexes
+                .push(current_valid_tick_array_start_index);
+
+            next_initialized_tick = *tick_array_current.first_initialized_tick(zero_for_one)?;
+        }
+        let tick_next = next_initialized_tick
+            .tick
+            .clamp(tick_math::MIN_TICK, tick_math::MAX_TICK);
+        let sqrt_price_next_x64 = tick_math::get_sqrt_price_at_tick(tick_next)?;
+
+        let target_price = if (zero_for_one && sqrt_price_next_x64 < sqrt_price_limit_x64)
+            || (!zero_for_one && sqrt_price_next_x64 > sqrt_price_limit_x64)
+        {
+            sqrt_price_limit_x64
+        } else {
+            sqrt_price_next_x64
+        };
+
+        let trade_fee_rate =
+            amm_config.get_trade_fee_rate(volatility_pool_state.get_volatility_accumulator(tick));
+        let trade_fee_rate =
+            launch_fee_rate.map_or(trade_fee_rate, |rate| rate.max(trade_fee_rate));
+        let swap_step = swap_math::compute_swap_step(
+            sqrt_price_start_x64,
+            target_price,
+            liquidity,
+            amount_specified_remaining,
+            trade_fee_rate,
+            is_base_input,
+            zero_for_one,
+            block_timestamp,
+        )?;
+        sqrt_price_x64 = swap_step.sqrt_price_next_x64;
+
+        if is_base_input {
+            amount_specified_remaining = amount_specified_remaining
+                .checked_sub(swap_step.amount_in + swap_step.fee_amount)
+                .ok_or(ErrorCode::CalculateOverflow)?;
+            amount_calculated = amount_calculated
+                .checked_add(swap_step.amount_out)
+                .ok_or(ErrorCode::CalculateOverflow)?;
+        } else {
+            amount_specified_remaining = amount_specified_remaining
+                .checked_sub(swap_step.amount_out)
+                .ok_or(ErrorCode::CalculateOverflow)?;
+            amount_calculated = swap_step
+                .amount_in
+                .checked_add(swap_step.fee_amount)
+                .and_then(|amount| amount_calculated.checked_add(amount))
+                .ok_or(ErrorCode::CalculateOverflow)?;
+        }
+
+        let protocol_fee = fee_share(swap_step.fee_amount, amm_config.protocol_fee_rate);
+        let fund_fee = fee_share(swap_step.fee_amount, amm_config.fund_fee_rate);
+        result.fee_amount += swap_step.fee_amount;
+        result.protocol_fee += protocol_fee;
+        result.fund_fee += fund_fee;
+        // the program only accrues the lp fee while there is liquidity in range
+        if liquidity > 0 {
+            result.lp_fee += swap_step.fee_amount - protocol_fee - fund_fee;
+        }
+
+        if sqrt_price_x64 == sqrt_price_next_x64 {
+            if next_initialized_tick.is_initialized() {
+                let mut liquidity_net = next_initialized_tick.liquidity_net;
+                if zero_for_one {
+                    liquidity_net = liquidity_net.neg();
+                }
+                liquidity = liquidity_math::add_delta(liquidity, liquidity_net)?;
+                result.ticks_crossed += 1;
+            }
+            tick = if zero_for_one {
+                tick_next - 1
+            } else {
+                tick_next
+            };
+        } else if sqrt_price_x64 != sqrt_price_start_x64 {
+            tick = tick_math::get_tick_at_sqrt_price(sqrt_price_x64)?;
+        }
+    }
+
+    let amount_used = amount_specified - amount_specified_remaining;
+    (result.amount_in, result.amount_out) = if is_base_input {
+        (amount_used, amount_calculated)
+    } else {
+        (amount_calculated, amount_used)
+    };
+    result.sqrt_price_x64_after = sqrt_price_x64;
+    result.tick_after = tick;
+    result.liquidity_after = liquidity;
+    Ok(result)
+}
+
+/// Reads the data of an observation account the way the program loads it, returning the
+/// observation state and the observations appended by `increase_observation_cardinality`
+pub fn load_observation_account(data: &[u8]) -> Result<(ObservationState, Vec<Observation>)> {
+    let state_len = std::mem::size_of::<ObservationState>();
+    require!(
+        data.len() >= 8 + state_len,
+        anchor_lang::error::ErrorCode::AccountDidNotDeserialize
+    );
+    require!(
+        data[..8] == ObservationState::DISCRIMINATOR[..],
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    let (state_data, trailing_data) = data[8..].split_at(state_len);
+    let trailing_len = trailing_data.len() / Observation::LEN * Observation::LEN;
+    Ok((
+        *bytemuck::from_bytes(state_data),
+        bytemuck::cast_slice(&trailing_data[..trailing_len]).to_vec(),
+    ))
+}
+
+fn find_tick_array(tick_arrays: &[TickArrayState], start_index: i32) -> Result<TickArrayState> {
+    tick_arrays
+        .iter()
+        .find(|tick_array| tick_array.start_tick_index == start_index)
+        .copied()
+        .ok_or_else(|| error!(ErrorCode::NotEnoughTickArrayAccount))
+}
+
+fn fee_share(fee_amount: u64, fee_rate: u32) -> u64 {
+    if fee_rate == 0 {
+        return 0;
+    }
+    U128::from(fee_amount)
+        .mul_div_floor(U128::from(fee_rate), U128::from(FEE_RATE_DENOMINATOR_VALUE))
+        .unwrap()
+        .as_u64()
+}
@@ -2,8 +2,13 @@ use crate::error::ErrorCode;
 use anchor_lang::prelude::*;
 
 pub const AMM_CONFIG_SEED: &str = "amm_config";
+pub const AMM_CONFIG_PENDING_OWNER_SEED: &str = "amm_config_pending_owner";
+pub const AMM_CONFIG_PENDING_UPDATE_SEED: &str = "amm_config_pending_update";
+pub const POOL_CONFIG_PENDING_UPDATE_SEED: &str = "pool_config_pending_update";
 
 pub const FEE_RATE_DENOMINATOR_VALUE: u32 = 1_000_000;
+/// The dynamic fee added for each tick of volatility, denominated in hundredths of a bip (10^-6)
+pub const DYNAMIC_FEE_RATE_PER_TICK: u32 = 10;
 
 /// Holds the current owner of the factory
 #[account]
@@ -22,10 +27,26 @@ pub struct AmmConfig {
     pub tick_spacing: u16,
     /// The fund fee, denominated in hundredths of a bip (10^-6)
     pub fund_fee_rate: u32,
-    // padding space for upgrade
-    pub padding_u32: u32,
+    /// The flash loan fee, denominated in hundredths of a bip (10^-6), flash loans are disabled when it is zero
+    pub flash_loan_fee_rate: u32,
     pub fund_owner: Pubkey,
-    pub padding: [u64; 3],
+    /// The lower bound of the dynamic trade fee, denominated in hundredths of a bip (10^-6)
+    pub dynamic_fee_min_rate: u32,
+    /// The upper bound of the dynamic trade fee, the dynamic fee mode is disabled when it is zero
+    pub dynamic_fee_max_rate: u32,
+    /// Bitwise representation of the operations disabled for the pools of the config, same bits
+    /// as the pool status. It applies on top of the pool status, swaps and flash loans read it
+    /// from the config and the other instructions from the copy cached on the pool
+    pub status: u8,
+    pub padding1: [u8; 3],
+    /// The max share of the trade fee a swap can pay to its referrer, denominated in hundredths
+    /// of a bip (10^-6) of the trade fee like the protocol fee
+    pub max_referral_fee_rate: u32,
+    /// The max launch fee start rate of the pools created in the config, denominated in
+    /// hundredths of a bip (10^-6), the launch fee is disabled when it is zero
+    pub max_launch_fee_start_rate: u32,
+    /// The max duration in seconds of the launch fee schedule of the pools created in the config
+    pub max_launch_fee_duration: u32,
 }
 
 impl AmmConfig {
@@ -42,6 +63,181 @@ impl AmmConfig {
         );
         Ok(())
     }
+
+    pub fn is_dynamic_fee_enabled(&self) -> bool {
+        self.dynamic_fee_max_rate > 0
+    }
+
+    /// Returns the trade fee rate for the given volatility accumulator,
+    /// falls back to the static trade fee rate when the dynamic fee mode is disabled
+    pub fn get_trade_fee_rate(&self, volatility_accumulator: u32) -> u32 {
+        if !self.is_dynamic_fee_enabled() {
+            return self.trade_fee_rate;
+        }
+        volatility_accumulator
+            .saturating_mul(DYNAMIC_FEE_RATE_PER_TICK)
+            .saturating_add(self.dynamic_fee_min_rate)
+            .min(self.dynamic_fee_max_rate)
+    }
+
+    pub fn is_flash_loan_enabled(&self) -> bool {
+        self.flash_loan_fee_rate > 0
+    }
+
+    /// Returns the fee charged for a flash loan of `amount`, rounded up
+    pub fn get_flash_loan_fee(&self, amount: u64) -> u64 {
+        u64::try_from(
+            (u128::from(amount) * u128::from(self.flash_loan_fee_rate))
+                .div_ceil(u128::from(FEE_RATE_DENOMINATOR_VALUE)),
+        )
+        .unwrap()
+    }
+}
+
+/// Holds the owners proposed for an amm config until they accept
+#[account]
+#[derive(Default, Debug)]
+pub struct AmmConfigPendingOwner {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The amm config the owners are proposed for
+    pub amm_config: Pubkey,
+    /// Address proposed as the protocol owner, the default pubkey when there is no proposal
+    pub pending_owner: Pubkey,
+    /// Address proposed as the fund owner, the default pubkey when there is no proposal
+    pub pending_fund_owner: Pubkey,
+    pub padding: [u64; 4],
+}
+
+impl AmmConfigPendingOwner {
+    pub const LEN: usize = 8 + 1 + 32 * 3 + 32;
+
+    /// Moves the proposals signed for to the amm config and clears them
+    pub fn accept(&mut self, amm_config: &mut AmmConfig, signer: Pubkey) -> Result<()> {
+        require!(signer != Pubkey::default(), ErrorCode::NotApproved);
+        let mut accepted = false;
+        if signer == self.pending_owner {
+            amm_config.owner = signer;
+            self.pending_owner = Pubkey::default();
+            accepted = true;
+        }
+        if signer == self.pending_fund_owner {
+            amm_config.fund_owner = signer;
+            self.pending_fund_owner = Pubkey::default();
+            accepted = true;
+        }
+        require!(accepted, ErrorCode::NotApproved);
+        Ok(())
+    }
+}
+
+/// Holds an amm config update until its delay has passed, one account per config and param
+#[account]
+#[derive(Default, Debug)]
+pub struct AmmConfigPendingUpdate {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The amm config the update is scheduled for
+    pub amm_config: Pubkey,
+    /// The `update_amm_config` param of the update
+    pub param: u8,
+    /// The new value of the param
+    pub value: u32,
+    /// The timestamp the update can be executed at
+    pub execute_after: u64,
+    pub padding: [u64; 4],
+}
+
+impl AmmConfigPendingUpdate {
+    pub const LEN: usize = 8 + 1 + 32 + 1 + 4 + 8 + 32;
+}
+
+/// The kinds of `PoolConfigPendingUpdate`
+#[derive(Clone, Copy, PartialEq)]
+pub enum PoolConfigUpdateKind {
+    /// `set_pool_fee_overrides`
+    FeeOverrides,
+    /// `migrate_pool_config`
+    Migration,
+}
+
+/// Holds a pool fee override change or a pool config migration until the config update delay
+/// has passed, one account per pool and kind
+#[account]
+#[derive(Default, Debug)]
+pub struct PoolConfigPendingUpdate {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The pool the update is scheduled for
+    pub pool_state: Pubkey,
+    /// See `PoolConfigUpdateKind`
+    pub kind: u8,
+    /// The fee overrides of a `FeeOverrides` update
+    pub trade_fee_rate: Option<u32>,
+    pub protocol_fee_rate: Option<u32>,
+    pub fund_fee_rate: Option<u32>,
+    /// The config of a `Migration` update
+    pub new_amm_config: Pubkey,
+    /// The timestamp the update can be executed at
+    pub execute_after: u64,
+    pub padding: [u64; 4],
+}
+
+impl PoolConfigPendingUpdate {
+    pub const LEN: usize = 8 + 1 + 32 + 1 + 5 * 3 + 32 + 8 + 32;
+
+    /// Checks the update can be executed and matches the fee overrides being set
+    pub fn check_fee_overrides(
+        &self,
+        trade_fee_rate: Option<u32>,
+        protocol_fee_rate: Option<u32>,
+        fund_fee_rate: Option<u32>,
+        block_timestamp: u64,
+    ) -> Result<()> {
+        require!(
+            self.kind == PoolConfigUpdateKind::FeeOverrides as u8
+                && self.trade_fee_rate == trade_fee_rate
+                && self.protocol_fee_rate == protocol_fee_rate
+                && self.fund_fee_rate == fund_fee_rate,
+            ErrorCode::PoolConfigUpdateMismatch
+        );
+        require_gte!(
+            block_timestamp,
+            self.execute_after,
+            ErrorCode::ConfigUpdateNotReady
+        );
+        Ok(())
+    }
+
+    /// Checks the update can be executed and matches the config the pool is migrated to
+    pub fn check_migration(&self, new_amm_config: Pubkey, block_timestamp: u64) -> Result<()> {
+        require!(
+            self.kind == PoolConfigUpdateKind::Migration as u8
+                && self.new_amm_config == new_amm_config,
+            ErrorCode::PoolConfigUpdateMismatch
+        );
+        require_gte!(
+            block_timestamp,
+            self.execute_after,
+            ErrorCode::ConfigUpdateNotReady
+        );
+        Ok(())
+    }
+}
+
+/// Emitted when a pool config update is scheduled or canceled
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolConfigPendingUpdateEvent {
+    pub pool_state: Pubkey,
+    pub kind: u8,
+    pub trade_fee_rate: Option<u32>,
+    pub protocol_fee_rate: Option<u32>,
+    pub fund_fee_rate: Option<u32>,
+    pub new_amm_config: Pubkey,
+    pub execute_after: u64,
+    /// Whether the update was canceled instead of scheduled
+    pub canceled: bool,
 }
 
 /// Emitted when create or update a config
@@ -55,4 +251,160 @@ pub struct ConfigChangeEvent {
     pub tick_spacing: u16,
     pub fund_fee_rate: u32,
     pub fund_owner: Pubkey,
+    pub dynamic_fee_min_rate: u32,
+    pub dynamic_fee_max_rate: u32,
+    pub flash_loan_fee_rate: u32,
+    pub status: u8,
+    pub max_referral_fee_rate: u32,
+    pub max_launch_fee_start_rate: u32,
+    pub max_launch_fee_duration: u32,
+    /// The param of the scheduled update, only set when the update is scheduled
+    pub scheduled_param: u8,
+    /// The value of the scheduled update, only set when the update is scheduled
+    pub scheduled_value: u32,
+    /// The timestamp the scheduled update can be executed at, zero when the change is applied
+    pub execute_after: u64,
+    /// Whether the scheduled update was canceled, the config is unchanged then
+    pub canceled: bool,
+}
+
+impl ConfigChangeEvent {
+    /// The event of a change applied to the amm config
+    pub fn new(amm_config: &AmmConfig) -> Self {
+        Self {
+            index: amm_config.index,
+            owner: amm_config.owner,
+            protocol_fee_rate: amm_config.protocol_fee_rate,
+            trade_fee_rate: amm_config.trade_fee_rate,
+            tick_spacing: amm_config.tick_spacing,
+            fund_fee_rate: amm_config.fund_fee_rate,
+            fund_owner: amm_config.fund_owner,
+            dynamic_fee_min_rate: amm_config.dynamic_fee_min_rate,
+            dynamic_fee_max_rate: amm_config.dynamic_fee_max_rate,
+            flash_loan_fee_rate: amm_config.flash_loan_fee_rate,
+            status: amm_config.status,
+            max_referral_fee_rate: amm_config.max_referral_fee_rate,
+            max_launch_fee_start_rate: amm_config.max_launch_fee_start_rate,
+            max_launch_fee_duration: amm_config.max_launch_fee_duration,
+            scheduled_param: 0,
+            scheduled_value: 0,
+            execute_after: 0,
+            canceled: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod config_test {
+    use super::*;
+
+    #[test]
+    fn get_trade_fee_rate_test() {
+        let mut amm_config = AmmConfig {
+            trade_fee_rate: 2500,
+            ..Default::default()
+        };
+        // static mode ignores the volatility
+        assert_eq!(amm_config.get_trade_fee_rate(0), 2500);
+        assert_eq!(amm_config.get_trade_fee_rate(1000), 2500);
+
+        amm_config.dynamic_fee_min_rate = 500;
+        amm_config.dynamic_fee_max_rate = 10000;
+        assert_eq!(amm_config.get_trade_fee_rate(0), 500);
+        assert_eq!(
+            amm_config.get_trade_fee_rate(100),
+            500 + 100 * DYNAMIC_FEE_RATE_PER_TICK
+        );
+        // bounded by the max rate
+        assert_eq!(amm_config.get_trade_fee_rate(100_000), 10000);
+        assert_eq!(amm_config.get_trade_fee_rate(u32::MAX), 10000);
+    }
+
+    #[test]
+    fn get_flash_loan_fee_test() {
+        let mut amm_config = AmmConfig::default();
+        assert!(!amm_config.is_flash_loan_enabled());
+
+        amm_config.flash_loan_fee_rate = 900;
+        assert!(amm_config.is_flash_loan_enabled());
+        assert_eq!(amm_config.get_flash_loan_fee(1_000_000), 900);
+        // rounded up in favor of the pool
+        assert_eq!(amm_config.get_flash_loan_fee(1), 1);
+        assert_eq!(amm_config.get_flash_loan_fee(0), 0);
+        assert_eq!(amm_config.get_flash_loan_fee(u64::MAX), 16602069666338597);
+    }
+
+    #[test]
+    fn accept_pending_owner_test() {
+        let owner = Pubkey::new_unique();
+        let fund_owner = Pubkey::new_unique();
+        let mut amm_config = AmmConfig::default();
+        let mut pending_owner = AmmConfigPendingOwner {
+            pending_owner: owner,
+            ..Default::default()
+        };
+        // only a proposed key can accept
+        assert!(pending_owner
+            .accept(&mut amm_config, Pubkey::new_unique())
+            .is_err());
+        assert!(pending_owner
+            .accept(&mut amm_config, Pubkey::default())
+            .is_err());
+
+        pending_owner.accept(&mut amm_config, owner).unwrap();
+        assert_eq!(amm_config.owner, owner);
+        assert_eq!(amm_config.fund_owner, Pubkey::default());
+        assert_eq!(pending_owner.pending_owner, Pubkey::default());
+        // the proposal is consumed
+        assert!(pending_owner.accept(&mut amm_config, owner).is_err());
+
+        pending_owner.pending_fund_owner = fund_owner;
+        pending_owner.accept(&mut amm_config, fund_owner).unwrap();
+        assert_eq!(amm_config.owner, owner);
+        assert_eq!(amm_config.fund_owner, fund_owner);
+    }
+
+    #[test]
+    fn pool_config_pending_update_test() {
+        let new_amm_config = Pubkey::new_unique();
+        let pending_update = PoolConfigPendingUpdate {
+            kind: PoolConfigUpdateKind::FeeOverrides as u8,
+            trade_fee_rate: Some(100),
+            fund_fee_rate: Some(0),
+            execute_after: 1000,
+            ..Default::default()
+        };
+        assert!(pending_update
+            .check_fee_overrides(Some(100), None, Some(0), 1000)
+            .is_ok());
+        // not before the delay has passed
+        assert!(pending_update
+            .check_fee_overrides(Some(100), None, Some(0), 999)
+            .is_err());
+        // only the scheduled rates
+        assert!(pending_update
+            .check_fee_overrides(Some(100), None, None, 1000)
+            .is_err());
+        assert!(pending_update
+            .check_fee_overrides(Some(200), None, Some(0), 1000)
+            .is_err());
+        assert!(pending_update
+            .check_migration(new_amm_config, 1000)
+            .is_err());
+
+        let pending_update = PoolConfigPendingUpdate {
+            kind: PoolConfigUpdateKind::Migration as u8,
+            new_amm_config,
+            execute_after: 1000,
+            ..Default::default()
+        };
+        assert!(pending_update.check_migration(new_amm_config, 1000).is_ok());
+        assert!(pending_update.check_migration(new_amm_config, 999).is_err());
+        assert!(pending_update
+            .check_migration(Pubkey::new_unique(), 1000)
+            .is_err());
+        assert!(pending_update
+            .check_fee_overrides(None, None, None, 1000)
+            .is_err());
+    }
 }
@@ -0,0 +1,155 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+pub const GLOBAL_CONFIG_SEED: &str = "global_config";
+
+/// Holds the current admin of the program
+#[account]
+#[derive(Default, Debug)]
+pub struct GlobalConfig {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// Address of the admin
+    pub admin: Pubkey,
+    /// Address proposed as the next admin, the default pubkey when there is no proposal
+    pub pending_admin: Pubkey,
+    /// The minimum seconds between scheduling and executing an amm config update
+    pub config_update_delay: u64,
+    /// The lower delay waiting for the current delay to pass
+    pub pending_config_update_delay: u64,
+    /// The timestamp the pending delay can be applied at, zero when there is no pending delay
+    pub pending_config_update_delay_time: u64,
+    /// Address allowed to disable operations of pools and amm configs, it can not enable them
+    pub pause_guardian: Pubkey,
+    pub padding: [u64; 1],
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 64;
+
+    pub fn initialize(&mut self, bump: u8, admin: Pubkey) {
+        self.bump = bump;
+        self.admin = admin;
+        self.pending_admin = Pubkey::default();
+        self.config_update_delay = 0;
+        self.pending_config_update_delay = 0;
+        self.pending_config_update_delay_time = 0;
+        self.pause_guardian = Pubkey::default();
+    }
+
+    pub fn is_admin(&self, key: Pubkey) -> bool {
+        key != Pubkey::default() && key == self.admin
+    }
+
+    /// The admin or the pause guardian
+    pub fn is_pause_authority(&self, key: Pubkey) -> bool {
+        self.is_admin(key) || (key != Pubkey::default() && key == self.pause_guardian)
+    }
+
+    pub fn propose_admin(&mut self, new_admin: Pubkey) {
+        self.pending_admin = new_admin;
+    }
+
+    /// Makes the pending admin the admin, must be signed by the pending admin
+    pub fn accept_admin(&mut self, signer: Pubkey) -> Result<()> {
+        require!(
+            self.pending_admin != Pubkey::default() && signer == self.pending_admin,
+            ErrorCode::NotApproved
+        );
+        self.admin = self.pending_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
+    }
+
+    /// A higher delay applies immediately. A lower delay is timelocked by the current delay,
+    /// it applies when it is set again once `pending_config_update_delay_time` has passed.
+    /// Returns whether the delay was applied
+    pub fn set_config_update_delay(&mut self, delay: u64, block_timestamp: u64) -> bool {
+        let applied = delay >= self.config_update_delay
+            || (self.pending_config_update_delay_time != 0
+                && self.pending_config_update_delay == delay
+                && block_timestamp >= self.pending_config_update_delay_time);
+        if applied {
+            self.config_update_delay = delay;
+            self.pending_config_update_delay = 0;
+            self.pending_config_update_delay_time = 0;
+        } else {
+            self.pending_config_update_delay = delay;
+            self.pending_config_update_delay_time =
+                block_timestamp.saturating_add(self.config_update_delay);
+        }
+        applied
+    }
+}
+
+/// Emitted when the admin is proposed or accepted
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct AdminChangeEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+/// Emitted when the config update delay is set, a lower delay stays pending until it is set again
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct ConfigUpdateDelayChangeEvent {
+    pub config_update_delay: u64,
+    pub pending_config_update_delay: u64,
+    pub pending_config_update_delay_time: u64,
+}
+
+#[cfg(test)]
+mod global_config_test {
+    use super::*;
+
+    #[test]
+    fn accept_admin_test() {
+        let admin = Pubkey::new_unique();
+        let new_admin = Pubkey::new_unique();
+        let mut global_config = GlobalConfig::default();
+        global_config.initialize(255, admin);
+        assert!(global_config.is_admin(admin));
+
+        // nothing to accept without a proposal
+        assert!(global_config.accept_admin(new_admin).is_err());
+
+        global_config.propose_admin(new_admin);
+        // the proposal does not change the admin
+        assert!(global_config.is_admin(admin));
+        assert!(global_config.accept_admin(admin).is_err());
+
+        global_config.accept_admin(new_admin).unwrap();
+        assert!(global_config.is_admin(new_admin));
+        assert!(!global_config.is_admin(admin));
+        assert_eq!(global_config.pending_admin, Pubkey::default());
+    }
+
+    #[test]
+    fn set_config_update_delay_test() {
+        let mut global_config = GlobalConfig::default();
+        // increases apply immediately
+        assert!(global_config.set_config_update_delay(86400, 1000));
+        assert_eq!(global_config.config_update_delay, 86400);
+
+        // a decrease waits for the current delay
+        assert!(!global_config.set_config_update_delay(3600, 2000));
+        assert_eq!(global_config.config_update_delay, 86400);
+        assert_eq!(global_config.pending_config_update_delay_time, 2000 + 86400);
+        assert!(!global_config.set_config_update_delay(3600, 2000 + 86399));
+        // setting it again before the time restarts the wait
+        assert_eq!(
+            global_config.pending_config_update_delay_time,
+            2000 + 86399 + 86400
+        );
+        assert!(global_config.set_config_update_delay(3600, 2000 + 86399 + 86400));
+        assert_eq!(global_config.config_update_delay, 3600);
+        assert_eq!(global_config.pending_config_update_delay_time, 0);
+
+        // another value than the pending one is scheduled again
+        assert!(!global_config.set_config_update_delay(0, 10_000));
+        assert!(!global_config.set_config_update_delay(60, 10_000 + 3600));
+        assert_eq!(global_config.pending_config_update_delay, 60);
+        assert_eq!(global_config.config_update_delay, 3600);
+    }
+}
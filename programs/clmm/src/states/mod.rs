@@ -1,4 +1,5 @@
 pub mod config;
+pub mod global_config;
 pub mod operation_account;
 pub mod oracle;
 pub mod personal_position;
@@ -9,6 +10,7 @@ pub mod tick_array;
 pub mod tickarray_bitmap_extension;
 
 pub use config::*;
+pub use global_config::*;
 pub use operation_account::*;
 pub use oracle::*;
 pub use personal_position::*;
@@ -2,12 +2,16 @@
 ///
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::libraries::{big_num::U256, full_math::MulDiv};
 use crate::util::get_recent_epoch;
 
 /// Seed to derive account address and signature
 pub const OBSERVATION_SEED: &str = "observation";
 // Number of ObservationState element
 pub const OBSERVATION_NUM: usize = 100;
+// Max number of observations after increasing the observation cardinality, about 24 hours with the default duration
+pub const OBSERVATION_MAX_NUM: usize = 5760;
 pub const OBSERVATION_UPDATE_DURATION_DEFAULT: u32 = 15;
 
 /// The element of observations in ObservationState
@@ -19,17 +23,21 @@ pub struct Observation {
     pub block_timestamp: u32,
     /// the cumulative of tick during the duration time
     pub tick_cumulative: i64,
+    /// the seconds per unit of in range liquidity of the pool as of the block timestamp, as a Q64.64
+    pub seconds_per_liquidity_cumulative_x64: u128,
     /// padding for feature update
-    pub padding: [u64; 4],
+    pub padding: [u64; 2],
 }
 
 impl Observation {
-    pub const LEN: usize = 4 + 8 + 8 * 4;
+    pub const LEN: usize = 4 + 8 + 16 + 8 * 2;
 }
 
+/// The observations beyond `OBSERVATION_NUM` are stored after `ObservationState` in the account data,
+/// they are appended by `increase_observation_cardinality` and loaded with `AccountLoad::load_data_with_trailing`
 #[account(zero_copy(unsafe))]
 #[repr(C, packed)]
-#[cfg_attr(feature = "client", derive(Debug))]
+#[cfg_attr(any(feature = "client", test), derive(Debug))]
 pub struct ObservationState {
     /// Whether the ObservationState is initialized
     pub initialized: bool,
@@ -41,8 +49,11 @@ pub struct ObservationState {
     pub pool_id: Pubkey,
     /// observation array
     pub observations: [Observation; OBSERVATION_NUM],
+    /// the number of observations in use, 0 means OBSERVATION_NUM for accounts created before it was introduced
+    pub observation_cardinality: u16,
+    pub padding_u16: [u16; 3],
     /// padding for feature update
-    pub padding: [u64; 4],
+    pub padding: [u64; 3],
 }
 
 impl Default for ObservationState {
@@ -54,13 +65,21 @@ impl Default for ObservationState {
             observation_index: 0,
             pool_id: Pubkey::default(),
             observations: [Observation::default(); OBSERVATION_NUM],
-            padding: [0u64; 4],
+            observation_cardinality: OBSERVATION_NUM as u16,
+            padding_u16: [0u16; 3],
+            padding: [0u64; 3],
         }
     }
 }
 
 impl ObservationState {
-    pub const LEN: usize = 8 + 1 + 8 + 2 + 32 + (Observation::LEN * OBSERVATION_NUM) + 8 * 4;
+    pub const LEN: usize =
+        8 + 1 + 8 + 2 + 32 + (Observation::LEN * OBSERVATION_NUM) + 2 + 2 * 3 + 8 * 3;
+
+    /// The account space needed to store `observation_cardinality` observations
+    pub fn space(observation_cardinality: usize) -> usize {
+        Self::LEN + Observation::LEN * observation_cardinality.saturating_sub(OBSERVATION_NUM)
+    }
 
     pub fn initialize(&mut self, pool_id: Pubkey) -> Result<()> {
         self.initialized = false;
@@ -68,43 +87,291 @@ impl ObservationState {
         self.observation_index = 0;
         self.pool_id = pool_id;
         self.observations = [Observation::default(); OBSERVATION_NUM];
-        self.padding = [0u64; 4];
+        self.observation_cardinality = OBSERVATION_NUM as u16;
+        self.padding_u16 = [0u16; 3];
+        self.padding = [0u64; 3];
         Ok(())
     }
 
+    /// The number of observations in use of the ring buffer
+    pub fn cardinality(&self) -> usize {
+        if self.observation_cardinality == 0 {
+            OBSERVATION_NUM
+        } else {
+            self.observation_cardinality as usize
+        }
+    }
+
+    fn observation(&self, observation_extension: &[Observation], index: usize) -> Observation {
+        if index < OBSERVATION_NUM {
+            self.observations[index]
+        } else {
+            observation_extension[index - OBSERVATION_NUM]
+        }
+    }
+
+    fn observation_mut<'a>(
+        &'a mut self,
+        observation_extension: &'a mut [Observation],
+        index: usize,
+    ) -> &'a mut Observation {
+        if index < OBSERVATION_NUM {
+            &mut self.observations[index]
+        } else {
+            &mut observation_extension[index - OBSERVATION_NUM]
+        }
+    }
+
     /// Writes an oracle observation to the account
     ///
     /// # Arguments
     ///
     /// * `self` - The ObservationState account to write in
+    /// * `observation_extension` - The observations stored after the ObservationState in the account
     /// * `block_timestamp` - The current timestamp of to update
+    /// * `tick` - The tick of the pool during the time since the last observation
+    /// * `seconds_per_liquidity_cumulative_x64` - The seconds per liquidity accumulator of the pool as of `block_timestamp`
     ///
-    pub fn update(&mut self, block_timestamp: u32, tick: i32) {
-        let observation_index = self.observation_index;
+    pub fn update(
+        &mut self,
+        observation_extension: &mut [Observation],
+        block_timestamp: u32,
+        tick: i32,
+        seconds_per_liquidity_cumulative_x64: u128,
+    ) {
+        let observation_index = self.observation_index as usize;
         if !self.initialized {
             self.initialized = true;
-            self.observations[observation_index as usize].block_timestamp = block_timestamp;
-            self.observations[observation_index as usize].tick_cumulative = 0;
+            let observation = self.observation_mut(observation_extension, observation_index);
+            observation.block_timestamp = block_timestamp;
+            observation.tick_cumulative = 0;
+            observation.seconds_per_liquidity_cumulative_x64 = seconds_per_liquidity_cumulative_x64;
         } else {
-            let last_observation = self.observations[observation_index as usize];
+            let last_observation = self.observation(observation_extension, observation_index);
             let delta_time = block_timestamp.saturating_sub(last_observation.block_timestamp);
             if delta_time < OBSERVATION_UPDATE_DURATION_DEFAULT {
                 return;
             }
 
             let delta_tick_cumulative = i64::from(tick).checked_mul(delta_time.into()).unwrap();
-            let next_observation_index = if observation_index as usize == OBSERVATION_NUM - 1 {
-                0
-            } else {
+            let cardinality = self.cardinality();
+            let capacity = OBSERVATION_NUM + observation_extension.len();
+            let next_observation_index = if observation_index + 1 < cardinality {
+                observation_index + 1
+            } else if capacity > cardinality {
+                // the buffer only grows once the index reaches the end of the used observations,
+                // so the observations keep their chronological order
+                self.observation_cardinality = capacity as u16;
                 observation_index + 1
+            } else {
+                0
             };
-            self.observations[next_observation_index as usize].block_timestamp = block_timestamp;
-            self.observations[next_observation_index as usize].tick_cumulative = last_observation
+            let next_observation =
+                self.observation_mut(observation_extension, next_observation_index);
+            next_observation.block_timestamp = block_timestamp;
+            next_observation.tick_cumulative = last_observation
                 .tick_cumulative
                 .wrapping_add(delta_tick_cumulative);
-            self.observation_index = next_observation_index;
+            next_observation.seconds_per_liquidity_cumulative_x64 =
+                seconds_per_liquidity_cumulative_x64;
+            self.observation_index = next_observation_index as u16;
+        }
+    }
+
+    /// Returns the index of the oldest observation and the number of written observations
+    fn oldest_index_and_count(&self, observation_extension: &[Observation]) -> (usize, usize) {
+        let cardinality = self.cardinality();
+        let next_index = (self.observation_index as usize + 1) % cardinality;
+        if self
+            .observation(observation_extension, next_index)
+            .block_timestamp
+            == 0
+        {
+            // the ring buffer has not wrapped yet
+            (0, self.observation_index as usize + 1)
+        } else {
+            (next_index, cardinality)
+        }
+    }
+
+    /// Returns the block timestamp of the oldest observation, None before the first observation
+    pub fn oldest_block_timestamp(&self, observation_extension: &[Observation]) -> Option<u32> {
+        if !self.initialized {
+            return None;
+        }
+        let (oldest_index, _) = self.oldest_index_and_count(observation_extension);
+        Some(
+            self.observation(observation_extension, oldest_index)
+                .block_timestamp,
+        )
+    }
+
+    /// Returns the tick cumulative and the seconds per liquidity cumulative as of `seconds_ago` before `current_timestamp`
+    ///
+    /// # Arguments
+    ///
+    /// * `observation_extension` - The observations stored after the ObservationState in the account
+    /// * `current_timestamp` - The current block timestamp
+    /// * `seconds_ago` - How far back to look, 0 means the current timestamp
+    /// * `tick_current` - The current tick of the pool, used to extrapolate past the newest observation
+    /// * `seconds_per_liquidity_current_x64` - The seconds per liquidity accumulator of the pool as of `current_timestamp`
+    ///
+    pub fn observe_single(
+        &self,
+        observation_extension: &[Observation],
+        current_timestamp: u32,
+        seconds_ago: u32,
+        tick_current: i32,
+        seconds_per_liquidity_current_x64: u128,
+    ) -> Result<(i64, u128)> {
+        require!(self.initialized, ErrorCode::ObservationNotInitialized);
+        let target = current_timestamp
+            .checked_sub(seconds_ago)
+            .ok_or(ErrorCode::ObservationTooOld)?;
+
+        let newest = self.observation(observation_extension, self.observation_index as usize);
+        if target >= newest.block_timestamp {
+            let delta_time = target - newest.block_timestamp;
+            let tick_cumulative = newest
+                .tick_cumulative
+                .wrapping_add(i64::from(tick_current) * i64::from(delta_time));
+            let seconds_per_liquidity_cumulative_x64 = if target == current_timestamp {
+                seconds_per_liquidity_current_x64
+            } else {
+                interpolate_seconds_per_liquidity(
+                    newest.seconds_per_liquidity_cumulative_x64,
+                    seconds_per_liquidity_current_x64,
+                    delta_time,
+                    current_timestamp - newest.block_timestamp,
+                )
+            };
+            return Ok((tick_cumulative, seconds_per_liquidity_cumulative_x64));
+        }
+
+        let cardinality = self.cardinality();
+        let (oldest_index, count) = self.oldest_index_and_count(observation_extension);
+        let oldest = self.observation(observation_extension, oldest_index);
+        require_gte!(target, oldest.block_timestamp, ErrorCode::ObservationTooOld);
+
+        // binary search keeping observations[low].block_timestamp <= target < observations[high].block_timestamp
+        let mut low = 0;
+        let mut high = count - 1;
+        while high - low > 1 {
+            let mid = (low + high) / 2;
+            let mid_timestamp = self
+                .observation(observation_extension, (oldest_index + mid) % cardinality)
+                .block_timestamp;
+            if mid_timestamp <= target {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let before = self.observation(observation_extension, (oldest_index + low) % cardinality);
+        let after = self.observation(observation_extension, (oldest_index + high) % cardinality);
+        if target == before.block_timestamp {
+            return Ok((
+                before.tick_cumulative,
+                before.seconds_per_liquidity_cumulative_x64,
+            ));
         }
+
+        // interpolate linearly between the two surrounding observations
+        let observation_time_delta = after.block_timestamp - before.block_timestamp;
+        let target_delta = target - before.block_timestamp;
+        let tick_cumulative_delta =
+            i128::from(after.tick_cumulative.wrapping_sub(before.tick_cumulative));
+        let tick_cumulative = before.tick_cumulative.wrapping_add(
+            (tick_cumulative_delta * i128::from(target_delta) / i128::from(observation_time_delta))
+                as i64,
+        );
+        let seconds_per_liquidity_cumulative_x64 = interpolate_seconds_per_liquidity(
+            before.seconds_per_liquidity_cumulative_x64,
+            after.seconds_per_liquidity_cumulative_x64,
+            target_delta,
+            observation_time_delta,
+        );
+        Ok((tick_cumulative, seconds_per_liquidity_cumulative_x64))
     }
+
+    /// Returns the tick cumulatives and the seconds per liquidity cumulatives for each of `seconds_agos`
+    pub fn observe(
+        &self,
+        observation_extension: &[Observation],
+        current_timestamp: u32,
+        seconds_agos: &[u32],
+        tick_current: i32,
+        seconds_per_liquidity_current_x64: u128,
+    ) -> Result<(Vec<i64>, Vec<u128>)> {
+        let cumulatives = seconds_agos
+            .iter()
+            .map(|seconds_ago| {
+                self.observe_single(
+                    observation_extension,
+                    current_timestamp,
+                    *seconds_ago,
+                    tick_current,
+                    seconds_per_liquidity_current_x64,
+                )
+            })
+            .collect::<Result<Vec<(i64, u128)>>>()?;
+        Ok(cumulatives.into_iter().unzip())
+    }
+
+    /// Returns the time-weighted average tick over the last `seconds_ago` seconds,
+    /// rounded towards negative infinity
+    pub fn consult(
+        &self,
+        observation_extension: &[Observation],
+        current_timestamp: u32,
+        seconds_ago: u32,
+        tick_current: i32,
+    ) -> Result<i32> {
+        require_gt!(seconds_ago, 0, ErrorCode::InvalidObservationPeriod);
+        let (tick_cumulative_now, _) =
+            self.observe_single(observation_extension, current_timestamp, 0, tick_current, 0)?;
+        let (tick_cumulative_ago, _) = self.observe_single(
+            observation_extension,
+            current_timestamp,
+            seconds_ago,
+            tick_current,
+            0,
+        )?;
+        let tick_cumulative_delta = tick_cumulative_now.wrapping_sub(tick_cumulative_ago);
+        let mut average_tick = tick_cumulative_delta / i64::from(seconds_ago);
+        if tick_cumulative_delta < 0 && tick_cumulative_delta % i64::from(seconds_ago) != 0 {
+            average_tick -= 1;
+        }
+        Ok(average_tick as i32)
+    }
+}
+
+/// Interpolates the seconds per liquidity accumulator `target_delta` seconds after `before`,
+/// where `after` is `time_delta` seconds after `before`
+fn interpolate_seconds_per_liquidity(
+    before: u128,
+    after: u128,
+    target_delta: u32,
+    time_delta: u32,
+) -> u128 {
+    let delta = U256::from(after.wrapping_sub(before))
+        .mul_div_floor(U256::from(target_delta), U256::from(time_delta))
+        .unwrap();
+    before.wrapping_add(delta.as_u128())
+}
+
+/// Emitted when the observation cardinality of a pool is increased
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct IncreaseObservationCardinalityEvent {
+    /// The pool of the observation account
+    pub pool_state: Pubkey,
+
+    /// The previous number of observations that can be stored
+    pub observation_cardinality_old: u16,
+
+    /// The new number of observations that can be stored
+    pub observation_cardinality_new: u16,
 }
 
 /// Returns the block timestamp truncated to 32 bits, i.e. mod 2**32
@@ -125,6 +392,7 @@ pub fn block_timestamp_mock() -> u64 {
 #[cfg(test)]
 pub mod oracle_layout_test {
     use super::*;
+    use crate::util::AccountLoad;
     use anchor_lang::Discriminator;
     #[test]
     fn test_observation_layout() {
@@ -132,12 +400,9 @@ pub mod oracle_layout_test {
         let recent_epoch: u64 = 0x123456789abcdef0;
         let observation_index: u16 = 0x1122;
         let pool_id: Pubkey = Pubkey::new_unique();
-        let padding: [u64; 4] = [
-            0x123456789abcde0f,
-            0x123456789abcd0ef,
-            0x123456789abc0def,
-            0x123456789ab0cdef,
-        ];
+        let observation_cardinality: u16 = 0x3344;
+        let padding_u16: [u16; 3] = [0x5566, 0x7788, 0x99aa];
+        let padding: [u64; 3] = [0x123456789abcde0f, 0x123456789abcd0ef, 0x123456789abc0def];
 
         let mut observation_datas = [0u8; Observation::LEN * OBSERVATION_NUM];
         let mut observations = [Observation::default(); OBSERVATION_NUM];
@@ -146,27 +411,24 @@ pub mod oracle_layout_test {
             let index = i + 1;
             let block_timestamp: u32 = u32::MAX - 3 * index as u32;
             let tick_cumulative: i64 = i64::MAX - 3 * index as i64;
-            let padding: [u64; 4] = [
-                u64::MAX - index as u64,
-                u64::MAX - 2 * index as u64,
-                u64::MAX - 3 * index as u64,
-                u64::MAX - 4 * index as u64,
-            ];
+            let seconds_per_liquidity_cumulative_x64: u128 = u128::MAX - 5 * index as u128;
+            let padding: [u64; 2] = [u64::MAX - index as u64, u64::MAX - 2 * index as u64];
             observations[i].block_timestamp = block_timestamp;
             observations[i].tick_cumulative = tick_cumulative;
+            observations[i].seconds_per_liquidity_cumulative_x64 =
+                seconds_per_liquidity_cumulative_x64;
             observations[i].padding = padding;
             observation_datas[offset..offset + 4].copy_from_slice(&block_timestamp.to_le_bytes());
             offset += 4;
             observation_datas[offset..offset + 8].copy_from_slice(&tick_cumulative.to_le_bytes());
             offset += 8;
+            observation_datas[offset..offset + 16]
+                .copy_from_slice(&seconds_per_liquidity_cumulative_x64.to_le_bytes());
+            offset += 16;
             observation_datas[offset..offset + 8].copy_from_slice(&padding[0].to_le_bytes());
             offset += 8;
             observation_datas[offset..offset + 8].copy_from_slice(&padding[1].to_le_bytes());
             offset += 8;
-            observation_datas[offset..offset + 8].copy_from_slice(&padding[2].to_le_bytes());
-            offset += 8;
-            observation_datas[offset..offset + 8].copy_from_slice(&padding[3].to_le_bytes());
-            offset += 8;
         }
 
         // serialize original data
@@ -188,14 +450,20 @@ pub mod oracle_layout_test {
         observation_state_data[offset..offset + Observation::LEN * OBSERVATION_NUM]
             .copy_from_slice(&observation_datas);
         offset += Observation::LEN * OBSERVATION_NUM;
+        observation_state_data[offset..offset + 2]
+            .copy_from_slice(&observation_cardinality.to_le_bytes());
+        offset += 2;
+        for i in 0..3 {
+            observation_state_data[offset..offset + 2]
+                .copy_from_slice(&padding_u16[i].to_le_bytes());
+            offset += 2;
+        }
         observation_state_data[offset..offset + 8].copy_from_slice(&padding[0].to_le_bytes());
         offset += 8;
         observation_state_data[offset..offset + 8].copy_from_slice(&padding[1].to_le_bytes());
         offset += 8;
         observation_state_data[offset..offset + 8].copy_from_slice(&padding[2].to_le_bytes());
         offset += 8;
-        observation_state_data[offset..offset + 8].copy_from_slice(&padding[3].to_le_bytes());
-        offset += 8;
         // len check
         assert_eq!(offset, observation_state_data.len());
         assert_eq!(
@@ -217,6 +485,10 @@ pub mod oracle_layout_test {
         assert_eq!(unpack_observation_index, observation_index);
         let unpack_pool_id = unpack_data.pool_id;
         assert_eq!(unpack_pool_id, pool_id);
+        let unpack_observation_cardinality = unpack_data.observation_cardinality;
+        assert_eq!(unpack_observation_cardinality, observation_cardinality);
+        let unpack_padding_u16 = unpack_data.padding_u16;
+        assert_eq!(unpack_padding_u16, padding_u16);
         let unpack_padding = unpack_data.padding;
         assert_eq!(unpack_padding, padding);
         for (observation, unpack_observation) in
@@ -224,14 +496,305 @@ pub mod oracle_layout_test {
         {
             let block_timestamp = observation.block_timestamp;
             let tick_cumulative = observation.tick_cumulative;
+            let seconds_per_liquidity_cumulative_x64 =
+                observation.seconds_per_liquidity_cumulative_x64;
             let padding = observation.padding;
 
             let unpack_block_timestamp = unpack_observation.block_timestamp;
             let unpack_tick_cumulative = unpack_observation.tick_cumulative;
+            let unpack_seconds_per_liquidity_cumulative_x64 =
+                unpack_observation.seconds_per_liquidity_cumulative_x64;
             let unpack_padding = unpack_observation.padding;
             assert_eq!(block_timestamp, unpack_block_timestamp);
             assert_eq!(tick_cumulative, unpack_tick_cumulative);
+            assert_eq!(
+                seconds_per_liquidity_cumulative_x64,
+                unpack_seconds_per_liquidity_cumulative_x64
+            );
             assert_eq!(padding, unpack_padding);
         }
     }
+
+    #[test]
+    fn test_observation_extension_layout() {
+        let extension_num = 3;
+        let mut data = vec![0u8; ObservationState::space(OBSERVATION_NUM + extension_num)];
+        assert_eq!(
+            data.len(),
+            ObservationState::LEN + Observation::LEN * extension_num
+        );
+        data[..8].copy_from_slice(&ObservationState::DISCRIMINATOR);
+        let mut offset = ObservationState::LEN;
+        for i in 0..extension_num {
+            let block_timestamp: u32 = 1000 + i as u32;
+            let tick_cumulative: i64 = -10 * i as i64;
+            data[offset..offset + 4].copy_from_slice(&block_timestamp.to_le_bytes());
+            offset += 4;
+            data[offset..offset + 8].copy_from_slice(&tick_cumulative.to_le_bytes());
+            offset += 8 + 16 + 8 * 2;
+        }
+        assert_eq!(offset, data.len());
+
+        let key = Pubkey::new_unique();
+        let owner = crate::id();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let (_, observation_extension) =
+            AccountLoad::<ObservationState>::load_data_with_trailing_mut::<Observation>(
+                &account_info,
+            )
+            .unwrap();
+        assert_eq!(observation_extension.len(), extension_num);
+        for (i, observation) in observation_extension.iter().enumerate() {
+            let block_timestamp = observation.block_timestamp;
+            let tick_cumulative = observation.tick_cumulative;
+            assert_eq!(block_timestamp, 1000 + i as u32);
+            assert_eq!(tick_cumulative, -10 * i as i64);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod oracle_observe_test {
+    use super::*;
+
+    fn build_observation_state(updates: &[(u32, i32)]) -> ObservationState {
+        let mut observation_state = ObservationState::default();
+        for (block_timestamp, tick) in updates {
+            observation_state.update(&mut [], *block_timestamp, *tick, 0);
+        }
+        observation_state
+    }
+
+    #[test]
+    fn observe_uninitialized_test() {
+        let observation_state = ObservationState::default();
+        assert!(observation_state
+            .observe_single(&[], 1000, 0, 10, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn observe_extrapolate_from_newest_test() {
+        let observation_state = build_observation_state(&[(1000, 0), (1100, 10)]);
+        // 100 seconds at tick 10
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1100, 0, 10, 0)
+                .unwrap()
+                .0,
+            1000
+        );
+        // 50 more seconds at the current tick 20
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1150, 0, 20, 0)
+                .unwrap()
+                .0,
+            2000
+        );
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1150, 25, 20, 0)
+                .unwrap()
+                .0,
+            1500
+        );
+    }
+
+    #[test]
+    fn observe_interpolate_test() {
+        let observation_state = build_observation_state(&[(1000, 0), (1100, 10), (1200, -20)]);
+        // exact hit on an observation
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1200, 100, 0, 0)
+                .unwrap()
+                .0,
+            1000
+        );
+        // between the first and second observation
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1200, 150, 0, 0)
+                .unwrap()
+                .0,
+            500
+        );
+        // between the second and third observation
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1200, 50, 0, 0)
+                .unwrap()
+                .0,
+            0
+        );
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1200, 75, 0, 0)
+                .unwrap()
+                .0,
+            500
+        );
+        // older than the oldest observation
+        assert!(observation_state
+            .observe_single(&[], 1200, 201, 0, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn observe_wrapped_ring_test() {
+        let updates: Vec<(u32, i32)> = (0..OBSERVATION_NUM as u32 + 10)
+            .map(|i| (1000 + i * OBSERVATION_UPDATE_DURATION_DEFAULT, 1))
+            .collect();
+        let observation_state = build_observation_state(&updates);
+        let current_timestamp = updates.last().unwrap().0;
+        let oldest_timestamp = 1000 + 10 * OBSERVATION_UPDATE_DURATION_DEFAULT;
+        let window = current_timestamp - oldest_timestamp;
+        let (tick_cumulatives, _) = observation_state
+            .observe(&[], current_timestamp, &[0, window, window / 2 + 1], 1, 0)
+            .unwrap();
+        assert_eq!(tick_cumulatives[0] - tick_cumulatives[1], i64::from(window));
+        assert_eq!(
+            tick_cumulatives[0] - tick_cumulatives[2],
+            i64::from(window / 2 + 1)
+        );
+        assert!(observation_state
+            .observe_single(&[], current_timestamp, window + 1, 1, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn consult_test() {
+        let observation_state = build_observation_state(&[(1000, 0), (1100, -7), (1200, 3)]);
+        // ticks: -7 over (1000, 1100], 3 over (1100, 1200]
+        assert_eq!(observation_state.consult(&[], 1200, 100, 0).unwrap(), 3);
+        assert_eq!(observation_state.consult(&[], 1200, 200, 0).unwrap(), -2);
+        assert_eq!(observation_state.consult(&[], 1100, 100, 0).unwrap(), -7);
+        // rounds towards negative infinity
+        assert_eq!(observation_state.consult(&[], 1200, 150, 0).unwrap(), -1);
+        assert!(observation_state.consult(&[], 1200, 0, 0).is_err());
+    }
+
+    #[test]
+    fn observe_increased_cardinality_test() {
+        let mut observation_state = ObservationState::default();
+        let mut observation_extension = [Observation::default(); 50];
+        let mut block_timestamp = 1000;
+        // fill and wrap the default buffer before it is enlarged
+        for _ in 0..OBSERVATION_NUM + 10 {
+            observation_state.update(&mut [], block_timestamp, 1, 0);
+            block_timestamp += OBSERVATION_UPDATE_DURATION_DEFAULT;
+        }
+        assert_eq!({ observation_state.observation_index }, 9);
+
+        // the buffer grows only after the index reaches the end of the used observations
+        for _ in 0..OBSERVATION_NUM - 10 {
+            observation_state.update(&mut observation_extension, block_timestamp, 1, 0);
+            block_timestamp += OBSERVATION_UPDATE_DURATION_DEFAULT;
+        }
+        assert_eq!({ observation_state.observation_index }, 99);
+        assert_eq!(observation_state.cardinality(), OBSERVATION_NUM);
+        observation_state.update(&mut observation_extension, block_timestamp, 1, 0);
+        assert_eq!({ observation_state.observation_index }, 100);
+        assert_eq!(observation_state.cardinality(), OBSERVATION_NUM + 50);
+
+        // the whole window is still observable while the new observations are filled
+        let current_timestamp = block_timestamp;
+        let window = OBSERVATION_NUM as u32 * OBSERVATION_UPDATE_DURATION_DEFAULT;
+        let (tick_cumulatives, _) = observation_state
+            .observe(
+                &observation_extension,
+                current_timestamp,
+                &[0, window],
+                1,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tick_cumulatives[0] - tick_cumulatives[1], i64::from(window));
+        assert!(observation_state
+            .observe_single(&observation_extension, current_timestamp, window + 1, 1, 0)
+            .is_err());
+
+        // fill and wrap the enlarged buffer
+        for _ in 0..60 {
+            block_timestamp += OBSERVATION_UPDATE_DURATION_DEFAULT;
+            observation_state.update(&mut observation_extension, block_timestamp, 1, 0);
+        }
+        assert_eq!({ observation_state.observation_index }, 10);
+        let current_timestamp = block_timestamp;
+        let window = (OBSERVATION_NUM as u32 + 49) * OBSERVATION_UPDATE_DURATION_DEFAULT;
+        let (tick_cumulatives, _) = observation_state
+            .observe(
+                &observation_extension,
+                current_timestamp,
+                &[0, window],
+                1,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tick_cumulatives[0] - tick_cumulatives[1], i64::from(window));
+        assert!(observation_state
+            .observe_single(&observation_extension, current_timestamp, window + 1, 1, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn observe_seconds_per_liquidity_test() {
+        let mut observation_state = ObservationState::default();
+        observation_state.update(&mut [], 1000, 0, 0);
+        observation_state.update(&mut [], 1100, 10, 1000);
+        observation_state.update(&mut [], 1200, -20, 3000);
+        // between observations
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1300, 150, 0, 4000)
+                .unwrap()
+                .1,
+            2000
+        );
+        // exact hit on an observation
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1300, 200, 0, 4000)
+                .unwrap()
+                .1,
+            1000
+        );
+        // between the newest observation and the current accumulator
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1300, 50, 0, 4000)
+                .unwrap()
+                .1,
+            3500
+        );
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1300, 0, 0, 4000)
+                .unwrap()
+                .1,
+            4000
+        );
+        // the accumulator wraps around
+        let mut observation_state = ObservationState::default();
+        observation_state.update(&mut [], 1000, 0, u128::MAX - 99);
+        observation_state.update(&mut [], 1100, 0, 100);
+        assert_eq!(
+            observation_state
+                .observe_single(&[], 1100, 50, 0, 100)
+                .unwrap()
+                .1,
+            0
+        );
+    }
 }
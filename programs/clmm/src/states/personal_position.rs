@@ -42,10 +42,23 @@ pub struct PersonalPositionState {
     pub reward_infos: [PositionRewardInfo; REWARD_NUM],
     // account update recent epoch
     pub recent_epoch: u64,
+    /// Whether the position is a range order, which is filled once the price crosses its range
+    pub range_order: bool,
+    /// True if the range order sells token_0 and is filled when the price crosses tick_upper upward,
+    /// otherwise it sells token_1 and is filled when the price crosses tick_lower downward
+    pub range_order_zero_for_one: bool,
+    /// The range_order_fill_count of the fill tick when the order was placed
+    pub range_order_fill_count: u32,
+    pub padding_u16: u16,
+    /// The liquidity can not be removed before this unix timestamp, `PERMANENT_LOCK` locks it forever
+    pub locked_until: u64,
     // Unused bytes for future upgrades.
-    pub padding: [u64; 7],
+    pub padding: [u64; 5],
 }
 
+/// The `locked_until` of a position locked forever
+pub const PERMANENT_LOCK: u64 = u64::MAX;
+
 impl PersonalPositionState {
     pub const LEN: usize =
         8 + 1 + 32 + 32 + 4 + 4 + 16 + 16 + 16 + 8 + 8 + PositionRewardInfo::LEN * REWARD_NUM + 64;
@@ -83,10 +96,20 @@ impl PersonalPositionState {
         // update rewards, must update before update liquidity
         self.update_rewards(reward_growths_inside, false, recent_epoch)?;
         self.liquidity = liquidity;
-        self.padding = [0; 7];
+        self.range_order = false;
+        self.range_order_zero_for_one = false;
+        self.range_order_fill_count = 0;
+        self.padding_u16 = 0;
+        self.locked_until = 0;
+        self.padding = [0; 5];
         Ok(())
     }
 
+    /// Whether the liquidity of the position is locked at the given unix timestamp
+    pub fn is_locked(&self, timestamp: u64) -> bool {
+        self.locked_until > timestamp
+    }
+
     pub fn increase_liquidity(
         &mut self,
         liquidity_delta: u128,
@@ -128,7 +151,8 @@ impl PersonalPositionState {
             .ok_or(ErrorCode::CalculateOverflow)?;
         Ok(())
     }
-    fn update_fee_and_reward(
+    /// Accrues the fees and rewards earned since the last update without changing the liquidity
+    pub fn update_fee_and_reward(
         &mut self,
         fee_growth_inside_0_x64_latest: u128,
         fee_growth_inside_1_x64_latest: u128,
@@ -344,6 +368,77 @@ pub struct CollectPersonalFeeEvent {
     pub amount_1: u64,
 }
 
+/// Emitted when the fees of a position are reinvested into its liquidity
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CompoundPositionEvent {
+    /// The ID of the token for which fees were compounded
+    pub position_nft_mint: Pubkey,
+
+    /// The amount by which liquidity for the NFT position was increased
+    pub liquidity: u128,
+
+    /// The amount of token_0 fees added to the position
+    pub amount_0: u64,
+
+    /// The amount of token_1 fees added to the position
+    pub amount_1: u64,
+
+    /// The amount of token_0 fees that could not be added and was returned to the owner
+    pub dust_amount_0: u64,
+
+    /// The amount of token_1 fees that could not be added and was returned to the owner
+    pub dust_amount_1: u64,
+}
+
+/// Emitted when a position is placed as a range order
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PlaceRangeOrderEvent {
+    /// The ID of the token for which the range order was placed
+    pub position_nft_mint: Pubkey,
+
+    /// True if the order sells token_0, otherwise it sells token_1
+    pub zero_for_one: bool,
+
+    /// The tick the price has to cross to fill the order
+    pub fill_tick: i32,
+
+    /// The liquidity of the order
+    pub liquidity: u128,
+}
+
+/// Emitted when a range order is claimed, or cancelled if it was not filled
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct ClaimRangeOrderEvent {
+    /// The ID of the token for which the range order was claimed
+    pub position_nft_mint: Pubkey,
+
+    /// Whether the order was filled
+    pub filled: bool,
+
+    /// The liquidity of the order
+    pub liquidity: u128,
+
+    /// The amount of token_0 withdrawn from the order, fees included
+    pub amount_0: u64,
+
+    /// The amount of token_1 withdrawn from the order, fees included
+    pub amount_1: u64,
+}
+
+/// Emitted when a position is locked or its lock is extended
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct LockPositionEvent {
+    /// The ID of the token for which the position was locked
+    pub position_nft_mint: Pubkey,
+
+    /// The unix timestamp until which the liquidity is locked, `PERMANENT_LOCK` if forever
+    pub locked_until: u64,
+}
+
 /// Emitted when Reward are updated for a pool
 #[event]
 #[cfg_attr(feature = "client", derive(Debug))]
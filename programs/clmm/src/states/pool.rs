@@ -22,6 +22,18 @@ pub const POOL_TICK_ARRAY_BITMAP_SEED: &str = "pool_tick_array_bitmap_extension"
 // Number of rewards Token
 pub const REWARD_NUM: usize = 3;
 
+/// Swaps within this period after the last swap share the same volatility reference
+pub const VOLATILITY_FILTER_PERIOD: u32 = 30;
+/// The volatility reference is reset once no swap happens within this period
+pub const VOLATILITY_DECAY_PERIOD: u32 = 600;
+/// The share of the volatility accumulator carried over to the next reference, in basis points
+pub const VOLATILITY_REDUCTION_FACTOR: u32 = 5000;
+pub const VOLATILITY_REDUCTION_FACTOR_DENOMINATOR: u32 = 10000;
+
+/// The max time in seconds a pool can be created ahead of its open_time, so the pool address of
+/// a pair can not be taken by a pool that never opens
+pub const MAX_OPEN_DELAY: u64 = 30 * 24 * 60 * 60;
+
 #[cfg(feature = "paramset")]
 pub mod reward_period_limit {
     pub const MIN_REWARD_PERIOD: u64 = 1 * 60 * 60;
@@ -49,6 +61,23 @@ pub enum PoolStatusBitFlag {
     Disable,
 }
 
+/// The bits of `PoolState::fee_override_flags`, a set bit means the pool overrides the amm config rate
+#[derive(Clone, Copy)]
+pub enum FeeOverrideBitIndex {
+    TradeFee,
+    ProtocolFee,
+    FundFee,
+}
+
+/// The values of `PoolState::twap_guard_mode`
+#[derive(Clone, Copy, PartialEq)]
+pub enum TwapGuardMode {
+    /// The swaps moving the price beyond the bound fail
+    Reject,
+    /// The swaps stop at the bound, like at a price limit
+    Cap,
+}
+
 /// The pool state
 ///
 /// PDA of `[POOL_SEED, config, token_mint_0, token_mint_1]`
@@ -113,8 +142,12 @@ pub struct PoolState {
     /// bit3, 1: disable collect reward, 0: normal
     /// bit4, 1: disable swap, 0: normal
     pub status: u8,
+    /// The status of the amm config of the pool, same bits as `status`. It is kept apart from
+    /// the pool status and refreshed from the amm config by swaps, flash loans and the config
+    /// status instructions
+    pub amm_config_status: u8,
     /// Leave blank for future use
-    pub padding: [u8; 7],
+    pub padding: [u8; 6],
 
     pub reward_infos: [RewardInfo; REWARD_NUM],
 
@@ -132,13 +165,66 @@ pub struct PoolState {
     pub fund_fees_token_1: u64,
 
     // The timestamp allowed for swap in the pool.
-    // Note: The open_time is disabled for now.
     pub open_time: u64,
     // account recent update epoch
     pub recent_epoch: u64,
 
+    /// The seconds per unit of in range liquidity as a Q64.64 number for the entire life of the pool,
+    /// only has relative meaning like the fee growth
+    pub seconds_per_liquidity_global_x64: u128,
+    /// The timestamp when seconds_per_liquidity_global_x64 was last updated
+    pub seconds_per_liquidity_update_time: u64,
+
+    /// The volatility accumulated by recent swaps in ticks, drives the dynamic trade fee
+    pub volatility_accumulator: u32,
+    /// The decayed volatility carried over from previous swaps
+    pub volatility_reference: u32,
+    /// The tick which the volatility accumulator measures the movement from
+    pub tick_reference: i32,
+    /// The timestamp of the last swap which updated the volatility accumulator
+    pub volatility_update_time: u32,
+
+    /// The amounts of token_0 and token_1 lent by a flash loan which is not repaid yet
+    pub flash_loan_amount_0: u64,
+    pub flash_loan_amount_1: u64,
+
+    /// Per pool overrides of the amm config fee rates, denominated in hundredths of a bip (10^-6),
+    /// only applied when the matching bit of fee_override_flags is set
+    pub trade_fee_rate_override: u32,
+    pub protocol_fee_rate_override: u32,
+    pub fund_fee_rate_override: u32,
+    /// bit0: trade fee rate, bit1: protocol fee rate, bit2: fund fee rate
+    pub fee_override_flags: u32,
+
+    /// The config the pool was migrated to, the default key while the pool uses the config
+    /// it was created with. `amm_config` is kept because it seeds the pool address
+    pub effective_amm_config: Pubkey,
+
+    /// The trade fee rate charged right after open_time, decaying linearly to the end rate
+    /// over launch_fee_duration seconds, the schedule is disabled when the duration is zero
+    pub launch_fee_start_rate: u32,
+    pub launch_fee_end_rate: u32,
+    pub launch_fee_duration: u32,
+
+    /// The seconds of the time-weighted average tick the swaps are compared to,
+    /// the twap guard is disabled when it is zero
+    pub twap_guard_window: u32,
+    /// The ticks the price can move away from the time-weighted average tick by a swap
+    pub twap_guard_max_tick_deviation: u32,
+    /// What happens to a swap moving the price too far, see `TwapGuardMode`
+    pub twap_guard_mode: u8,
+    pub padding5: [u8; 3],
+
+    /// The in range liquidity when the open flash loan was borrowed, it earns the flash loan fees
+    pub flash_loan_liquidity: u128,
+
+    /// The tick before the first swap at twap_guard_anchor_time, the twap guard bound is
+    /// centred on it while the observations have no history yet
+    pub twap_guard_anchor_tick: i32,
+    pub twap_guard_anchor_time: u32,
+
     // Unused bytes for future upgrades.
-    pub padding1: [u64; 24],
+    pub padding1: [u64; 5],
     pub padding2: [u64; 32],
 }
 
@@ -220,7 +306,9 @@ impl PoolState {
         self.swap_in_amount_token_1 = 0;
         self.swap_out_amount_token_0 = 0;
         self.status = 0;
-        self.padding = [0; 7];
+        // a pool created in a paused config starts paused
+        self.amm_config_status = amm_config.status;
+        self.padding = [0; 6];
         self.tick_array_bitmap = [0; 16];
         self.total_fees_token_0 = 0;
         self.total_fees_claimed_token_0 = 0;
@@ -230,7 +318,30 @@ impl PoolState {
         self.fund_fees_token_1 = 0;
         self.open_time = open_time;
         self.recent_epoch = get_recent_epoch()?;
-        self.padding1 = [0; 24];
+        self.seconds_per_liquidity_global_x64 = 0;
+        self.seconds_per_liquidity_update_time = 0;
+        self.volatility_accumulator = 0;
+        self.volatility_reference = 0;
+        self.tick_reference = tick;
+        self.volatility_update_time = 0;
+        self.flash_loan_amount_0 = 0;
+        self.flash_loan_amount_1 = 0;
+        self.trade_fee_rate_override = 0;
+        self.protocol_fee_rate_override = 0;
+        self.fund_fee_rate_override = 0;
+        self.fee_override_flags = 0;
+        self.effective_amm_config = Pubkey::default();
+        self.launch_fee_start_rate = 0;
+        self.launch_fee_end_rate = 0;
+        self.launch_fee_duration = 0;
+        self.twap_guard_window = 0;
+        self.twap_guard_max_tick_deviation = 0;
+        self.twap_guard_mode = 0;
+        self.padding5 = [0; 3];
+        self.flash_loan_liquidity = 0;
+        self.twap_guard_anchor_tick = 0;
+        self.twap_guard_anchor_time = 0;
+        self.padding1 = [0; 5];
         self.padding2 = [0; 32];
         self.observation_key = observation_state_key;
 
@@ -247,6 +358,7 @@ impl PoolState {
         token_vault: &Pubkey,
         authority: &Pubkey,
         operation_state: &OperationState,
+        global_config: &GlobalConfig,
     ) -> Result<()> {
         let reward_infos = self.reward_infos;
         let lowest_index = match reward_infos.iter().position(|r| !r.initialized()) {
@@ -311,7 +423,7 @@ impl PoolState {
         } else if lowest_index == REWARD_NUM - 1 {
             // the last reward token must be controled by the admin
             require!(
-                *authority == crate::admin::ID
+                global_config.is_admin(*authority)
                     || operation_state.validate_operation_owner(*authority),
                 ErrorCode::NotApproved
             );
@@ -335,12 +447,398 @@ impl PoolState {
         Ok(())
     }
 
+    /// Returns the seconds per liquidity accumulator as of `curr_timestamp` without updating the pool
+    pub fn seconds_per_liquidity_cumulative_x64(&self, curr_timestamp: u64) -> u128 {
+        if self.seconds_per_liquidity_update_time == 0
+            || curr_timestamp <= self.seconds_per_liquidity_update_time
+        {
+            return self.seconds_per_liquidity_global_x64;
+        }
+        let time_delta = curr_timestamp - self.seconds_per_liquidity_update_time;
+        self.seconds_per_liquidity_global_x64.wrapping_add(
+            (u128::from(time_delta) << fixed_point_64::RESOLUTION) / self.liquidity.max(1),
+        )
+    }
+
+    /// Accumulates the seconds per liquidity up to `curr_timestamp`, must be called before the liquidity changes
+    pub fn update_seconds_per_liquidity(&mut self, curr_timestamp: u64) {
+        self.seconds_per_liquidity_global_x64 =
+            self.seconds_per_liquidity_cumulative_x64(curr_timestamp);
+        if curr_timestamp > self.seconds_per_liquidity_update_time {
+            self.seconds_per_liquidity_update_time = curr_timestamp;
+        }
+    }
+
+    /// Refreshes the volatility reference before a swap, the reference decays when swaps are sparse
+    pub fn update_volatility_reference(&mut self, block_timestamp: u32) {
+        let elapsed = block_timestamp.saturating_sub(self.volatility_update_time);
+        if elapsed < VOLATILITY_FILTER_PERIOD {
+            return;
+        }
+        self.tick_reference = self.tick_current;
+        self.volatility_reference = if elapsed < VOLATILITY_DECAY_PERIOD {
+            (u64::from(self.volatility_accumulator) * u64::from(VOLATILITY_REDUCTION_FACTOR)
+                / u64::from(VOLATILITY_REDUCTION_FACTOR_DENOMINATOR)) as u32
+        } else {
+            0
+        };
+    }
+
+    /// Returns the volatility accumulator if the price moved to `tick`
+    pub fn get_volatility_accumulator(&self, tick: i32) -> u32 {
+        self.volatility_reference
+            .saturating_add(tick.abs_diff(self.tick_reference))
+    }
+
+    /// Records the volatility after a swap which ended at `tick`
+    pub fn update_volatility_accumulator(&mut self, tick: i32, block_timestamp: u32) {
+        self.volatility_accumulator = self.get_volatility_accumulator(tick);
+        self.volatility_update_time = block_timestamp;
+    }
+
+    /// Records an open flash loan and the in range liquidity which earns its fees
+    pub fn open_flash_loan(&mut self, amount_0: u64, amount_1: u64) -> Result<()> {
+        self.require_no_flash_loan()?;
+        self.flash_loan_amount_0 = amount_0;
+        self.flash_loan_amount_1 = amount_1;
+        self.flash_loan_liquidity = self.liquidity;
+        Ok(())
+    }
+
+    /// The vaults of the pool are short of the loan while a flash loan is open, every instruction
+    /// of the pool but `flash_loan_repay` must fail until it is repaid
+    pub fn require_no_flash_loan(&self) -> Result<()> {
+        require!(
+            self.flash_loan_amount_0 == 0 && self.flash_loan_amount_1 == 0,
+            ErrorCode::FlashLoanInProgress
+        );
+        Ok(())
+    }
+
+    /// Closes the open flash loan and credits its fees, returns the borrowed amounts and the fees
+    /// the borrower owes on top of them
+    pub fn close_flash_loan(&mut self, amm_config: &AmmConfig) -> Result<(u64, u64, u64, u64)> {
+        let amount_0 = self.flash_loan_amount_0;
+        let amount_1 = self.flash_loan_amount_1;
+        require!(
+            amount_0 > 0 || amount_1 > 0,
+            ErrorCode::FlashLoanNotBorrowed
+        );
+
+        let fee_0 = amm_config.get_flash_loan_fee(amount_0);
+        let fee_1 = amm_config.get_flash_loan_fee(amount_1);
+        self.add_flash_loan_fees(fee_0, fee_1);
+        self.flash_loan_amount_0 = 0;
+        self.flash_loan_amount_1 = 0;
+        self.flash_loan_liquidity = 0;
+        Ok((amount_0, amount_1, fee_0, fee_1))
+    }
+
+    /// Credits the flash loan fees to the in range liquidity of the borrow like trade fees.
+    /// When no liquidity was in range at the borrow nobody can earn them, so they are added
+    /// to the protocol fees instead
+    pub fn add_flash_loan_fees(&mut self, fee_0: u64, fee_1: u64) {
+        let liquidity = self.flash_loan_liquidity;
+        if liquidity == 0 {
+            self.protocol_fees_token_0 = self.protocol_fees_token_0.checked_add(fee_0).unwrap();
+            self.protocol_fees_token_1 = self.protocol_fees_token_1.checked_add(fee_1).unwrap();
+            return;
+        }
+        self.fee_growth_global_0_x64 = self
+            .fee_growth_global_0_x64
+            .checked_add(
+                U128::from(fee_0)
+                    .mul_div_floor(U128::from(fixed_point_64::Q64), U128::from(liquidity))
+                    .unwrap()
+                    .as_u128(),
+            )
+            .unwrap();
+        self.fee_growth_global_1_x64 = self
+            .fee_growth_global_1_x64
+            .checked_add(
+                U128::from(fee_1)
+                    .mul_div_floor(U128::from(fixed_point_64::Q64), U128::from(liquidity))
+                    .unwrap()
+                    .as_u128(),
+            )
+            .unwrap();
+        self.total_fees_token_0 = self.total_fees_token_0.checked_add(fee_0).unwrap();
+        self.total_fees_token_1 = self.total_fees_token_1.checked_add(fee_1).unwrap();
+    }
+
+    /// The key of the config whose fee rates and owners apply to the pool
+    pub fn get_amm_config(&self) -> Pubkey {
+        if self.effective_amm_config == Pubkey::default() {
+            self.amm_config
+        } else {
+            self.effective_amm_config
+        }
+    }
+
+    /// Checks the current sqrt price of the pool is within the given bounds, if any,
+    /// so a liquidity change can not be sandwiched into a skewed ratio
+    pub fn check_sqrt_price_range(
+        &self,
+        sqrt_price_min_x64: Option<u128>,
+        sqrt_price_max_x64: Option<u128>,
+    ) -> Result<()> {
+        let sqrt_price_x64 = self.sqrt_price_x64;
+        if let Some(sqrt_price_min_x64) = sqrt_price_min_x64 {
+            require_gte!(
+                sqrt_price_x64,
+                sqrt_price_min_x64,
+                ErrorCode::SqrtPriceOutOfRange
+            );
+        }
+        if let Some(sqrt_price_max_x64) = sqrt_price_max_x64 {
+            require_gte!(
+                sqrt_price_max_x64,
+                sqrt_price_x64,
+                ErrorCode::SqrtPriceOutOfRange
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the open_time of a pool created at `block_timestamp`, a pool opened in the past
+    /// opens now, so the launch fee schedule starts at creation
+    pub fn get_open_time(open_time: u64, block_timestamp: u64) -> Result<u64> {
+        require_gte!(
+            block_timestamp + MAX_OPEN_DELAY,
+            open_time,
+            ErrorCode::InvalidOpenTime
+        );
+        Ok(open_time.max(block_timestamp))
+    }
+
+    /// Sets the launch fee schedule, bounded by the launch fee caps of the amm config
+    pub fn set_launch_fee(
+        &mut self,
+        start_rate: u32,
+        end_rate: u32,
+        duration: u32,
+        amm_config: &AmmConfig,
+    ) -> Result<()> {
+        require!(
+            start_rate <= amm_config.max_launch_fee_start_rate
+                && duration <= amm_config.max_launch_fee_duration
+                && start_rate < FEE_RATE_DENOMINATOR_VALUE
+                && end_rate <= start_rate,
+            ErrorCode::InvalidLaunchFee
+        );
+        self.launch_fee_start_rate = start_rate;
+        self.launch_fee_end_rate = end_rate;
+        self.launch_fee_duration = duration;
+        Ok(())
+    }
+
+    /// Returns the launch fee rate at the given timestamp, or None before open_time
+    /// and once the launch fee schedule is over
+    pub fn get_launch_fee_rate(&self, block_timestamp: u64) -> Option<u32> {
+        let elapsed = block_timestamp.checked_sub(self.open_time)?;
+        let duration = u64::from(self.launch_fee_duration);
+        if elapsed >= duration {
+            return None;
+        }
+        let decay =
+            u64::from(self.launch_fee_start_rate - self.launch_fee_end_rate) * elapsed / duration;
+        Some(self.launch_fee_start_rate - decay as u32)
+    }
+
+    pub fn set_twap_guard(&mut self, window: u32, max_tick_deviation: u32, mode: u8) -> Result<()> {
+        require!(
+            window == 0 || (max_tick_deviation > 0 && mode <= TwapGuardMode::Cap as u8),
+            ErrorCode::InvalidTwapGuard
+        );
+        self.twap_guard_window = window;
+        self.twap_guard_max_tick_deviation = max_tick_deviation;
+        self.twap_guard_mode = mode;
+        Ok(())
+    }
+
+    pub fn get_twap_guard_mode(&self) -> TwapGuardMode {
+        if self.twap_guard_mode == TwapGuardMode::Cap as u8 {
+            TwapGuardMode::Cap
+        } else {
+            TwapGuardMode::Reject
+        }
+    }
+
+    /// Returns the sqrt price a swap in the given direction can not move the price beyond,
+    /// or None when the twap guard is disabled. The bound is `twap_guard_max_tick_deviation`
+    /// ticks away from the average tick over `twap_guard_window` seconds, the window is
+    /// shortened to the observed history. Without any history the bound is centred on the tick
+    /// before the first swap of the block timestamp, so swaps in the same second can not walk it.
+    pub fn get_twap_guard_sqrt_price(
+        &self,
+        observation_state: &ObservationState,
+        observation_extension: &[Observation],
+        block_timestamp: u32,
+        zero_for_one: bool,
+    ) -> Result<Option<u128>> {
+        if self.twap_guard_window == 0 {
+            return Ok(None);
+        }
+        let history = observation_state
+            .oldest_block_timestamp(observation_extension)
+            .map_or(0, |oldest| block_timestamp.saturating_sub(oldest));
+        let seconds_ago = history.min(self.twap_guard_window);
+        let average_tick = if seconds_ago == 0 {
+            self.get_twap_guard_anchor_tick(block_timestamp)
+        } else {
+            observation_state.consult(
+                observation_extension,
+                block_timestamp,
+                seconds_ago,
+                self.tick_current,
+            )?
+        };
+        let deviation = i32::try_from(self.twap_guard_max_tick_deviation).unwrap_or(i32::MAX);
+        let bound_tick = if zero_for_one {
+            average_tick
+                .saturating_sub(deviation)
+                .max(tick_math::MIN_TICK)
+        } else {
+            average_tick
+                .saturating_add(deviation)
+                .min(tick_math::MAX_TICK)
+        };
+        Ok(Some(tick_math::get_sqrt_price_at_tick(bound_tick)?))
+    }
+
+    /// The tick before the first swap at `block_timestamp`, which is the current tick
+    /// until a swap records the anchor
+    pub fn get_twap_guard_anchor_tick(&self, block_timestamp: u32) -> i32 {
+        if self.twap_guard_anchor_time == block_timestamp {
+            self.twap_guard_anchor_tick
+        } else {
+            self.tick_current
+        }
+    }
+
+    /// Records the tick before the first swap at `block_timestamp`, must be called before
+    /// the swap moves the tick
+    pub fn update_twap_guard_anchor(&mut self, block_timestamp: u32) {
+        if self.twap_guard_anchor_time != block_timestamp {
+            self.twap_guard_anchor_tick = self.tick_current;
+            self.twap_guard_anchor_time = block_timestamp;
+        }
+    }
+
+    /// Applies the twap guard bound to the price limit of a swap. In the cap mode the swap stops
+    /// at the bound like at a price limit, and fails if the price is already beyond it
+    pub fn apply_twap_guard_to_price_limit(
+        &self,
+        twap_guard_sqrt_price_x64: Option<u128>,
+        sqrt_price_limit_x64: u128,
+        zero_for_one: bool,
+    ) -> Result<u128> {
+        match twap_guard_sqrt_price_x64 {
+            Some(bound) if self.get_twap_guard_mode() == TwapGuardMode::Cap => {
+                require!(
+                    if zero_for_one {
+                        bound < self.sqrt_price_x64
+                    } else {
+                        bound > self.sqrt_price_x64
+                    },
+                    ErrorCode::TwapDeviationExceeded
+                );
+                Ok(if zero_for_one {
+                    sqrt_price_limit_x64.max(bound)
+                } else {
+                    sqrt_price_limit_x64.min(bound)
+                })
+            }
+            _ => Ok(sqrt_price_limit_x64),
+        }
+    }
+
+    /// Checks the price after a swap did not move beyond the twap guard bound
+    pub fn check_twap_guard(
+        twap_guard_sqrt_price_x64: Option<u128>,
+        sqrt_price_x64_after: u128,
+        zero_for_one: bool,
+    ) -> Result<()> {
+        if let Some(bound) = twap_guard_sqrt_price_x64 {
+            require!(
+                if zero_for_one {
+                    sqrt_price_x64_after >= bound
+                } else {
+                    sqrt_price_x64_after <= bound
+                },
+                ErrorCode::TwapDeviationExceeded
+            );
+        }
+        Ok(())
+    }
+
+    pub fn get_fee_override(&self, bit: FeeOverrideBitIndex) -> Option<u32> {
+        if self.fee_override_flags & (1 << bit as u32) == 0 {
+            return None;
+        }
+        Some(match bit {
+            FeeOverrideBitIndex::TradeFee => self.trade_fee_rate_override,
+            FeeOverrideBitIndex::ProtocolFee => self.protocol_fee_rate_override,
+            FeeOverrideBitIndex::FundFee => self.fund_fee_rate_override,
+        })
+    }
+
+    /// Sets the override of a fee rate, `None` falls back to the amm config rate
+    pub fn set_fee_override(&mut self, bit: FeeOverrideBitIndex, fee_rate: Option<u32>) {
+        let rate = fee_rate.unwrap_or(0);
+        match bit {
+            FeeOverrideBitIndex::TradeFee => self.trade_fee_rate_override = rate,
+            FeeOverrideBitIndex::ProtocolFee => self.protocol_fee_rate_override = rate,
+            FeeOverrideBitIndex::FundFee => self.fund_fee_rate_override = rate,
+        }
+        if fee_rate.is_some() {
+            self.fee_override_flags |= 1 << bit as u32;
+        } else {
+            self.fee_override_flags &= !(1 << bit as u32);
+        }
+    }
+
+    /// Returns the amm config with the fee rates overridden by the pool,
+    /// an overridden trade fee is static so it also turns the dynamic fee mode off.
+    /// The amm config can change after the overrides are set, so the overridden protocol
+    /// and fund rates are clamped to keep protocol + fund + max referral within the denominator
+    pub fn apply_fee_overrides(&self, amm_config: &AmmConfig) -> AmmConfig {
+        let mut config = amm_config.clone();
+        if let Some(trade_fee_rate) = self.get_fee_override(FeeOverrideBitIndex::TradeFee) {
+            config.trade_fee_rate = trade_fee_rate.min(FEE_RATE_DENOMINATOR_VALUE - 1);
+            config.dynamic_fee_min_rate = 0;
+            config.dynamic_fee_max_rate = 0;
+        }
+        let protocol_fee_override = self.get_fee_override(FeeOverrideBitIndex::ProtocolFee);
+        let fund_fee_override = self.get_fee_override(FeeOverrideBitIndex::FundFee);
+        if let Some(fund_fee_rate) = fund_fee_override {
+            config.fund_fee_rate = fund_fee_rate;
+        }
+        if let Some(protocol_fee_rate) = protocol_fee_override {
+            config.protocol_fee_rate = protocol_fee_rate.min(
+                FEE_RATE_DENOMINATOR_VALUE
+                    .saturating_sub(config.fund_fee_rate)
+                    .saturating_sub(config.max_referral_fee_rate),
+            );
+        }
+        if let Some(fund_fee_rate) = fund_fee_override {
+            config.fund_fee_rate = fund_fee_rate.min(
+                FEE_RATE_DENOMINATOR_VALUE
+                    .saturating_sub(config.protocol_fee_rate)
+                    .saturating_sub(config.max_referral_fee_rate),
+            );
+        }
+        config
+    }
+
     // Calculates the next global reward growth variables based on the given timestamp.
     // The provided timestamp must be greater than or equal to the last updated timestamp.
     pub fn update_reward_infos(&mut self, curr_timestamp: u64) -> Result<[RewardInfo; REWARD_NUM]> {
         #[cfg(feature = "enable-log")]
         msg!("current block timestamp:{}", curr_timestamp);
 
+        self.update_seconds_per_liquidity(curr_timestamp);
+
         let mut next_reward_infos = self.reward_infos;
 
         for i in 0..REWARD_NUM {
@@ -558,6 +1056,16 @@ impl PoolState {
         self.status = status
     }
 
+    /// Caches the status of the amm config of the pool, the pool status is left unchanged
+    pub fn set_amm_config_status(&mut self, amm_config_status: u8) {
+        self.amm_config_status = amm_config_status
+    }
+
+    /// The operations disabled by either the pool or its amm config
+    pub fn get_effective_status(&self) -> u8 {
+        self.status.bitor(self.amm_config_status)
+    }
+
     pub fn set_status_by_bit(&mut self, bit: PoolStatusBitIndex, flag: PoolStatusBitFlag) {
         let s = u8::from(1) << (bit as u8);
         if flag == PoolStatusBitFlag::Disable {
@@ -568,10 +1076,24 @@ impl PoolState {
         }
     }
 
-    /// Get status by bit, if it is `noraml` status, return true
+    /// Get status by bit, if it is `noraml` status, return true.
+    /// A bit disabled by the pool or its cached amm config status is disabled,
+    /// every bit is disabled while a flash loan is open
     pub fn get_status_by_bit(&self, bit: PoolStatusBitIndex) -> bool {
+        self.get_status_by_bit_with_config(bit, self.amm_config_status)
+    }
+
+    /// Like `get_status_by_bit`, with the status of the amm config read from the config itself
+    pub fn get_status_by_bit_with_config(
+        &self,
+        bit: PoolStatusBitIndex,
+        amm_config_status: u8,
+    ) -> bool {
+        if self.require_no_flash_loan().is_err() {
+            return false;
+        }
         let status = u8::from(1) << (bit as u8);
-        self.status.bitand(status) == 0
+        self.status.bitor(amm_config_status).bitand(status) == 0
     }
 
     pub fn is_overflow_default_tickarray_bitmap(&self, tick_indexs: Vec<i32>) -> bool {
@@ -771,6 +1293,9 @@ pub struct SwapEvent {
 
     /// The log base 1.0001 of price of the pool after the swap
     pub tick: i32,
+
+    /// The part of the input token fee paid to the referrer
+    pub referral_fee: u64,
 }
 
 /// Emitted pool liquidity change when increase and decrease liquidity
@@ -796,6 +1321,77 @@ pub struct LiquidityChangeEvent {
     pub liquidity_after: u128,
 }
 
+/// Emitted when the fee overrides of a pool are changed, `None` means the amm config rate applies
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolConfigChangeEvent {
+    pub pool_state: Pubkey,
+    pub trade_fee_rate: Option<u32>,
+    pub protocol_fee_rate: Option<u32>,
+    pub fund_fee_rate: Option<u32>,
+}
+
+/// Emitted when the twap guard of a pool is changed
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct TwapGuardChangeEvent {
+    pub pool_state: Pubkey,
+    pub window: u32,
+    pub max_tick_deviation: u32,
+    pub mode: u8,
+}
+
+/// Emitted when the status of a pool is changed
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolStatusChangeEvent {
+    pub pool_state: Pubkey,
+    pub status: u8,
+}
+
+/// Emitted when a pool is migrated to another amm config
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct MigratePoolConfigEvent {
+    pub pool_state: Pubkey,
+    pub old_amm_config: Pubkey,
+    pub new_amm_config: Pubkey,
+    /// The protocol fees settled to the owner of the old config
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    /// The fund fees settled to the fund owner of the old config
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+}
+
+/// Emitted when a flash loan is repaid
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct FlashLoanEvent {
+    /// The pool the flash loan was borrowed from
+    pub pool_state: Pubkey,
+
+    /// The amount of token_0 borrowed
+    pub amount_0: u64,
+
+    /// The amount of token_1 borrowed
+    pub amount_1: u64,
+
+    /// The fee of token_0 credited to the liquidity providers,
+    /// or to the protocol fees when no liquidity was in range at the borrow
+    pub fee_0: u64,
+
+    /// The fee of token_1 credited to the liquidity providers,
+    /// or to the protocol fees when no liquidity was in range at the borrow
+    pub fee_1: u64,
+
+    /// The transfer fee of token_0 paid on the repayment
+    pub transfer_fee_0: u64,
+
+    /// The transfer fee of token_1 paid on the repayment
+    pub transfer_fee_1: u64,
+}
+
 // /// Emitted when price move in a swap step
 // #[event]
 // #[cfg_attr(feature = "client", derive(Debug))]
@@ -1022,6 +1618,47 @@ pub mod pool_test {
                 false
             );
         }
+
+        #[test]
+        fn amm_config_status_test() {
+            let mut pool_state = PoolState::default();
+            let swap = 1 << (PoolStatusBitIndex::Swap as u8);
+            let collect_fee = 1 << (PoolStatusBitIndex::CollectFee as u8);
+            pool_state.set_status(collect_fee);
+
+            // paused on the config
+            pool_state.set_amm_config_status(swap);
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee));
+            assert_eq!(pool_state.status, collect_fee);
+            assert_eq!(pool_state.get_effective_status(), swap | collect_fee);
+
+            // resumed on the config
+            pool_state.set_amm_config_status(0);
+            assert!(pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::CollectFee));
+
+            // the status read from the config takes precedence over the cached one
+            assert!(!pool_state.get_status_by_bit_with_config(PoolStatusBitIndex::Swap, swap));
+            pool_state.set_amm_config_status(swap);
+            assert!(pool_state.get_status_by_bit_with_config(PoolStatusBitIndex::Swap, 0));
+        }
+
+        #[test]
+        fn amm_config_resume_keeps_pool_pause_test() {
+            let mut pool_state = PoolState::default();
+            let swap = 1 << (PoolStatusBitIndex::Swap as u8);
+            // the pool is paused by the admin
+            pool_state.set_status_by_bit(PoolStatusBitIndex::Swap, PoolStatusBitFlag::Disable);
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            // then its config is paused
+            pool_state.set_amm_config_status(swap);
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            // resuming the config leaves the pool paused
+            pool_state.set_amm_config_status(0);
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            assert_eq!(pool_state.status, swap);
+        }
     }
 
     mod update_reward_infos_test {
@@ -1048,6 +1685,7 @@ pub mod pool_test {
                     &Pubkey::default(),
                     &Pubkey::default(),
                     &operation_state,
+                    &GlobalConfig::default(),
                 )
                 .unwrap();
 
@@ -1082,6 +1720,475 @@ pub mod pool_test {
         }
     }
 
+    mod seconds_per_liquidity_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn update_seconds_per_liquidity_test() {
+            let pool_state = &mut PoolState::default();
+            // the first update only records the timestamp
+            pool_state.update_seconds_per_liquidity(1000);
+            assert_eq!(identity(pool_state.seconds_per_liquidity_global_x64), 0);
+            assert_eq!(identity(pool_state.seconds_per_liquidity_update_time), 1000);
+
+            // zero liquidity is treated as one unit
+            pool_state.update_seconds_per_liquidity(1010);
+            assert_eq!(
+                identity(pool_state.seconds_per_liquidity_global_x64),
+                10 * fixed_point_64::Q64
+            );
+
+            pool_state.liquidity = 4;
+            assert_eq!(
+                pool_state.seconds_per_liquidity_cumulative_x64(1020),
+                10 * fixed_point_64::Q64 + 10 * fixed_point_64::Q64 / 4
+            );
+            // the view does not change the pool
+            assert_eq!(identity(pool_state.seconds_per_liquidity_update_time), 1010);
+
+            pool_state.update_seconds_per_liquidity(1020);
+            assert_eq!(
+                identity(pool_state.seconds_per_liquidity_global_x64),
+                10 * fixed_point_64::Q64 + 10 * fixed_point_64::Q64 / 4
+            );
+            // an earlier timestamp changes nothing
+            pool_state.update_seconds_per_liquidity(1015);
+            assert_eq!(identity(pool_state.seconds_per_liquidity_update_time), 1020);
+        }
+    }
+
+    mod volatility_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn update_volatility_accumulator_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.tick_current = 100;
+            pool_state.update_volatility_reference(1000);
+            assert_eq!(identity(pool_state.tick_reference), 100);
+            assert_eq!(identity(pool_state.volatility_reference), 0);
+
+            // the price moved 50 ticks down in the first swap
+            assert_eq!(pool_state.get_volatility_accumulator(50), 50);
+            pool_state.update_volatility_accumulator(50, 1000);
+            pool_state.tick_current = 50;
+
+            // a swap within the filter period keeps measuring from the same reference
+            pool_state.update_volatility_reference(1000 + VOLATILITY_FILTER_PERIOD - 1);
+            assert_eq!(identity(pool_state.tick_reference), 100);
+            assert_eq!(pool_state.get_volatility_accumulator(120), 20);
+            pool_state.update_volatility_accumulator(0, 1010);
+            assert_eq!(identity(pool_state.volatility_accumulator), 100);
+            pool_state.tick_current = 0;
+
+            // after the filter period the accumulator decays into the reference
+            pool_state.update_volatility_reference(1010 + VOLATILITY_FILTER_PERIOD);
+            assert_eq!(identity(pool_state.tick_reference), 0);
+            assert_eq!(identity(pool_state.volatility_reference), 50);
+            assert_eq!(pool_state.get_volatility_accumulator(-10), 60);
+            pool_state.update_volatility_accumulator(-10, 1040);
+            pool_state.tick_current = -10;
+
+            // after the decay period the reference is reset
+            pool_state.update_volatility_reference(1040 + VOLATILITY_DECAY_PERIOD);
+            assert_eq!(identity(pool_state.tick_reference), -10);
+            assert_eq!(identity(pool_state.volatility_reference), 0);
+            assert_eq!(pool_state.get_volatility_accumulator(-10), 0);
+        }
+    }
+
+    mod flash_loan_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn add_flash_loan_fees_test() {
+            let pool_state = &mut PoolState::default();
+            // no in range liquidity, the fees go to the protocol
+            pool_state.add_flash_loan_fees(10, 20);
+            assert_eq!(identity(pool_state.protocol_fees_token_0), 10);
+            assert_eq!(identity(pool_state.protocol_fees_token_1), 20);
+            assert_eq!(identity(pool_state.fee_growth_global_0_x64), 0);
+            assert_eq!(identity(pool_state.total_fees_token_0), 0);
+
+            // the fees go to the liquidity in range when the loan was borrowed
+            pool_state.liquidity = 4 * fixed_point_64::Q64;
+            pool_state.open_flash_loan(1000, 0).unwrap();
+            pool_state.liquidity = 8 * fixed_point_64::Q64;
+            pool_state.add_flash_loan_fees(100, 0);
+            assert_eq!(identity(pool_state.fee_growth_global_0_x64), 25);
+            assert_eq!(identity(pool_state.fee_growth_global_1_x64), 0);
+            assert_eq!(identity(pool_state.total_fees_token_0), 100);
+            assert_eq!(identity(pool_state.total_fees_token_1), 0);
+            assert_eq!(identity(pool_state.protocol_fees_token_0), 10);
+        }
+
+        #[test]
+        fn open_flash_loan_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.open_flash_loan(0, 100).unwrap();
+            // a second loan and every other instruction of the pool fail until it is repaid
+            assert_eq!(
+                pool_state.open_flash_loan(100, 0).unwrap_err(),
+                ErrorCode::FlashLoanInProgress.into()
+            );
+            assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+            assert!(
+                !pool_state.get_status_by_bit(PoolStatusBitIndex::OpenPositionOrIncreaseLiquidity)
+            );
+
+            pool_state.flash_loan_amount_1 = 0;
+            pool_state.require_no_flash_loan().unwrap();
+            assert!(pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+        }
+
+        #[test]
+        fn close_flash_loan_test() {
+            let amm_config = &AmmConfig {
+                flash_loan_fee_rate: 1000,
+                ..Default::default()
+            };
+            let pool_state = &mut PoolState::default();
+            assert_eq!(
+                pool_state.close_flash_loan(amm_config).unwrap_err(),
+                ErrorCode::FlashLoanNotBorrowed.into()
+            );
+
+            pool_state.liquidity = 2 * fixed_point_64::Q64;
+            pool_state.open_flash_loan(1_000_000, 10_000).unwrap();
+            assert_eq!(
+                pool_state.close_flash_loan(amm_config).unwrap(),
+                (1_000_000, 10_000, 1000, 10)
+            );
+            assert_eq!(identity(pool_state.fee_growth_global_0_x64), 500);
+            assert_eq!(identity(pool_state.fee_growth_global_1_x64), 5);
+            assert_eq!(identity(pool_state.protocol_fees_token_0), 0);
+            // the loan is closed and the pool works again
+            assert_eq!(identity(pool_state.flash_loan_liquidity), 0);
+            pool_state.require_no_flash_loan().unwrap();
+            assert_eq!(
+                pool_state.close_flash_loan(amm_config).unwrap_err(),
+                ErrorCode::FlashLoanNotBorrowed.into()
+            );
+
+            // the fees go to the protocol when no liquidity was in range at the borrow
+            pool_state.liquidity = 0;
+            pool_state.open_flash_loan(1_000_000, 0).unwrap();
+            pool_state.liquidity = 2 * fixed_point_64::Q64;
+            assert_eq!(
+                pool_state.close_flash_loan(amm_config).unwrap(),
+                (1_000_000, 0, 1000, 0)
+            );
+            assert_eq!(identity(pool_state.fee_growth_global_0_x64), 500);
+            assert_eq!(identity(pool_state.protocol_fees_token_0), 1000);
+        }
+    }
+
+    mod sqrt_price_range_test {
+        use super::*;
+
+        #[test]
+        fn check_sqrt_price_range_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.sqrt_price_x64 = fixed_point_64::Q64;
+            pool_state.check_sqrt_price_range(None, None).unwrap();
+            // the bounds are inclusive
+            pool_state
+                .check_sqrt_price_range(Some(fixed_point_64::Q64), Some(fixed_point_64::Q64))
+                .unwrap();
+            assert_eq!(
+                pool_state
+                    .check_sqrt_price_range(Some(fixed_point_64::Q64 + 1), None)
+                    .unwrap_err(),
+                ErrorCode::SqrtPriceOutOfRange.into()
+            );
+            assert_eq!(
+                pool_state
+                    .check_sqrt_price_range(None, Some(fixed_point_64::Q64 - 1))
+                    .unwrap_err(),
+                ErrorCode::SqrtPriceOutOfRange.into()
+            );
+        }
+    }
+
+    mod migrate_pool_config_test {
+        use super::*;
+        use std::convert::identity;
+
+        #[test]
+        fn get_amm_config_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.amm_config = Pubkey::new_unique();
+            assert_eq!(pool_state.get_amm_config(), identity(pool_state.amm_config));
+
+            let new_amm_config = Pubkey::new_unique();
+            pool_state.effective_amm_config = new_amm_config;
+            assert_eq!(pool_state.get_amm_config(), new_amm_config);
+        }
+    }
+
+    mod twap_guard_test {
+        use super::*;
+
+        #[test]
+        fn set_twap_guard_test() {
+            let pool_state = &mut PoolState::default();
+            assert!(pool_state
+                .set_twap_guard(600, 500, TwapGuardMode::Cap as u8)
+                .is_ok());
+            assert!(pool_state.get_twap_guard_mode() == TwapGuardMode::Cap);
+            // disabling ignores the other parameters
+            assert!(pool_state.set_twap_guard(0, 0, 0).is_ok());
+            assert!(pool_state.set_twap_guard(600, 0, 0).is_err());
+            assert!(pool_state.set_twap_guard(600, 500, 2).is_err());
+        }
+
+        #[test]
+        fn get_twap_guard_sqrt_price_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.tick_current = 500;
+            let mut observation_state = ObservationState::default();
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, true)
+                    .unwrap(),
+                None
+            );
+
+            pool_state.set_twap_guard(1000, 50, 0).unwrap();
+            // without any history the bound is around the tick before the first swap of the second
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, true)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(450).unwrap())
+            );
+            pool_state.update_twap_guard_anchor(1100);
+            pool_state.tick_current = 300;
+            pool_state.update_twap_guard_anchor(1100);
+            observation_state.update(&mut [], 1100, 500, 0);
+            // a later swap in the same second is still bounded around the anchor, not the moved tick
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, true)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(450).unwrap())
+            );
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, false)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(550).unwrap())
+            );
+            observation_state = ObservationState::default();
+            pool_state.tick_current = 500;
+
+            // tick 100 from 1000 to 1100, the window is shortened to these 100 seconds
+            observation_state.update(&mut [], 1000, 0, 0);
+            observation_state.update(&mut [], 1100, 100, 0);
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, true)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(50).unwrap())
+            );
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1100, false)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(150).unwrap())
+            );
+            // the current tick counts from the newest observation on
+            assert_eq!(
+                pool_state
+                    .get_twap_guard_sqrt_price(&observation_state, &[], 1200, false)
+                    .unwrap(),
+                Some(tick_math::get_sqrt_price_at_tick(350).unwrap())
+            );
+        }
+    }
+
+    mod launch_fee_test {
+        use super::*;
+
+        #[test]
+        fn get_open_time_test() {
+            // a pool opened in the past opens at creation
+            assert_eq!(PoolState::get_open_time(0, 1000).unwrap(), 1000);
+            assert_eq!(PoolState::get_open_time(1500, 1000).unwrap(), 1500);
+            assert_eq!(
+                PoolState::get_open_time(1000 + MAX_OPEN_DELAY, 1000).unwrap(),
+                1000 + MAX_OPEN_DELAY
+            );
+            assert_eq!(
+                PoolState::get_open_time(1001 + MAX_OPEN_DELAY, 1000).unwrap_err(),
+                ErrorCode::InvalidOpenTime.into()
+            );
+            assert!(PoolState::get_open_time(u64::MAX, 1000).is_err());
+        }
+
+        #[test]
+        fn set_launch_fee_test() {
+            let pool_state = &mut PoolState::default();
+            let amm_config = &mut AmmConfig {
+                max_launch_fee_start_rate: 50_000,
+                max_launch_fee_duration: 600,
+                ..Default::default()
+            };
+            assert!(pool_state
+                .set_launch_fee(50_000, 3_000, 600, amm_config)
+                .is_ok());
+            assert!(pool_state.set_launch_fee(0, 0, 0, amm_config).is_ok());
+            // the fee can not increase over time
+            assert!(pool_state
+                .set_launch_fee(3_000, 50_000, 600, amm_config)
+                .is_err());
+            assert!(pool_state
+                .set_launch_fee(FEE_RATE_DENOMINATOR_VALUE, 0, 600, amm_config)
+                .is_err());
+        }
+
+        #[test]
+        fn set_launch_fee_above_config_caps_test() {
+            let pool_state = &mut PoolState::default();
+            let amm_config = &mut AmmConfig::default();
+            // the launch fee is disabled until the config caps are set
+            assert!(pool_state.set_launch_fee(0, 0, 0, amm_config).is_ok());
+            assert_eq!(
+                pool_state
+                    .set_launch_fee(999_999, 999_999, u32::MAX, amm_config)
+                    .unwrap_err(),
+                ErrorCode::InvalidLaunchFee.into()
+            );
+
+            amm_config.max_launch_fee_start_rate = 50_000;
+            amm_config.max_launch_fee_duration = 600;
+            // start rate above the cap
+            assert_eq!(
+                pool_state
+                    .set_launch_fee(50_001, 3_000, 600, amm_config)
+                    .unwrap_err(),
+                ErrorCode::InvalidLaunchFee.into()
+            );
+            // duration above the cap
+            assert_eq!(
+                pool_state
+                    .set_launch_fee(50_000, 3_000, 601, amm_config)
+                    .unwrap_err(),
+                ErrorCode::InvalidLaunchFee.into()
+            );
+        }
+
+        #[test]
+        fn get_launch_fee_rate_test() {
+            let pool_state = &mut PoolState::default();
+            pool_state.open_time = 1000;
+            // disabled without a duration
+            assert_eq!(pool_state.get_launch_fee_rate(1000), None);
+
+            let amm_config = &AmmConfig {
+                max_launch_fee_start_rate: 50_000,
+                max_launch_fee_duration: 400,
+                ..Default::default()
+            };
+            pool_state
+                .set_launch_fee(50_000, 10_000, 400, amm_config)
+                .unwrap();
+            assert_eq!(pool_state.get_launch_fee_rate(999), None);
+            assert_eq!(pool_state.get_launch_fee_rate(1000), Some(50_000));
+            assert_eq!(pool_state.get_launch_fee_rate(1100), Some(40_000));
+            assert_eq!(pool_state.get_launch_fee_rate(1399), Some(10_100));
+            assert_eq!(pool_state.get_launch_fee_rate(1400), None);
+            assert_eq!(pool_state.get_launch_fee_rate(u64::MAX), None);
+        }
+    }
+
+    mod fee_override_test {
+        use super::*;
+
+        #[test]
+        fn apply_fee_overrides_test() {
+            let pool_state = &mut PoolState::default();
+            let amm_config = AmmConfig {
+                trade_fee_rate: 2500,
+                protocol_fee_rate: 120_000,
+                fund_fee_rate: 40_000,
+                dynamic_fee_min_rate: 500,
+                dynamic_fee_max_rate: 10_000,
+                ..Default::default()
+            };
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.trade_fee_rate, 2500);
+            assert!(config.is_dynamic_fee_enabled());
+
+            pool_state.set_fee_override(FeeOverrideBitIndex::TradeFee, Some(100));
+            pool_state.set_fee_override(FeeOverrideBitIndex::FundFee, Some(0));
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.trade_fee_rate, 100);
+            assert!(!config.is_dynamic_fee_enabled());
+            assert_eq!(config.get_trade_fee_rate(1000), 100);
+            assert_eq!(config.protocol_fee_rate, 120_000);
+            assert_eq!(config.fund_fee_rate, 0);
+
+            pool_state.set_fee_override(FeeOverrideBitIndex::TradeFee, None);
+            assert_eq!(
+                pool_state.get_fee_override(FeeOverrideBitIndex::TradeFee),
+                None
+            );
+            assert_eq!(
+                pool_state.get_fee_override(FeeOverrideBitIndex::FundFee),
+                Some(0)
+            );
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.trade_fee_rate, 2500);
+            assert!(config.is_dynamic_fee_enabled());
+        }
+
+        #[test]
+        fn apply_fee_overrides_after_config_change_test() {
+            let pool_state = &mut PoolState::default();
+            let mut amm_config = AmmConfig {
+                trade_fee_rate: 2500,
+                protocol_fee_rate: 120_000,
+                fund_fee_rate: 40_000,
+                ..Default::default()
+            };
+            pool_state.set_fee_override(FeeOverrideBitIndex::ProtocolFee, Some(900_000));
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.protocol_fee_rate, 900_000);
+
+            // the config raises the fund and referral rates after the override is set
+            amm_config.fund_fee_rate = 100_000;
+            amm_config.max_referral_fee_rate = 50_000;
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.protocol_fee_rate, 850_000);
+            assert_eq!(config.fund_fee_rate, 100_000);
+
+            // both rates overridden, the protocol rate takes what is left
+            pool_state.set_fee_override(FeeOverrideBitIndex::FundFee, Some(200_000));
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.protocol_fee_rate, 750_000);
+            assert_eq!(config.fund_fee_rate, 200_000);
+
+            amm_config.max_referral_fee_rate = 300_000;
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.protocol_fee_rate, 500_000);
+            assert_eq!(config.fund_fee_rate, 200_000);
+            assert!(
+                config.protocol_fee_rate + config.fund_fee_rate + config.max_referral_fee_rate
+                    <= FEE_RATE_DENOMINATOR_VALUE
+            );
+
+            pool_state.set_fee_override(FeeOverrideBitIndex::ProtocolFee, Some(1_000_000));
+            pool_state.set_fee_override(FeeOverrideBitIndex::FundFee, Some(1_000_000));
+            let config = pool_state.apply_fee_overrides(&amm_config);
+            assert_eq!(config.protocol_fee_rate, 0);
+            assert_eq!(config.fund_fee_rate, 700_000);
+        }
+    }
+
     mod use_tickarray_bitmap_extension_test {
 
         use std::ops::Deref;
@@ -1616,7 +2723,8 @@ pub mod pool_test {
             let swap_in_amount_token_1: u128 = 0x11223344556677008899aabbccddeeff;
             let swap_out_amount_token_0: u128 = 0x11223344556677880099aabbccddeeff;
             let status: u8 = 0x1b;
-            let padding: [u8; 7] = [0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18];
+            let amm_config_status: u8 = 0x12;
+            let padding: [u8; 6] = [0x13, 0x14, 0x15, 0x16, 0x17, 0x18];
             // RewardInfo
             let reward_state: u8 = 0x1c;
             let open_time: u64 = 0x123456789abc0def;
@@ -1687,10 +2795,33 @@ pub mod pool_test {
             let fund_fees_token_1: u64 = 0x1230456789abcdef;
             let pool_open_time: u64 = 0x1203456789abcdef;
             let recent_epoch: u64 = 0x1023456789abcdef;
-            let mut padding1: [u64; 24] = [0u64; 24];
-            let mut padding1_data = [0u8; 8 * 24];
+            let seconds_per_liquidity_global_x64: u128 = 0x11223344556677889900aabbccddee0f;
+            let seconds_per_liquidity_update_time: u64 = 0x1123456789abcde0;
+            let volatility_accumulator: u32 = 0x12345678;
+            let volatility_reference: u32 = 0x23456781;
+            let tick_reference: i32 = -0x3456781;
+            let volatility_update_time: u32 = 0x45678123;
+            let flash_loan_amount_0: u64 = 0x123456789abcde0f;
+            let flash_loan_amount_1: u64 = 0x123456789abcd0ef;
+            let trade_fee_rate_override: u32 = 0x12345687;
+            let protocol_fee_rate_override: u32 = 0x12345876;
+            let fund_fee_rate_override: u32 = 0x12348765;
+            let fee_override_flags: u32 = 0x12387654;
+            let effective_amm_config = Pubkey::new_unique();
+            let launch_fee_start_rate: u32 = 0x12876543;
+            let launch_fee_end_rate: u32 = 0x18765432;
+            let launch_fee_duration: u32 = 0x87654321;
+            let twap_guard_window: u32 = 0x76543218;
+            let twap_guard_max_tick_deviation: u32 = 0x65432187;
+            let twap_guard_mode: u8 = 0x1c;
+            let padding5: [u8; 3] = [0x21, 0x22, 0x23];
+            let flash_loan_liquidity: u128 = 0x11223344556677889900aabbccddeeff;
+            let twap_guard_anchor_tick: i32 = -0x1234567;
+            let twap_guard_anchor_time: u32 = 0x54321876;
+            let mut padding1: [u64; 5] = [0u64; 5];
+            let mut padding1_data = [0u8; 8 * 5];
             let mut offset = 0;
-            for i in 0..24 {
+            for i in 0..5 {
                 padding1[i] = u64::MAX - i as u64;
                 padding1_data[offset..offset + 8].copy_from_slice(&padding1[i].to_le_bytes());
                 offset += 8;
@@ -1758,8 +2889,10 @@ pub mod pool_test {
             offset += 16;
             pool_data[offset..offset + 1].copy_from_slice(&status.to_le_bytes());
             offset += 1;
-            pool_data[offset..offset + 7].copy_from_slice(&padding);
-            offset += 7;
+            pool_data[offset..offset + 1].copy_from_slice(&amm_config_status.to_le_bytes());
+            offset += 1;
+            pool_data[offset..offset + 6].copy_from_slice(&padding);
+            offset += 6;
             pool_data[offset..offset + RewardInfo::LEN * REWARD_NUM]
                 .copy_from_slice(&reward_info_datas);
             offset += RewardInfo::LEN * REWARD_NUM;
@@ -1783,8 +2916,58 @@ pub mod pool_test {
             offset += 8;
             pool_data[offset..offset + 8].copy_from_slice(&recent_epoch.to_le_bytes());
             offset += 8;
-            pool_data[offset..offset + 8 * 24].copy_from_slice(&padding1_data);
-            offset += 8 * 24;
+            pool_data[offset..offset + 16]
+                .copy_from_slice(&seconds_per_liquidity_global_x64.to_le_bytes());
+            offset += 16;
+            pool_data[offset..offset + 8]
+                .copy_from_slice(&seconds_per_liquidity_update_time.to_le_bytes());
+            offset += 8;
+            pool_data[offset..offset + 4].copy_from_slice(&volatility_accumulator.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&volatility_reference.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&tick_reference.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&volatility_update_time.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 8].copy_from_slice(&flash_loan_amount_0.to_le_bytes());
+            offset += 8;
+            pool_data[offset..offset + 8].copy_from_slice(&flash_loan_amount_1.to_le_bytes());
+            offset += 8;
+            pool_data[offset..offset + 4].copy_from_slice(&trade_fee_rate_override.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4]
+                .copy_from_slice(&protocol_fee_rate_override.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&fund_fee_rate_override.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&fee_override_flags.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 32].copy_from_slice(&effective_amm_config.to_bytes());
+            offset += 32;
+            pool_data[offset..offset + 4].copy_from_slice(&launch_fee_start_rate.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&launch_fee_end_rate.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&launch_fee_duration.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&twap_guard_window.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4]
+                .copy_from_slice(&twap_guard_max_tick_deviation.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 1].copy_from_slice(&twap_guard_mode.to_le_bytes());
+            offset += 1;
+            pool_data[offset..offset + 3].copy_from_slice(&padding5);
+            offset += 3;
+            pool_data[offset..offset + 16].copy_from_slice(&flash_loan_liquidity.to_le_bytes());
+            offset += 16;
+            pool_data[offset..offset + 4].copy_from_slice(&twap_guard_anchor_tick.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 4].copy_from_slice(&twap_guard_anchor_time.to_le_bytes());
+            offset += 4;
+            pool_data[offset..offset + 8 * 5].copy_from_slice(&padding1_data);
+            offset += 8 * 5;
             pool_data[offset..offset + 8 * 32].copy_from_slice(&padding2_data);
             offset += 8 * 32;
 
@@ -1847,6 +3030,8 @@ pub mod pool_test {
             assert_eq!(unpack_swap_out_amount_token_0, swap_out_amount_token_0);
             let unpack_status = unpack_data.status;
             assert_eq!(unpack_status, status);
+            let unpack_amm_config_status = unpack_data.amm_config_status;
+            assert_eq!(unpack_amm_config_status, amm_config_status);
             let unpack_padding = unpack_data.padding;
             assert_eq!(unpack_padding, padding);
 
@@ -1897,6 +3082,66 @@ pub mod pool_test {
             assert_eq!(unpack_open_time, pool_open_time);
             let unpack_recent_epoch = unpack_data.recent_epoch;
             assert_eq!(unpack_recent_epoch, recent_epoch);
+            let unpack_seconds_per_liquidity_global_x64 =
+                unpack_data.seconds_per_liquidity_global_x64;
+            assert_eq!(
+                unpack_seconds_per_liquidity_global_x64,
+                seconds_per_liquidity_global_x64
+            );
+            let unpack_seconds_per_liquidity_update_time =
+                unpack_data.seconds_per_liquidity_update_time;
+            assert_eq!(
+                unpack_seconds_per_liquidity_update_time,
+                seconds_per_liquidity_update_time
+            );
+            let unpack_volatility_accumulator = unpack_data.volatility_accumulator;
+            assert_eq!(unpack_volatility_accumulator, volatility_accumulator);
+            let unpack_volatility_reference = unpack_data.volatility_reference;
+            assert_eq!(unpack_volatility_reference, volatility_reference);
+            let unpack_tick_reference = unpack_data.tick_reference;
+            assert_eq!(unpack_tick_reference, tick_reference);
+            let unpack_volatility_update_time = unpack_data.volatility_update_time;
+            assert_eq!(unpack_volatility_update_time, volatility_update_time);
+            let unpack_flash_loan_amount_0 = unpack_data.flash_loan_amount_0;
+            assert_eq!(unpack_flash_loan_amount_0, flash_loan_amount_0);
+            let unpack_flash_loan_amount_1 = unpack_data.flash_loan_amount_1;
+            assert_eq!(unpack_flash_loan_amount_1, flash_loan_amount_1);
+            let unpack_trade_fee_rate_override = unpack_data.trade_fee_rate_override;
+            assert_eq!(unpack_trade_fee_rate_override, trade_fee_rate_override);
+            let unpack_protocol_fee_rate_override = unpack_data.protocol_fee_rate_override;
+            assert_eq!(
+                unpack_protocol_fee_rate_override,
+                protocol_fee_rate_override
+            );
+            let unpack_fund_fee_rate_override = unpack_data.fund_fee_rate_override;
+            assert_eq!(unpack_fund_fee_rate_override, fund_fee_rate_override);
+            let unpack_fee_override_flags = unpack_data.fee_override_flags;
+            assert_eq!(unpack_fee_override_flags, fee_override_flags);
+            let unpack_effective_amm_config = unpack_data.effective_amm_config;
+            assert_eq!(unpack_effective_amm_config, effective_amm_config);
+            let unpack_launch_fee_start_rate = unpack_data.launch_fee_start_rate;
+            assert_eq!(unpack_launch_fee_start_rate, launch_fee_start_rate);
+            let unpack_launch_fee_end_rate = unpack_data.launch_fee_end_rate;
+            assert_eq!(unpack_launch_fee_end_rate, launch_fee_end_rate);
+            let unpack_launch_fee_duration = unpack_data.launch_fee_duration;
+            assert_eq!(unpack_launch_fee_duration, launch_fee_duration);
+            let unpack_twap_guard_window = unpack_data.twap_guard_window;
+            assert_eq!(unpack_twap_guard_window, twap_guard_window);
+            let unpack_twap_guard_max_tick_deviation = unpack_data.twap_guard_max_tick_deviation;
+            assert_eq!(
+                unpack_twap_guard_max_tick_deviation,
+                twap_guard_max_tick_deviation
+            );
+            let unpack_twap_guard_mode = unpack_data.twap_guard_mode;
+            assert_eq!(unpack_twap_guard_mode, twap_guard_mode);
+            let unpack_padding5 = unpack_data.padding5;
+            assert_eq!(unpack_padding5, padding5);
+            let unpack_flash_loan_liquidity = unpack_data.flash_loan_liquidity;
+            assert_eq!(unpack_flash_loan_liquidity, flash_loan_liquidity);
+            let unpack_twap_guard_anchor_tick = unpack_data.twap_guard_anchor_tick;
+            assert_eq!(unpack_twap_guard_anchor_tick, twap_guard_anchor_tick);
+            let unpack_twap_guard_anchor_time = unpack_data.twap_guard_anchor_time;
+            assert_eq!(unpack_twap_guard_anchor_time, twap_guard_anchor_time);
             let unpack_padding1 = unpack_data.padding1;
             assert_eq!(unpack_padding1, padding1);
             let unpack_padding2 = unpack_data.padding2;
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use spl_token_2022::extension::ExtensionType;
 
 pub const SUPPORT_MINT_SEED: &str = "support_mint";
 
@@ -10,15 +11,60 @@ pub struct SupportMintAssociated {
     pub bump: u8,
     /// Address of the supported token22 mint
     pub mint: Pubkey,
-    pub padding: [u64; 8],
+    /// The token22 extensions tolerated for the mint, bit n is the extension type n.
+    /// Zero for the accounts created before the policy, they tolerate every extension
+    pub allowed_extensions: u64,
+    /// The pool vaults of the mint are created with the ImmutableOwner extension,
+    /// required by the issuers whose mints freeze the accounts without it
+    pub vault_immutable_owner: bool,
+    pub padding1: [u8; 7],
+    pub padding: [u64; 6],
 }
 
 impl SupportMintAssociated {
     pub const LEN: usize = 8 + 1 + 32 + 64;
 
-    pub fn initialize<'info>(&mut self, bump: u8, mint: Pubkey) -> Result<()> {
+    pub fn initialize<'info>(
+        &mut self,
+        bump: u8,
+        mint: Pubkey,
+        allowed_extensions: u64,
+        vault_immutable_owner: bool,
+    ) -> Result<()> {
         self.bump = bump;
         self.mint = mint;
+        self.allowed_extensions = allowed_extensions;
+        self.vault_immutable_owner = vault_immutable_owner;
         Ok(())
     }
+
+    pub fn is_extension_allowed(&self, extension: ExtensionType) -> bool {
+        self.allowed_extensions == 0
+            || self.allowed_extensions & Self::extension_bit(extension) != 0
+    }
+
+    /// The bit of the extension in `allowed_extensions`, zero for the types which do not fit
+    pub fn extension_bit(extension: ExtensionType) -> u64 {
+        1u64.checked_shl(u16::from(extension).into()).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod support_mint_associated_test {
+    use super::*;
+
+    #[test]
+    fn is_extension_allowed_test() {
+        let mut support_mint_associated = SupportMintAssociated::default();
+        // the accounts created before the policy tolerate every extension
+        assert!(support_mint_associated.is_extension_allowed(ExtensionType::PermanentDelegate));
+
+        support_mint_associated.allowed_extensions =
+            SupportMintAssociated::extension_bit(ExtensionType::PermanentDelegate)
+                | SupportMintAssociated::extension_bit(ExtensionType::Pausable);
+        assert!(support_mint_associated.is_extension_allowed(ExtensionType::PermanentDelegate));
+        assert!(support_mint_associated.is_extension_allowed(ExtensionType::Pausable));
+        assert!(!support_mint_associated.is_extension_allowed(ExtensionType::DefaultAccountState));
+        assert!(!support_mint_associated.is_extension_allowed(ExtensionType::MintCloseAuthority));
+    }
 }
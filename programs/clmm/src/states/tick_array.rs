@@ -121,6 +121,11 @@ impl TickArrayState {
         Ok(())
     }
 
+    pub fn get_tick_state(&self, tick_index: i32, tick_spacing: u16) -> Result<&TickState> {
+        let offset_in_array = self.get_tick_offset_in_array(tick_index, tick_spacing)?;
+        Ok(&self.ticks[offset_in_array])
+    }
+
     pub fn get_tick_state_mut(
         &mut self,
         tick_index: i32,
@@ -142,6 +147,45 @@ impl TickArrayState {
         Ok(())
     }
 
+    /// Fills the range orders waiting on a crossed tick. Their liquidity leaves the active liquidity
+    /// through the tick as usual, and is also removed from the liquidity_net of the tick and of the other
+    /// bound of the orders, so it is not swapped back when the price returns.
+    ///
+    /// Every crossing is counted, the first one after an order is placed fills it. Until the next one the
+    /// outside growths of both bounds are left untouched, so the growths inside the range stay at their
+    /// value of the fill and the order can be settled against them.
+    pub fn fill_range_orders(
+        &mut self,
+        tick_state: &mut TickState,
+        zero_for_one: bool,
+        tick_spacing: u16,
+    ) -> Result<()> {
+        tick_state.range_order_fill_count = tick_state.range_order_fill_count.wrapping_add(1);
+        if tick_state.range_order_liquidity == 0 {
+            return Ok(());
+        }
+        let liquidity = i128::try_from(tick_state.range_order_liquidity).unwrap();
+        // orders selling token_1 are filled through their lower tick when the price moves down,
+        // orders selling token_0 are filled through their upper tick when the price moves up
+        let (liquidity_delta, other_tick) = if zero_for_one {
+            (-liquidity, tick_state.tick + i32::from(tick_spacing))
+        } else {
+            (liquidity, tick_state.tick - i32::from(tick_spacing))
+        };
+        tick_state.liquidity_net = tick_state
+            .liquidity_net
+            .checked_add(liquidity_delta)
+            .unwrap();
+        let other_tick_state = self.get_tick_state_mut(other_tick, tick_spacing)?;
+        other_tick_state.liquidity_net = other_tick_state
+            .liquidity_net
+            .checked_sub(liquidity_delta)
+            .unwrap();
+
+        tick_state.range_order_liquidity = 0;
+        Ok(())
+    }
+
     /// Get tick's offset in current tick array, tick must be include in tick array， otherwise throw an error
     fn get_tick_offset_in_array(self, tick_index: i32, tick_spacing: u16) -> Result<usize> {
         let start_tick_index = TickArrayState::get_array_start_index(tick_index, tick_spacing);
@@ -281,8 +325,15 @@ pub struct TickState {
 
     // Reward growth per unit of liquidity like fee, array of Q64.64
     pub reward_growths_outside_x64: [u128; REWARD_NUM],
+    /// Seconds per unit of liquidity on the _other_ side of this tick (relative to the current tick) as a Q64.64,
+    /// only has relative meaning like the fee growth outside
+    pub seconds_per_liquidity_outside_x64: u128,
+    /// The liquidity of the range orders that are filled when the price crosses this tick
+    pub range_order_liquidity: u128,
+    /// The number of times this tick has been crossed, the range orders waiting on it are filled by the next crossing
+    pub range_order_fill_count: u32,
     // Unused bytes for future upgrades.
-    pub padding: [u32; 13],
+    pub padding: [u32; 4],
 }
 
 impl TickState {
@@ -306,6 +357,7 @@ impl TickState {
         liquidity_delta: i128,
         fee_growth_global_0_x64: u128,
         fee_growth_global_1_x64: u128,
+        seconds_per_liquidity_global_x64: u128,
         upper: bool,
         reward_infos: &[RewardInfo; REWARD_NUM],
     ) -> Result<bool> {
@@ -322,6 +374,7 @@ impl TickState {
                 self.fee_growth_outside_0_x64 = fee_growth_global_0_x64;
                 self.fee_growth_outside_1_x64 = fee_growth_global_1_x64;
                 self.reward_growths_outside_x64 = RewardInfo::get_reward_growths(reward_infos);
+                self.seconds_per_liquidity_outside_x64 = seconds_per_liquidity_global_x64;
             }
         }
 
@@ -344,6 +397,7 @@ impl TickState {
         &mut self,
         fee_growth_global_0_x64: u128,
         fee_growth_global_1_x64: u128,
+        seconds_per_liquidity_global_x64: u128,
         reward_infos: &[RewardInfo; REWARD_NUM],
     ) -> i128 {
         self.fee_growth_outside_0_x64 = fee_growth_global_0_x64
@@ -352,6 +406,8 @@ impl TickState {
         self.fee_growth_outside_1_x64 = fee_growth_global_1_x64
             .checked_sub(self.fee_growth_outside_1_x64)
             .unwrap();
+        self.seconds_per_liquidity_outside_x64 =
+            seconds_per_liquidity_global_x64.wrapping_sub(self.seconds_per_liquidity_outside_x64);
 
         for i in 0..REWARD_NUM {
             if !reward_infos[i].initialized() {
@@ -373,6 +429,7 @@ impl TickState {
         self.fee_growth_outside_0_x64 = 0;
         self.fee_growth_outside_1_x64 = 0;
         self.reward_growths_outside_x64 = [0; REWARD_NUM];
+        self.seconds_per_liquidity_outside_x64 = 0;
     }
 
     pub fn is_initialized(self) -> bool {
@@ -488,6 +545,28 @@ pub fn get_reward_growths_inside(
     reward_growths_inside
 }
 
+// Calculates the seconds per liquidity inside of tick_lower and tick_upper based on their positions relative to tick_current.
+pub fn get_seconds_per_liquidity_inside(
+    tick_lower: &TickState,
+    tick_upper: &TickState,
+    tick_current: i32,
+    seconds_per_liquidity_global_x64: u128,
+) -> u128 {
+    let seconds_per_liquidity_below_x64 = if tick_current >= tick_lower.tick {
+        tick_lower.seconds_per_liquidity_outside_x64
+    } else {
+        seconds_per_liquidity_global_x64.wrapping_sub(tick_lower.seconds_per_liquidity_outside_x64)
+    };
+    let seconds_per_liquidity_above_x64 = if tick_current < tick_upper.tick {
+        tick_upper.seconds_per_liquidity_outside_x64
+    } else {
+        seconds_per_liquidity_global_x64.wrapping_sub(tick_upper.seconds_per_liquidity_outside_x64)
+    };
+    seconds_per_liquidity_global_x64
+        .wrapping_sub(seconds_per_liquidity_below_x64)
+        .wrapping_sub(seconds_per_liquidity_above_x64)
+}
+
 pub fn check_tick_array_start_index(
     tick_array_start_index: i32,
     tick_index: i32,
@@ -706,6 +785,56 @@ pub mod tick_array_test {
             assert_eq!(-15, tick);
         }
 
+        #[test]
+        fn fill_range_orders_test() {
+            let tick_spacing = 10;
+            // an order selling token_0 on [10, 20] and an order selling token_1 on [30, 40]
+            let mut sell_0_upper = *build_tick(20, 1000, -1000).borrow();
+            sell_0_upper.range_order_liquidity = 1000;
+            let mut sell_1_lower = *build_tick(30, 500, 500).borrow();
+            sell_1_lower.range_order_liquidity = 500;
+            let tick_array_ref = build_tick_array_with_tick_states(
+                Pubkey::default(),
+                0,
+                tick_spacing,
+                vec![
+                    *build_tick(10, 1000, 1000).borrow(),
+                    sell_0_upper,
+                    sell_1_lower,
+                    *build_tick(40, 500, -500).borrow(),
+                ],
+            );
+            let mut tick_array = tick_array_ref.borrow_mut();
+
+            // price moves up through tick 20
+            tick_array
+                .fill_range_orders(&mut sell_0_upper, false, tick_spacing)
+                .unwrap();
+            assert_eq!(identity(sell_0_upper.liquidity_net), 0);
+            assert_eq!(identity(sell_0_upper.liquidity_gross), 1000);
+            assert_eq!(identity(sell_0_upper.range_order_liquidity), 0);
+            assert_eq!(identity(sell_0_upper.range_order_fill_count), 1);
+            let lower = tick_array.get_tick_state(10, tick_spacing).unwrap();
+            assert_eq!(identity(lower.liquidity_net), 0);
+            assert_eq!(identity(lower.liquidity_gross), 1000);
+
+            // price moves down through tick 30
+            tick_array
+                .fill_range_orders(&mut sell_1_lower, true, tick_spacing)
+                .unwrap();
+            assert_eq!(identity(sell_1_lower.liquidity_net), 0);
+            assert_eq!(identity(sell_1_lower.range_order_fill_count), 1);
+            let upper = tick_array.get_tick_state(40, tick_spacing).unwrap();
+            assert_eq!(identity(upper.liquidity_net), 0);
+
+            // crossing again without waiting orders only counts the crossing
+            tick_array
+                .fill_range_orders(&mut sell_1_lower, false, tick_spacing)
+                .unwrap();
+            assert_eq!(identity(sell_1_lower.liquidity_net), 0);
+            assert_eq!(identity(sell_1_lower.range_order_fill_count), 2);
+        }
+
         #[test]
         fn next_initialized_tick_when_tick_is_positive() {
             // init tick_index [0,30,105]
@@ -822,12 +951,14 @@ pub mod tick_array_test {
                 tick_lower.cross(
                     fee_growth_global_0_x64,
                     fee_growth_global_1_x64,
+                    0,
                     &[RewardInfo::default(); 3],
                 );
             } else {
                 tick_upper.cross(
                     fee_growth_global_0_x64,
                     fee_growth_global_1_x64,
+                    0,
                     &[RewardInfo::default(); 3],
                 );
             }
@@ -1159,9 +1290,9 @@ pub mod tick_array_test {
 
             reward_growth_global_x64 = reward_growth_global_x64 + reward_growth_global_delta;
             if cross_tick_lower {
-                tick_lower.cross(0, 0, &build_reward_infos(reward_growth_global_x64));
+                tick_lower.cross(0, 0, 0, &build_reward_infos(reward_growth_global_x64));
             } else {
-                tick_upper.cross(0, 0, &build_reward_infos(reward_growth_global_x64));
+                tick_upper.cross(0, 0, 0, &build_reward_infos(reward_growth_global_x64));
             }
 
             tick_current = target_tick_current;
@@ -1307,6 +1438,48 @@ pub mod tick_array_test {
             assert_eq!(reward_frowth_inside_delta, 500);
         }
     }
+    mod get_seconds_per_liquidity_inside_test {
+        use super::*;
+
+        #[test]
+        fn seconds_per_liquidity_inside_after_cross_test() {
+            let tick_lower = &mut TickState::default();
+            tick_lower.tick = -10;
+            let tick_upper = &mut TickState::default();
+            tick_upper.tick = 10;
+            let reward_infos = &[RewardInfo::default(); REWARD_NUM];
+
+            // both ticks initialized at tick 0 with the accumulator at 100
+            tick_lower
+                .update(0, 1000, 0, 0, 100, false, reward_infos)
+                .unwrap();
+            tick_upper
+                .update(0, 1000, 0, 0, 100, true, reward_infos)
+                .unwrap();
+            assert_eq!({ tick_lower.seconds_per_liquidity_outside_x64 }, 100);
+            assert_eq!({ tick_upper.seconds_per_liquidity_outside_x64 }, 0);
+            assert_eq!(
+                get_seconds_per_liquidity_inside(tick_lower, tick_upper, 0, 300),
+                200
+            );
+
+            // price moves above the upper tick when the accumulator is 400
+            tick_upper.cross(0, 0, 400, reward_infos);
+            assert_eq!(
+                get_seconds_per_liquidity_inside(tick_lower, tick_upper, 10, 500),
+                300
+            );
+
+            // price moves back below the lower tick when the accumulator is 600
+            tick_upper.cross(0, 0, 600, reward_infos);
+            tick_lower.cross(0, 0, 600, reward_infos);
+            assert_eq!(
+                get_seconds_per_liquidity_inside(tick_lower, tick_upper, -11, 900),
+                300
+            );
+        }
+    }
+
     mod tick_array_layout_test {
         use super::*;
         use anchor_lang::Discriminator;
@@ -1333,10 +1506,13 @@ pub mod tick_array_test {
                 0x11223344556600778899aabbccddeeff,
                 0x11223344556677008899aabbccddeeff,
             ];
-            let mut tick_padding: [u32; 13] = [0u32; 13];
-            let mut tick_padding_data = [0u8; 4 * 13];
+            let seconds_per_liquidity_outside_x64: u128 = 0x11223344556677880099aabbccddeeff;
+            let range_order_liquidity: u128 = 0x112233445566778899aa00bbccddeeff;
+            let range_order_fill_count: u32 = 0x12345678;
+            let mut tick_padding: [u32; 4] = [0u32; 4];
+            let mut tick_padding_data = [0u8; 4 * 4];
             let mut offset = 0;
-            for i in 0..13 {
+            for i in 0..4 {
                 tick_padding[i] = u32::MAX - 3 * i as u32;
                 tick_padding_data[offset..offset + 4]
                     .copy_from_slice(&tick_padding[i].to_le_bytes());
@@ -1360,8 +1536,15 @@ pub mod tick_array_test {
                     .copy_from_slice(&reward_growths_outside_x64[i].to_le_bytes());
                 offset += 16;
             }
-            tick_data[offset..offset + 4 * 13].copy_from_slice(&tick_padding_data);
-            offset += 4 * 13;
+            tick_data[offset..offset + 16]
+                .copy_from_slice(&seconds_per_liquidity_outside_x64.to_le_bytes());
+            offset += 16;
+            tick_data[offset..offset + 16].copy_from_slice(&range_order_liquidity.to_le_bytes());
+            offset += 16;
+            tick_data[offset..offset + 4].copy_from_slice(&range_order_fill_count.to_le_bytes());
+            offset += 4;
+            tick_data[offset..offset + 4 * 4].copy_from_slice(&tick_padding_data);
+            offset += 4 * 4;
             assert_eq!(offset, tick_data.len());
             assert_eq!(tick_data.len(), core::mem::size_of::<TickState>());
 
@@ -1419,6 +1602,16 @@ pub mod tick_array_test {
                     unpack_reward_growths_outside_x64,
                     reward_growths_outside_x64
                 );
+                let unpack_seconds_per_liquidity_outside_x64 =
+                    tick_item.seconds_per_liquidity_outside_x64;
+                assert_eq!(
+                    unpack_seconds_per_liquidity_outside_x64,
+                    seconds_per_liquidity_outside_x64
+                );
+                let unpack_range_order_liquidity = tick_item.range_order_liquidity;
+                assert_eq!(unpack_range_order_liquidity, range_order_liquidity);
+                let unpack_range_order_fill_count = tick_item.range_order_fill_count;
+                assert_eq!(unpack_range_order_fill_count, range_order_fill_count);
                 let unpack_tick_padding = tick_item.padding;
                 assert_eq!(unpack_tick_padding, tick_padding);
             }
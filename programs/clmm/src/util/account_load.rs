@@ -108,6 +108,71 @@ impl<'info, T: ZeroCopy + Owner> AccountLoad<'info, T> {
         }))
     }
 
+    /// Returns a `RefMut` to the account data structure and a `RefMut` to the elements of type `E`
+    /// stored after it, for accounts that have been reallocated beyond `size_of::<T>()`.
+    /// It is necessary to check the owner as `load_data_mut`
+    pub fn load_data_with_trailing_mut<'a, E: bytemuck::Pod>(
+        acc_info: &'a AccountInfo,
+    ) -> Result<(RefMut<'a, T>, RefMut<'a, [E]>)> {
+        if acc_info.owner != &T::owner() {
+            return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*acc_info.owner, T::owner())));
+        }
+        if !acc_info.is_writable {
+            return Err(ErrorCode::AccountNotMutable.into());
+        }
+
+        let data = acc_info.try_borrow_mut_data()?;
+        if data.len() < mem::size_of::<T>() + 8 {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        let disc_bytes = array_ref![data, 0, 8];
+        if disc_bytes != &T::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        Ok(RefMut::map_split(data, |data| {
+            let (account_data, trailing_data) =
+                data.deref_mut()[8..].split_at_mut(mem::size_of::<T>());
+            let trailing_len = trailing_data.len() / mem::size_of::<E>() * mem::size_of::<E>();
+            (
+                bytemuck::from_bytes_mut(account_data),
+                bytemuck::cast_slice_mut(&mut trailing_data[..trailing_len]),
+            )
+        }))
+    }
+
+    /// Returns a Ref to the account data structure and a Ref to the elements of type `E`
+    /// stored after it for reading.
+    pub fn load_data_with_trailing<'a, E: bytemuck::Pod>(
+        acc_info: &'a AccountInfo,
+    ) -> Result<(Ref<'a, T>, Ref<'a, [E]>)> {
+        if acc_info.owner != &T::owner() {
+            return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*acc_info.owner, T::owner())));
+        }
+
+        let data = acc_info.try_borrow_data()?;
+        if data.len() < mem::size_of::<T>() + 8 {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        let disc_bytes = array_ref![data, 0, 8];
+        if disc_bytes != &T::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        Ok(Ref::map_split(data, |data| {
+            let (account_data, trailing_data) = data[8..].split_at(mem::size_of::<T>());
+            let trailing_len = trailing_data.len() / mem::size_of::<E>() * mem::size_of::<E>();
+            (
+                bytemuck::from_bytes(account_data),
+                bytemuck::cast_slice(&trailing_data[..trailing_len]),
+            )
+        }))
+    }
+
     /// Returns a Ref to the account data structure for reading.
     pub fn load(&self) -> Result<Ref<T>> {
         let data = self.acc_info.try_borrow_data()?;
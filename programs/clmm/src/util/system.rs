@@ -1,3 +1,4 @@
+use crate::error::ErrorCode;
 use anchor_lang::{prelude::*, system_program};
 
 pub fn create_or_allocate_account<'a>(
@@ -55,6 +56,15 @@ pub fn create_or_allocate_account<'a>(
     Ok(())
 }
 
+/// Rejects the transaction once the unix timestamp `deadline` has passed, if any
+pub fn check_deadline(deadline: Option<u64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        let block_timestamp = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+        require_gte!(deadline, block_timestamp, ErrorCode::TransactionTooOld);
+    }
+    Ok(())
+}
+
 #[cfg(not(any(test, feature = "client")))]
 pub fn get_recent_epoch() -> Result<u64> {
     Ok(Clock::get()?.epoch)
@@ -4,7 +4,6 @@ use crate::states::*;
 use anchor_lang::{
     prelude::*,
     solana_program,
-    solana_program::program_option::COption,
     system_program::{create_account, CreateAccount},
 };
 use anchor_spl::memo::spl_memo;
@@ -17,29 +16,23 @@ use anchor_spl::token_interface::{initialize_mint2, InitializeMint2, Mint, Token
 use spl_token_2022::{
     self,
     extension::{
-        default_account_state::DefaultAccountState,
         metadata_pointer,
         transfer_fee::{TransferFeeConfig, MAX_FEE_BASIS_POINTS},
-        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        transfer_hook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
     },
-    state::AccountState,
 };
-use std::collections::HashSet;
 
-const MINT_WHITELIST: [&'static str; 6] = [
-    "HVbpJAQGNpkgBaYBZQBR1t7yFdvaYVp2vCQQfKKEN4tM",
-    "Crn4x1Y2HUKko7ox2EZMT6N2t2ZyH7eKtwkBGVnhEq1g",
-    "FrBfWJ4qE5sCzKm3k3JaAtqZcXUh4LvJygDeketsrsH4",
-    "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo",
-    "DAUcJBg4jSpVoEzASxYzdqHMUN8vuTpQyG2TvDcCHfZg",
-    "AUSD1jCcCyPLybk1YnvPWsHQSrZ46dxwoMniN4N2UEB9",
+/// The token22 extensions tolerated for every mint, other extensions must be allowed
+/// by the `SupportMintAssociated` policy of the mint. A transfer hook runs arbitrary code
+/// on every vault transfer, so it always needs the policy bit
+pub const DEFAULT_ALLOWED_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::ScaledUiAmount,
 ];
 
-pub mod superstate_allowlist {
-    use super::{pubkey, Pubkey};
-    pub const ID: Pubkey = pubkey!("3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2");
-}
-
 pub fn invoke_memo_instruction<'info>(
     memo_msg: &[u8],
     memo_program: AccountInfo<'info>,
@@ -57,6 +50,7 @@ pub fn transfer_from_user_to_pool_vault<'info>(
     token_program: &AccountInfo<'info>,
     token_program_2022: Option<AccountInfo<'info>>,
     amount: u64,
+    transfer_hook_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
@@ -68,6 +62,20 @@ pub fn transfer_from_user_to_pool_vault<'info>(
             if from_token_info.owner == token_program_2022.key {
                 token_program_info = token_program_2022.to_account_info()
             }
+            if get_transfer_hook_program_id(&mint)?.is_some() {
+                return spl_token_2022::onchain::invoke_transfer_checked(
+                    token_program_info.key,
+                    from_token_info,
+                    mint.to_account_info(),
+                    to_vault.to_account_info(),
+                    signer.to_account_info(),
+                    transfer_hook_accounts,
+                    amount,
+                    mint.decimals,
+                    &[],
+                )
+                .map_err(Into::into);
+            }
             token_2022::transfer_checked(
                 CpiContext::new(
                     token_program_info,
@@ -104,6 +112,7 @@ pub fn transfer_from_pool_vault_to_user<'info>(
     token_program: &AccountInfo<'info>,
     token_program_2022: Option<AccountInfo<'info>>,
     amount: u64,
+    transfer_hook_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
@@ -115,6 +124,20 @@ pub fn transfer_from_pool_vault_to_user<'info>(
             if from_vault_info.owner == token_program_2022.key {
                 token_program_info = token_program_2022.to_account_info()
             }
+            if get_transfer_hook_program_id(&mint)?.is_some() {
+                return spl_token_2022::onchain::invoke_transfer_checked(
+                    token_program_info.key,
+                    from_vault_info,
+                    mint.to_account_info(),
+                    to.to_account_info(),
+                    pool_state_loader.to_account_info(),
+                    transfer_hook_accounts,
+                    amount,
+                    mint.decimals,
+                    &[&pool_state_loader.load()?.seeds()],
+                )
+                .map_err(Into::into);
+            }
             token_2022::transfer_checked(
                 CpiContext::new_with_signer(
                     token_program_info,
@@ -187,6 +210,21 @@ pub fn burn<'a, 'b, 'c, 'info>(
     )
 }
 
+/// Returns the program invoked by token-2022 on every transfer of the mint, if any.
+/// Its extra accounts are resolved from the `ExtraAccountMetaList` by `invoke_transfer_checked`,
+/// so the caller passes them after the remaining accounts of the instruction
+pub fn get_transfer_hook_program_id(
+    mint_account: &InterfaceAccount<Mint>,
+) -> Result<Option<Pubkey>> {
+    let mint_info = mint_account.to_account_info();
+    if *mint_info.owner == Token::id() {
+        return Ok(None);
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
 /// Calculate the fee for output amount
 pub fn get_transfer_inverse_fee(
     mint_account: Box<InterfaceAccount<Mint>>,
@@ -200,23 +238,7 @@ pub fn get_transfer_inverse_fee(
     let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
 
     let fee = if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
-        let epoch = get_recent_epoch()?;
-
-        let transfer_fee = transfer_fee_config.get_epoch_fee(epoch);
-        if u16::from(transfer_fee.transfer_fee_basis_points) == MAX_FEE_BASIS_POINTS {
-            u64::from(transfer_fee.maximum_fee)
-        } else {
-            let transfer_fee = transfer_fee_config
-                .calculate_inverse_epoch_fee(epoch, post_fee_amount)
-                .unwrap();
-            let transfer_fee_for_check = transfer_fee_config
-                .calculate_epoch_fee(epoch, post_fee_amount.checked_add(transfer_fee).unwrap())
-                .unwrap();
-            if transfer_fee != transfer_fee_for_check {
-                return err!(ErrorCode::TransferFeeCalculateNotMatch);
-            }
-            transfer_fee
-        }
+        calculate_transfer_inverse_fee(transfer_fee_config, get_recent_epoch()?, post_fee_amount)?
     } else {
         0
     };
@@ -236,27 +258,57 @@ pub fn get_transfer_fee(
     let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
 
     let fee = if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
-        transfer_fee_config
-            .calculate_epoch_fee(get_recent_epoch()?, pre_fee_amount)
-            .unwrap()
+        calculate_transfer_fee(transfer_fee_config, get_recent_epoch()?, pre_fee_amount)
     } else {
         0
     };
     Ok(fee)
 }
 
-pub fn support_mint_associated_is_initialized(
+/// Calculate the transfer fee which makes the receiver get `post_fee_amount` at the given epoch
+pub fn calculate_transfer_inverse_fee(
+    transfer_fee_config: &TransferFeeConfig,
+    epoch: u64,
+    post_fee_amount: u64,
+) -> Result<u64> {
+    let transfer_fee = transfer_fee_config.get_epoch_fee(epoch);
+    if u16::from(transfer_fee.transfer_fee_basis_points) == MAX_FEE_BASIS_POINTS {
+        return Ok(u64::from(transfer_fee.maximum_fee));
+    }
+    let transfer_fee = transfer_fee_config
+        .calculate_inverse_epoch_fee(epoch, post_fee_amount)
+        .unwrap();
+    let transfer_fee_for_check = transfer_fee_config
+        .calculate_epoch_fee(epoch, post_fee_amount.checked_add(transfer_fee).unwrap())
+        .unwrap();
+    if transfer_fee != transfer_fee_for_check {
+        return err!(ErrorCode::TransferFeeCalculateNotMatch);
+    }
+    Ok(transfer_fee)
+}
+
+/// Calculate the transfer fee charged on sending `pre_fee_amount` at the given epoch
+pub fn calculate_transfer_fee(
+    transfer_fee_config: &TransferFeeConfig,
+    epoch: u64,
+    pre_fee_amount: u64,
+) -> u64 {
+    transfer_fee_config
+        .calculate_epoch_fee(epoch, pre_fee_amount)
+        .unwrap()
+}
+
+pub fn get_support_mint_associated(
     remaining_accounts: &[AccountInfo],
     token_mint: &InterfaceAccount<Mint>,
-) -> Result<bool> {
+) -> Result<Option<SupportMintAssociated>> {
     if remaining_accounts.len() == 0 {
-        return Ok(false);
+        return Ok(None);
     }
     let (expect_mint_associated, __bump) = Pubkey::find_program_address(
         &[SUPPORT_MINT_SEED.as_bytes(), token_mint.key().as_ref()],
         &crate::id(),
     );
-    let mut mint_associated_is_initialized = false;
     for mint_associated_info in remaining_accounts.into_iter() {
         if *mint_associated_info.owner != crate::id()
             || mint_associated_info.key() != expect_mint_associated
@@ -267,44 +319,34 @@ pub fn support_mint_associated_is_initialized(
             &mut mint_associated_info.data.borrow().as_ref(),
         )?;
         if mint_associated.mint == token_mint.key() {
-            mint_associated_is_initialized = true;
-            break;
+            return Ok(Some(mint_associated));
         }
     }
-    return Ok(mint_associated_is_initialized);
+    Ok(None)
 }
 
 pub fn is_supported_mint(
     mint_account: &InterfaceAccount<Mint>,
-    mint_associated_is_initialized: bool,
+    mint_associated: Option<&SupportMintAssociated>,
 ) -> Result<bool> {
     let mint_info = mint_account.to_account_info();
     if *mint_info.owner == Token::id() {
         return Ok(true);
     }
-    let mint_whitelist: HashSet<&str> = MINT_WHITELIST.into_iter().collect();
-    if mint_whitelist.contains(mint_account.key().to_string().as_str()) {
-        return Ok(true);
-    }
-    if mint_associated_is_initialized {
-        return Ok(true);
-    }
-
-    if is_superstate_token(&mint_account) {
-        // Supports ScaledUiConfig, which does not work with StateWithExtensions::<spl_token_2022::state::Mint>::unpack
-        // To avoid having to resort to other tricks (or upgrading library dependencies), this is simpler.
-        return Ok(true);
+    if let Some(mint_associated) = mint_associated {
+        // The accounts created before the policy tolerate every extension
+        if mint_associated.allowed_extensions == 0 {
+            return Ok(true);
+        }
     }
     let mint_data = mint_info.try_borrow_data()?;
     let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
     let extensions = mint.get_extension_types()?;
     for e in extensions {
-        if e != ExtensionType::TransferFeeConfig
-            && e != ExtensionType::MetadataPointer
-            && e != ExtensionType::TokenMetadata
-            && e != ExtensionType::InterestBearingConfig
-            && e != ExtensionType::ScaledUiAmount
-        {
+        if DEFAULT_ALLOWED_EXTENSIONS.contains(&e) {
+            continue;
+        }
+        if !mint_associated.is_some_and(|mint_associated| mint_associated.is_extension_allowed(e)) {
             return Ok(false);
         }
     }
@@ -408,8 +450,8 @@ pub fn create_token_vault_account<'info>(
     system_program: &Program<'info, System>,
     token_2022_program: &Interface<'info, TokenInterface>,
     signer_seeds: &[&[u8]],
+    immutable_owner_required: bool,
 ) -> Result<()> {
-    let immutable_owner_required = is_superstate_token(token_mint);
     // support both spl_token_program & token_program_2022
     let space = get_account_data_size(
         CpiContext::new(
@@ -457,33 +499,3 @@ pub fn create_token_vault_account<'info>(
 
     Ok(())
 }
-
-pub fn is_superstate_token(mint_account: &InterfaceAccount<Mint>) -> bool {
-    if let COption::Some(freeze_authority) = mint_account.freeze_authority {
-        let mint_account_info = mint_account.to_account_info();
-        let mint_data = mint_account_info.try_borrow_data().unwrap();
-        let mint_state =
-            StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data).unwrap();
-        let default_account_state_freeze =
-            if let Ok(default_account_state) = mint_state.get_extension::<DefaultAccountState>() {
-                default_account_state.state == (AccountState::Frozen as u8)
-            } else {
-                false
-            };
-
-        let maybe_permanent_delegate = if let Some(permanent_delegate) =
-            spl_token_2022::extension::permanent_delegate::get_permanent_delegate(&mint_state)
-        {
-            permanent_delegate == superstate_allowlist::ID
-        } else {
-            false
-        };
-
-        superstate_allowlist::ID == freeze_authority
-            && *mint_account_info.owner == spl_token_2022::ID
-            && default_account_state_freeze
-            && maybe_permanent_delegate
-    } else {
-        false
-    }
-}
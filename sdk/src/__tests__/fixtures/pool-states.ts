@@ -75,9 +75,15 @@ export const DEFAULT_AMM_CONFIG: AmmConfig = {
   tradeFeeRate: 3000, // 0.3% (denominated in hundredths of a bip: 3000 / 10^6 = 0.003)
   tickSpacing: 60, // Standard tick spacing
   fundFeeRate: 1000, // 0.1%
-  paddingU32: 0,
+  flashLoanFeeRate: 0,
   fundOwner: TEST_ADDRESSES.FUND_OWNER,
-  padding: [0n, 0n, 0n],
+  dynamicFeeMinRate: 0,
+  dynamicFeeMaxRate: 0,
+  status: 0,
+  padding1: new Uint8Array(3),
+  maxReferralFeeRate: 0,
+  maxLaunchFeeStartRate: 0,
+  maxLaunchFeeDuration: 0,
 };
 
 /**
@@ -93,9 +99,15 @@ export const STABLECOIN_AMM_CONFIG: AmmConfig = {
   tradeFeeRate: 500, // 0.05%
   tickSpacing: 10, // Tighter tick spacing for stablecoins
   fundFeeRate: 400, // 0.04%
-  paddingU32: 0,
+  flashLoanFeeRate: 0,
   fundOwner: TEST_ADDRESSES.FUND_OWNER,
-  padding: [0n, 0n, 0n],
+  dynamicFeeMinRate: 0,
+  dynamicFeeMaxRate: 0,
+  status: 0,
+  padding1: new Uint8Array(3),
+  maxReferralFeeRate: 0,
+  maxLaunchFeeStartRate: 0,
+  maxLaunchFeeDuration: 0,
 };
 
 /**
@@ -110,9 +122,15 @@ export const HIGH_FEE_AMM_CONFIG: AmmConfig = {
   tradeFeeRate: 10000, // 1%
   tickSpacing: 200, // Wider tick spacing
   fundFeeRate: 3000, // 0.3%
-  paddingU32: 0,
+  flashLoanFeeRate: 0,
   fundOwner: TEST_ADDRESSES.FUND_OWNER,
-  padding: [0n, 0n, 0n],
+  dynamicFeeMinRate: 0,
+  dynamicFeeMaxRate: 0,
+  status: 0,
+  padding1: new Uint8Array(3),
+  maxReferralFeeRate: 0,
+  maxLaunchFeeStartRate: 0,
+  maxLaunchFeeDuration: 0,
 };
 
 /**
@@ -152,7 +170,8 @@ export const USDC_SOL_POOL: PoolState = {
   swapInAmountToken1: 50000000000n, // 50 SOL swapped in
   swapOutAmountToken0: 5000000000000n, // 5M USDC swapped out
   status: 0, // All operations enabled
-  padding: new Uint8Array(7),
+  ammConfigStatus: 0,
+  padding: new Uint8Array(6),
   rewardInfos: [
     createEmptyRewardInfo(),
     createEmptyRewardInfo(),
@@ -167,7 +186,30 @@ export const USDC_SOL_POOL: PoolState = {
   fundFeesToken1: 25000000n,
   openTime: 1700000000n,
   recentEpoch: 500n,
-  padding1: Array(24).fill(0n),
+  secondsPerLiquidityGlobalX64: 0n,
+  secondsPerLiquidityUpdateTime: 0n,
+  volatilityAccumulator: 0,
+  volatilityReference: 0,
+  tickReference: 0,
+  volatilityUpdateTime: 0,
+  flashLoanAmount0: 0n,
+  flashLoanAmount1: 0n,
+  tradeFeeRateOverride: 0,
+  protocolFeeRateOverride: 0,
+  fundFeeRateOverride: 0,
+  feeOverrideFlags: 0,
+  effectiveAmmConfig: address("11111111111111111111111111111111"),
+  launchFeeStartRate: 0,
+  launchFeeEndRate: 0,
+  launchFeeDuration: 0,
+  twapGuardWindow: 0,
+  twapGuardMaxTickDeviation: 0,
+  twapGuardMode: 0,
+  padding5: new Uint8Array(3),
+  flashLoanLiquidity: 0n,
+  twapGuardAnchorTick: 0,
+  twapGuardAnchorTime: 0,
+  padding1: Array(5).fill(0n),
   padding2: Array(32).fill(0n),
 };
 
@@ -207,7 +249,8 @@ export const USDC_USDT_POOL: PoolState = {
   swapInAmountToken1: 10000000000000n, // 10M USDT
   swapOutAmountToken0: 9995000000000n, // ~10M USDC (minus fees)
   status: 0,
-  padding: new Uint8Array(7),
+  ammConfigStatus: 0,
+  padding: new Uint8Array(6),
   rewardInfos: [
     createEmptyRewardInfo(),
     createEmptyRewardInfo(),
@@ -222,7 +265,30 @@ export const USDC_USDT_POOL: PoolState = {
   fundFeesToken1: 2000000n,
   openTime: 1700000000n,
   recentEpoch: 500n,
-  padding1: Array(24).fill(0n),
+  secondsPerLiquidityGlobalX64: 0n,
+  secondsPerLiquidityUpdateTime: 0n,
+  volatilityAccumulator: 0,
+  volatilityReference: 0,
+  tickReference: 0,
+  volatilityUpdateTime: 0,
+  flashLoanAmount0: 0n,
+  flashLoanAmount1: 0n,
+  tradeFeeRateOverride: 0,
+  protocolFeeRateOverride: 0,
+  fundFeeRateOverride: 0,
+  feeOverrideFlags: 0,
+  effectiveAmmConfig: address("11111111111111111111111111111111"),
+  launchFeeStartRate: 0,
+  launchFeeEndRate: 0,
+  launchFeeDuration: 0,
+  twapGuardWindow: 0,
+  twapGuardMaxTickDeviation: 0,
+  twapGuardMode: 0,
+  padding5: new Uint8Array(3),
+  flashLoanLiquidity: 0n,
+  twapGuardAnchorTick: 0,
+  twapGuardAnchorTime: 0,
+  padding1: Array(5).fill(0n),
   padding2: Array(32).fill(0n),
 };
 
@@ -262,7 +328,8 @@ export const BTC_ETH_POOL: PoolState = {
   swapInAmountToken1: 5000000000n, // 50 BTC
   swapOutAmountToken0: 75000000000n, // 750 ETH
   status: 0,
-  padding: new Uint8Array(7),
+  ammConfigStatus: 0,
+  padding: new Uint8Array(6),
   rewardInfos: [
     createEmptyRewardInfo(),
     createEmptyRewardInfo(),
@@ -277,7 +344,30 @@ export const BTC_ETH_POOL: PoolState = {
   fundFeesToken1: 5000000n,
   openTime: 1700000000n,
   recentEpoch: 500n,
-  padding1: Array(24).fill(0n),
+  secondsPerLiquidityGlobalX64: 0n,
+  secondsPerLiquidityUpdateTime: 0n,
+  volatilityAccumulator: 0,
+  volatilityReference: 0,
+  tickReference: 0,
+  volatilityUpdateTime: 0,
+  flashLoanAmount0: 0n,
+  flashLoanAmount1: 0n,
+  tradeFeeRateOverride: 0,
+  protocolFeeRateOverride: 0,
+  fundFeeRateOverride: 0,
+  feeOverrideFlags: 0,
+  effectiveAmmConfig: address("11111111111111111111111111111111"),
+  launchFeeStartRate: 0,
+  launchFeeEndRate: 0,
+  launchFeeDuration: 0,
+  twapGuardWindow: 0,
+  twapGuardMaxTickDeviation: 0,
+  twapGuardMode: 0,
+  padding5: new Uint8Array(3),
+  flashLoanLiquidity: 0n,
+  twapGuardAnchorTick: 0,
+  twapGuardAnchorTime: 0,
+  padding1: Array(5).fill(0n),
   padding2: Array(32).fill(0n),
 };
 
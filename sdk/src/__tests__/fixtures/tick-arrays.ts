@@ -22,7 +22,10 @@ function createEmptyTick(tickIndex: number) {
     feeGrowthOutside0X64: 0n,
     feeGrowthOutside1X64: 0n,
     rewardGrowthsOutsideX64: [0n, 0n, 0n],
-    padding: [0, 0, 0, 0, 0, 0, 0],
+    secondsPerLiquidityOutsideX64: 0n,
+    rangeOrderLiquidity: 0n,
+    rangeOrderFillCount: 0,
+    padding: [0, 0, 0, 0],
   };
 }
 
@@ -715,8 +715,9 @@ describe("PoolDataManager", () => {
 
       // Should have default values for fields not in API
       expect(config.bump).toBe(0);
-      expect(config.paddingU32).toBe(0);
-      expect(config.padding).toEqual([0n, 0n, 0n]);
+      expect(config.flashLoanFeeRate).toBe(0);
+      expect(config.maxLaunchFeeStartRate).toBe(0);
+      expect(config.maxLaunchFeeDuration).toBe(0);
       expect(config.discriminator).toEqual(
         new Uint8Array([218, 244, 33, 104, 203, 203, 43, 111])
       );
@@ -17,8 +17,6 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getArrayDecoder,
-  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -27,8 +25,6 @@ import {
   getU16Encoder,
   getU32Decoder,
   getU32Encoder,
-  getU64Decoder,
-  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -68,9 +64,32 @@ export type AmmConfig = {
   tickSpacing: number;
   /** The fund fee, denominated in hundredths of a bip (10^-6) */
   fundFeeRate: number;
-  paddingU32: number;
+  /** The flash loan fee, denominated in hundredths of a bip (10^-6), flash loans are disabled when it is zero */
+  flashLoanFeeRate: number;
   fundOwner: Address;
-  padding: Array<bigint>;
+  /** The lower bound of the dynamic trade fee, denominated in hundredths of a bip (10^-6) */
+  dynamicFeeMinRate: number;
+  /** The upper bound of the dynamic trade fee, the dynamic fee mode is disabled when it is zero */
+  dynamicFeeMaxRate: number;
+  /**
+   * Bitwise representation of the operations disabled for the pools of the config, same bits
+   * as the pool status. It applies on top of the pool status, swaps and flash loans read it
+   * from the config and the other instructions from the copy cached on the pool
+   */
+  status: number;
+  padding1: ReadonlyUint8Array;
+  /**
+   * The max share of the trade fee a swap can pay to its referrer, denominated in hundredths
+   * of a bip (10^-6) of the trade fee like the protocol fee
+   */
+  maxReferralFeeRate: number;
+  /**
+   * The max launch fee start rate of the pools created in the config, denominated in
+   * hundredths of a bip (10^-6), the launch fee is disabled when it is zero
+   */
+  maxLaunchFeeStartRate: number;
+  /** The max duration in seconds of the launch fee schedule of the pools created in the config */
+  maxLaunchFeeDuration: number;
 };
 
 export type AmmConfigArgs = {
@@ -87,9 +106,32 @@ export type AmmConfigArgs = {
   tickSpacing: number;
   /** The fund fee, denominated in hundredths of a bip (10^-6) */
   fundFeeRate: number;
-  paddingU32: number;
+  /** The flash loan fee, denominated in hundredths of a bip (10^-6), flash loans are disabled when it is zero */
+  flashLoanFeeRate: number;
   fundOwner: Address;
-  padding: Array<number | bigint>;
+  /** The lower bound of the dynamic trade fee, denominated in hundredths of a bip (10^-6) */
+  dynamicFeeMinRate: number;
+  /** The upper bound of the dynamic trade fee, the dynamic fee mode is disabled when it is zero */
+  dynamicFeeMaxRate: number;
+  /**
+   * Bitwise representation of the operations disabled for the pools of the config, same bits
+   * as the pool status. It applies on top of the pool status, swaps and flash loans read it
+   * from the config and the other instructions from the copy cached on the pool
+   */
+  status: number;
+  padding1: ReadonlyUint8Array;
+  /**
+   * The max share of the trade fee a swap can pay to its referrer, denominated in hundredths
+   * of a bip (10^-6) of the trade fee like the protocol fee
+   */
+  maxReferralFeeRate: number;
+  /**
+   * The max launch fee start rate of the pools created in the config, denominated in
+   * hundredths of a bip (10^-6), the launch fee is disabled when it is zero
+   */
+  maxLaunchFeeStartRate: number;
+  /** The max duration in seconds of the launch fee schedule of the pools created in the config */
+  maxLaunchFeeDuration: number;
 };
 
 export function getAmmConfigEncoder(): FixedSizeEncoder<AmmConfigArgs> {
@@ -103,9 +145,15 @@ export function getAmmConfigEncoder(): FixedSizeEncoder<AmmConfigArgs> {
       ['tradeFeeRate', getU32Encoder()],
       ['tickSpacing', getU16Encoder()],
       ['fundFeeRate', getU32Encoder()],
-      ['paddingU32', getU32Encoder()],
+      ['flashLoanFeeRate', getU32Encoder()],
       ['fundOwner', getAddressEncoder()],
-      ['padding', getArrayEncoder(getU64Encoder(), { size: 3 })],
+      ['dynamicFeeMinRate', getU32Encoder()],
+      ['dynamicFeeMaxRate', getU32Encoder()],
+      ['status', getU8Encoder()],
+      ['padding1', fixEncoderSize(getBytesEncoder(), 3)],
+      ['maxReferralFeeRate', getU32Encoder()],
+      ['maxLaunchFeeStartRate', getU32Encoder()],
+      ['maxLaunchFeeDuration', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: AMM_CONFIG_DISCRIMINATOR })
   );
@@ -121,9 +169,15 @@ export function getAmmConfigDecoder(): FixedSizeDecoder<AmmConfig> {
     ['tradeFeeRate', getU32Decoder()],
     ['tickSpacing', getU16Decoder()],
     ['fundFeeRate', getU32Decoder()],
-    ['paddingU32', getU32Decoder()],
+    ['flashLoanFeeRate', getU32Decoder()],
     ['fundOwner', getAddressDecoder()],
-    ['padding', getArrayDecoder(getU64Decoder(), { size: 3 })],
+    ['dynamicFeeMinRate', getU32Decoder()],
+    ['dynamicFeeMaxRate', getU32Decoder()],
+    ['status', getU8Decoder()],
+    ['padding1', fixDecoderSize(getBytesDecoder(), 3)],
+    ['maxReferralFeeRate', getU32Decoder()],
+    ['maxLaunchFeeStartRate', getU32Decoder()],
+    ['maxLaunchFeeDuration', getU32Decoder()],
   ]);
 }
 
@@ -7,9 +7,13 @@
  */
 
 export * from './ammConfig';
+export * from './ammConfigPendingOwner';
+export * from './ammConfigPendingUpdate';
+export * from './globalConfig';
 export * from './observationState';
 export * from './operationState';
 export * from './personalPositionState';
+export * from './poolConfigPendingUpdate';
 export * from './poolState';
 export * from './protocolPositionState';
 export * from './supportMintAssociated';
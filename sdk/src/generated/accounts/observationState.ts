@@ -71,6 +71,9 @@ export type ObservationState = {
   poolId: Address;
   /** observation array */
   observations: Array<Observation>;
+  /** the number of observations in use, 0 means OBSERVATION_NUM for accounts created before it was introduced */
+  observationCardinality: number;
+  paddingU16: Array<number>;
   /** padding for feature update */
   padding: Array<bigint>;
 };
@@ -86,6 +89,9 @@ export type ObservationStateArgs = {
   poolId: Address;
   /** observation array */
   observations: Array<ObservationArgs>;
+  /** the number of observations in use, 0 means OBSERVATION_NUM for accounts created before it was introduced */
+  observationCardinality: number;
+  paddingU16: Array<number>;
   /** padding for feature update */
   padding: Array<number | bigint>;
 };
@@ -99,7 +105,9 @@ export function getObservationStateEncoder(): FixedSizeEncoder<ObservationStateA
       ['observationIndex', getU16Encoder()],
       ['poolId', getAddressEncoder()],
       ['observations', getArrayEncoder(getObservationEncoder(), { size: 100 })],
-      ['padding', getArrayEncoder(getU64Encoder(), { size: 4 })],
+      ['observationCardinality', getU16Encoder()],
+      ['paddingU16', getArrayEncoder(getU16Encoder(), { size: 3 })],
+      ['padding', getArrayEncoder(getU64Encoder(), { size: 3 })],
     ]),
     (value) => ({ ...value, discriminator: OBSERVATION_STATE_DISCRIMINATOR })
   );
@@ -113,7 +121,9 @@ export function getObservationStateDecoder(): FixedSizeDecoder<ObservationState>
     ['observationIndex', getU16Decoder()],
     ['poolId', getAddressDecoder()],
     ['observations', getArrayDecoder(getObservationDecoder(), { size: 100 })],
-    ['padding', getArrayDecoder(getU64Decoder(), { size: 4 })],
+    ['observationCardinality', getU16Decoder()],
+    ['paddingU16', getArrayDecoder(getU16Decoder(), { size: 3 })],
+    ['padding', getArrayDecoder(getU64Decoder(), { size: 3 })],
   ]);
 }
 
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI32Decoder,
@@ -27,6 +29,10 @@ import {
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -83,6 +89,18 @@ export type PersonalPositionState = {
   tokenFeesOwed1: bigint;
   rewardInfos: Array<PositionRewardInfo>;
   recentEpoch: bigint;
+  /** Whether the position is a range order, which is filled once the price crosses its range */
+  rangeOrder: boolean;
+  /**
+   * True if the range order sells token_0 and is filled when the price crosses tick_upper upward,
+   * otherwise it sells token_1 and is filled when the price crosses tick_lower downward
+   */
+  rangeOrderZeroForOne: boolean;
+  /** The range_order_fill_count of the fill tick when the order was placed */
+  rangeOrderFillCount: number;
+  paddingU16: number;
+  /** The liquidity can not be removed before this unix timestamp, `PERMANENT_LOCK` locks it forever */
+  lockedUntil: bigint;
   padding: Array<bigint>;
 };
 
@@ -109,6 +127,18 @@ export type PersonalPositionStateArgs = {
   tokenFeesOwed1: number | bigint;
   rewardInfos: Array<PositionRewardInfoArgs>;
   recentEpoch: number | bigint;
+  /** Whether the position is a range order, which is filled once the price crosses its range */
+  rangeOrder: boolean;
+  /**
+   * True if the range order sells token_0 and is filled when the price crosses tick_upper upward,
+   * otherwise it sells token_1 and is filled when the price crosses tick_lower downward
+   */
+  rangeOrderZeroForOne: boolean;
+  /** The range_order_fill_count of the fill tick when the order was placed */
+  rangeOrderFillCount: number;
+  paddingU16: number;
+  /** The liquidity can not be removed before this unix timestamp, `PERMANENT_LOCK` locks it forever */
+  lockedUntil: number | bigint;
   padding: Array<number | bigint>;
 };
 
@@ -131,7 +161,12 @@ export function getPersonalPositionStateEncoder(): FixedSizeEncoder<PersonalPosi
         getArrayEncoder(getPositionRewardInfoEncoder(), { size: 3 }),
       ],
       ['recentEpoch', getU64Encoder()],
-      ['padding', getArrayEncoder(getU64Encoder(), { size: 7 })],
+      ['rangeOrder', getBooleanEncoder()],
+      ['rangeOrderZeroForOne', getBooleanEncoder()],
+      ['rangeOrderFillCount', getU32Encoder()],
+      ['paddingU16', getU16Encoder()],
+      ['lockedUntil', getU64Encoder()],
+      ['padding', getArrayEncoder(getU64Encoder(), { size: 5 })],
     ]),
     (value) => ({
       ...value,
@@ -158,7 +193,12 @@ export function getPersonalPositionStateDecoder(): FixedSizeDecoder<PersonalPosi
       getArrayDecoder(getPositionRewardInfoDecoder(), { size: 3 }),
     ],
     ['recentEpoch', getU64Decoder()],
-    ['padding', getArrayDecoder(getU64Decoder(), { size: 7 })],
+    ['rangeOrder', getBooleanDecoder()],
+    ['rangeOrderZeroForOne', getBooleanDecoder()],
+    ['rangeOrderFillCount', getU32Decoder()],
+    ['paddingU16', getU16Decoder()],
+    ['lockedUntil', getU64Decoder()],
+    ['padding', getArrayDecoder(getU64Decoder(), { size: 5 })],
   ]);
 }
 
@@ -29,6 +29,8 @@ import {
   getU128Encoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -111,6 +113,12 @@ export type PoolState = {
    * bit4, 1: disable swap, 0: normal
    */
   status: number;
+  /**
+   * The status of the amm config of the pool, same bits as `status`. It is kept apart from
+   * the pool status and refreshed from the amm config by swaps, flash loans and the config
+   * status instructions
+   */
+  ammConfigStatus: number;
   /** Leave blank for future use */
   padding: ReadonlyUint8Array;
   rewardInfos: Array<RewardInfo>;
@@ -126,6 +134,63 @@ export type PoolState = {
   fundFeesToken1: bigint;
   openTime: bigint;
   recentEpoch: bigint;
+  /**
+   * The seconds per unit of in range liquidity as a Q64.64 number for the entire life of the pool,
+   * only has relative meaning like the fee growth
+   */
+  secondsPerLiquidityGlobalX64: bigint;
+  /** The timestamp when seconds_per_liquidity_global_x64 was last updated */
+  secondsPerLiquidityUpdateTime: bigint;
+  /** The volatility accumulated by recent swaps in ticks, drives the dynamic trade fee */
+  volatilityAccumulator: number;
+  /** The decayed volatility carried over from previous swaps */
+  volatilityReference: number;
+  /** The tick which the volatility accumulator measures the movement from */
+  tickReference: number;
+  /** The timestamp of the last swap which updated the volatility accumulator */
+  volatilityUpdateTime: number;
+  /** The amounts of token_0 and token_1 lent by a flash loan which is not repaid yet */
+  flashLoanAmount0: bigint;
+  flashLoanAmount1: bigint;
+  /**
+   * Per pool overrides of the amm config fee rates, denominated in hundredths of a bip (10^-6),
+   * only applied when the matching bit of fee_override_flags is set
+   */
+  tradeFeeRateOverride: number;
+  protocolFeeRateOverride: number;
+  fundFeeRateOverride: number;
+  /** bit0: trade fee rate, bit1: protocol fee rate, bit2: fund fee rate */
+  feeOverrideFlags: number;
+  /**
+   * The config the pool was migrated to, the default key while the pool uses the config
+   * it was created with. `amm_config` is kept because it seeds the pool address
+   */
+  effectiveAmmConfig: Address;
+  /**
+   * The trade fee rate charged right after open_time, decaying linearly to the end rate
+   * over launch_fee_duration seconds, the schedule is disabled when the duration is zero
+   */
+  launchFeeStartRate: number;
+  launchFeeEndRate: number;
+  launchFeeDuration: number;
+  /**
+   * The seconds of the time-weighted average tick the swaps are compared to,
+   * the twap guard is disabled when it is zero
+   */
+  twapGuardWindow: number;
+  /** The ticks the price can move away from the time-weighted average tick by a swap */
+  twapGuardMaxTickDeviation: number;
+  /** What happens to a swap moving the price too far, see `TwapGuardMode` */
+  twapGuardMode: number;
+  padding5: ReadonlyUint8Array;
+  /** The in range liquidity when the open flash loan was borrowed, it earns the flash loan fees */
+  flashLoanLiquidity: bigint;
+  /**
+   * The tick before the first swap at twap_guard_anchor_time, the twap guard bound is
+   * centred on it while the observations have no history yet
+   */
+  twapGuardAnchorTick: number;
+  twapGuardAnchorTime: number;
   padding1: Array<bigint>;
   padding2: Array<bigint>;
 };
@@ -179,6 +244,12 @@ export type PoolStateArgs = {
    * bit4, 1: disable swap, 0: normal
    */
   status: number;
+  /**
+   * The status of the amm config of the pool, same bits as `status`. It is kept apart from
+   * the pool status and refreshed from the amm config by swaps, flash loans and the config
+   * status instructions
+   */
+  ammConfigStatus: number;
   /** Leave blank for future use */
   padding: ReadonlyUint8Array;
   rewardInfos: Array<RewardInfoArgs>;
@@ -194,6 +265,63 @@ export type PoolStateArgs = {
   fundFeesToken1: number | bigint;
   openTime: number | bigint;
   recentEpoch: number | bigint;
+  /**
+   * The seconds per unit of in range liquidity as a Q64.64 number for the entire life of the pool,
+   * only has relative meaning like the fee growth
+   */
+  secondsPerLiquidityGlobalX64: number | bigint;
+  /** The timestamp when seconds_per_liquidity_global_x64 was last updated */
+  secondsPerLiquidityUpdateTime: number | bigint;
+  /** The volatility accumulated by recent swaps in ticks, drives the dynamic trade fee */
+  volatilityAccumulator: number;
+  /** The decayed volatility carried over from previous swaps */
+  volatilityReference: number;
+  /** The tick which the volatility accumulator measures the movement from */
+  tickReference: number;
+  /** The timestamp of the last swap which updated the volatility accumulator */
+  volatilityUpdateTime: number;
+  /** The amounts of token_0 and token_1 lent by a flash loan which is not repaid yet */
+  flashLoanAmount0: number | bigint;
+  flashLoanAmount1: number | bigint;
+  /**
+   * Per pool overrides of the amm config fee rates, denominated in hundredths of a bip (10^-6),
+   * only applied when the matching bit of fee_override_flags is set
+   */
+  tradeFeeRateOverride: number;
+  protocolFeeRateOverride: number;
+  fundFeeRateOverride: number;
+  /** bit0: trade fee rate, bit1: protocol fee rate, bit2: fund fee rate */
+  feeOverrideFlags: number;
+  /**
+   * The config the pool was migrated to, the default key while the pool uses the config
+   * it was created with. `amm_config` is kept because it seeds the pool address
+   */
+  effectiveAmmConfig: Address;
+  /**
+   * The trade fee rate charged right after open_time, decaying linearly to the end rate
+   * over launch_fee_duration seconds, the schedule is disabled when the duration is zero
+   */
+  launchFeeStartRate: number;
+  launchFeeEndRate: number;
+  launchFeeDuration: number;
+  /**
+   * The seconds of the time-weighted average tick the swaps are compared to,
+   * the twap guard is disabled when it is zero
+   */
+  twapGuardWindow: number;
+  /** The ticks the price can move away from the time-weighted average tick by a swap */
+  twapGuardMaxTickDeviation: number;
+  /** What happens to a swap moving the price too far, see `TwapGuardMode` */
+  twapGuardMode: number;
+  padding5: ReadonlyUint8Array;
+  /** The in range liquidity when the open flash loan was borrowed, it earns the flash loan fees */
+  flashLoanLiquidity: number | bigint;
+  /**
+   * The tick before the first swap at twap_guard_anchor_time, the twap guard bound is
+   * centred on it while the observations have no history yet
+   */
+  twapGuardAnchorTick: number;
+  twapGuardAnchorTime: number;
   padding1: Array<number | bigint>;
   padding2: Array<number | bigint>;
 };
@@ -227,7 +355,8 @@ export function getPoolStateEncoder(): FixedSizeEncoder<PoolStateArgs> {
       ['swapInAmountToken1', getU128Encoder()],
       ['swapOutAmountToken0', getU128Encoder()],
       ['status', getU8Encoder()],
-      ['padding', fixEncoderSize(getBytesEncoder(), 7)],
+      ['ammConfigStatus', getU8Encoder()],
+      ['padding', fixEncoderSize(getBytesEncoder(), 6)],
       ['rewardInfos', getArrayEncoder(getRewardInfoEncoder(), { size: 3 })],
       ['tickArrayBitmap', getArrayEncoder(getU64Encoder(), { size: 16 })],
       ['totalFeesToken0', getU64Encoder()],
@@ -238,7 +367,30 @@ export function getPoolStateEncoder(): FixedSizeEncoder<PoolStateArgs> {
       ['fundFeesToken1', getU64Encoder()],
       ['openTime', getU64Encoder()],
       ['recentEpoch', getU64Encoder()],
-      ['padding1', getArrayEncoder(getU64Encoder(), { size: 24 })],
+      ['secondsPerLiquidityGlobalX64', getU128Encoder()],
+      ['secondsPerLiquidityUpdateTime', getU64Encoder()],
+      ['volatilityAccumulator', getU32Encoder()],
+      ['volatilityReference', getU32Encoder()],
+      ['tickReference', getI32Encoder()],
+      ['volatilityUpdateTime', getU32Encoder()],
+      ['flashLoanAmount0', getU64Encoder()],
+      ['flashLoanAmount1', getU64Encoder()],
+      ['tradeFeeRateOverride', getU32Encoder()],
+      ['protocolFeeRateOverride', getU32Encoder()],
+      ['fundFeeRateOverride', getU32Encoder()],
+      ['feeOverrideFlags', getU32Encoder()],
+      ['effectiveAmmConfig', getAddressEncoder()],
+      ['launchFeeStartRate', getU32Encoder()],
+      ['launchFeeEndRate', getU32Encoder()],
+      ['launchFeeDuration', getU32Encoder()],
+      ['twapGuardWindow', getU32Encoder()],
+      ['twapGuardMaxTickDeviation', getU32Encoder()],
+      ['twapGuardMode', getU8Encoder()],
+      ['padding5', fixEncoderSize(getBytesEncoder(), 3)],
+      ['flashLoanLiquidity', getU128Encoder()],
+      ['twapGuardAnchorTick', getI32Encoder()],
+      ['twapGuardAnchorTime', getU32Encoder()],
+      ['padding1', getArrayEncoder(getU64Encoder(), { size: 5 })],
       ['padding2', getArrayEncoder(getU64Encoder(), { size: 32 })],
     ]),
     (value) => ({ ...value, discriminator: POOL_STATE_DISCRIMINATOR })
@@ -273,7 +425,8 @@ export function getPoolStateDecoder(): FixedSizeDecoder<PoolState> {
     ['swapInAmountToken1', getU128Decoder()],
     ['swapOutAmountToken0', getU128Decoder()],
     ['status', getU8Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 7)],
+    ['ammConfigStatus', getU8Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 6)],
     ['rewardInfos', getArrayDecoder(getRewardInfoDecoder(), { size: 3 })],
     ['tickArrayBitmap', getArrayDecoder(getU64Decoder(), { size: 16 })],
     ['totalFeesToken0', getU64Decoder()],
@@ -284,7 +437,30 @@ export function getPoolStateDecoder(): FixedSizeDecoder<PoolState> {
     ['fundFeesToken1', getU64Decoder()],
     ['openTime', getU64Decoder()],
     ['recentEpoch', getU64Decoder()],
-    ['padding1', getArrayDecoder(getU64Decoder(), { size: 24 })],
+    ['secondsPerLiquidityGlobalX64', getU128Decoder()],
+    ['secondsPerLiquidityUpdateTime', getU64Decoder()],
+    ['volatilityAccumulator', getU32Decoder()],
+    ['volatilityReference', getU32Decoder()],
+    ['tickReference', getI32Decoder()],
+    ['volatilityUpdateTime', getU32Decoder()],
+    ['flashLoanAmount0', getU64Decoder()],
+    ['flashLoanAmount1', getU64Decoder()],
+    ['tradeFeeRateOverride', getU32Decoder()],
+    ['protocolFeeRateOverride', getU32Decoder()],
+    ['fundFeeRateOverride', getU32Decoder()],
+    ['feeOverrideFlags', getU32Decoder()],
+    ['effectiveAmmConfig', getAddressDecoder()],
+    ['launchFeeStartRate', getU32Decoder()],
+    ['launchFeeEndRate', getU32Decoder()],
+    ['launchFeeDuration', getU32Decoder()],
+    ['twapGuardWindow', getU32Decoder()],
+    ['twapGuardMaxTickDeviation', getU32Decoder()],
+    ['twapGuardMode', getU8Decoder()],
+    ['padding5', fixDecoderSize(getBytesDecoder(), 3)],
+    ['flashLoanLiquidity', getU128Decoder()],
+    ['twapGuardAnchorTick', getI32Decoder()],
+    ['twapGuardAnchorTime', getU32Decoder()],
+    ['padding1', getArrayDecoder(getU64Decoder(), { size: 5 })],
     ['padding2', getArrayDecoder(getU64Decoder(), { size: 32 })],
   ]);
 }
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -57,6 +59,17 @@ export type SupportMintAssociated = {
   bump: number;
   /** Address of the supported token22 mint */
   mint: Address;
+  /**
+   * The token22 extensions tolerated for the mint, bit n is the extension type n.
+   * Zero for the accounts created before the policy, they tolerate every extension
+   */
+  allowedExtensions: bigint;
+  /**
+   * The pool vaults of the mint are created with the ImmutableOwner extension,
+   * required by the issuers whose mints freeze the accounts without it
+   */
+  vaultImmutableOwner: boolean;
+  padding1: ReadonlyUint8Array;
   padding: Array<bigint>;
 };
 
@@ -65,6 +78,17 @@ export type SupportMintAssociatedArgs = {
   bump: number;
   /** Address of the supported token22 mint */
   mint: Address;
+  /**
+   * The token22 extensions tolerated for the mint, bit n is the extension type n.
+   * Zero for the accounts created before the policy, they tolerate every extension
+   */
+  allowedExtensions: number | bigint;
+  /**
+   * The pool vaults of the mint are created with the ImmutableOwner extension,
+   * required by the issuers whose mints freeze the accounts without it
+   */
+  vaultImmutableOwner: boolean;
+  padding1: ReadonlyUint8Array;
   padding: Array<number | bigint>;
 };
 
@@ -74,7 +98,10 @@ export function getSupportMintAssociatedEncoder(): FixedSizeEncoder<SupportMintA
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['bump', getU8Encoder()],
       ['mint', getAddressEncoder()],
-      ['padding', getArrayEncoder(getU64Encoder(), { size: 8 })],
+      ['allowedExtensions', getU64Encoder()],
+      ['vaultImmutableOwner', getBooleanEncoder()],
+      ['padding1', fixEncoderSize(getBytesEncoder(), 7)],
+      ['padding', getArrayEncoder(getU64Encoder(), { size: 6 })],
     ]),
     (value) => ({
       ...value,
@@ -88,7 +115,10 @@ export function getSupportMintAssociatedDecoder(): FixedSizeDecoder<SupportMintA
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['bump', getU8Decoder()],
     ['mint', getAddressDecoder()],
-    ['padding', getArrayDecoder(getU64Decoder(), { size: 8 })],
+    ['allowedExtensions', getU64Decoder()],
+    ['vaultImmutableOwner', getBooleanDecoder()],
+    ['padding1', fixDecoderSize(getBytesDecoder(), 7)],
+    ['padding', getArrayDecoder(getU64Decoder(), { size: 6 })],
   ]);
 }
 
@@ -104,18 +104,104 @@ export const AMM_V3_ERROR__MAX_TOKEN_OVERFLOW = 0x179a; // 6042
 export const AMM_V3_ERROR__CALCULATE_OVERFLOW = 0x179b; // 6043
 /** TransferFeeCalculateNotMatch: TransferFee calculate not match */
 export const AMM_V3_ERROR__TRANSFER_FEE_CALCULATE_NOT_MATCH = 0x179c; // 6044
+/** ObservationNotInitialized: Observation state is not initialized */
+export const AMM_V3_ERROR__OBSERVATION_NOT_INITIALIZED = 0x179d; // 6045
+/** ObservationTooOld: Observation target is older than the oldest observation */
+export const AMM_V3_ERROR__OBSERVATION_TOO_OLD = 0x179e; // 6046
+/** InvalidObservationPeriod: Invalid observation period */
+export const AMM_V3_ERROR__INVALID_OBSERVATION_PERIOD = 0x179f; // 6047
+/** InvalidObservationCardinality: Invalid observation cardinality */
+export const AMM_V3_ERROR__INVALID_OBSERVATION_CARDINALITY = 0x17a0; // 6048
+/** TickNotInitialized: Tick is not initialized */
+export const AMM_V3_ERROR__TICK_NOT_INITIALIZED = 0x17a1; // 6049
+/** InvalidRangeOrder: Range order must span one tick spacing on one side of the current price */
+export const AMM_V3_ERROR__INVALID_RANGE_ORDER = 0x17a2; // 6050
+/** RangeOrderPosition: Not allowed on a range order position */
+export const AMM_V3_ERROR__RANGE_ORDER_POSITION = 0x17a3; // 6051
+/** NotRangeOrder: Position is not a range order */
+export const AMM_V3_ERROR__NOT_RANGE_ORDER = 0x17a4; // 6052
+/** FlashLoanDisabled: Flash loans are disabled for this config */
+export const AMM_V3_ERROR__FLASH_LOAN_DISABLED = 0x17a5; // 6053
+/** FlashLoanInProgress: The pool has a flash loan in progress */
+export const AMM_V3_ERROR__FLASH_LOAN_IN_PROGRESS = 0x17a6; // 6054
+/** FlashLoanNotRepaid: The flash loan is not repaid in the same transaction */
+export const AMM_V3_ERROR__FLASH_LOAN_NOT_REPAID = 0x17a7; // 6055
+/** FlashLoanNotBorrowed: The pool has no flash loan to repay */
+export const AMM_V3_ERROR__FLASH_LOAN_NOT_BORROWED = 0x17a8; // 6056
+/** InvalidFlashSwapCallback: Invalid flash swap callback program */
+export const AMM_V3_ERROR__INVALID_FLASH_SWAP_CALLBACK = 0x17a9; // 6057
+/** FlashSwapNotPaid: The flash swap callback did not pay the input amount */
+export const AMM_V3_ERROR__FLASH_SWAP_NOT_PAID = 0x17aa; // 6058
+/** PositionLocked: The position is locked */
+export const AMM_V3_ERROR__POSITION_LOCKED = 0x17ab; // 6059
+/** InvalidLockTime: The lock must end in the future and can only be extended */
+export const AMM_V3_ERROR__INVALID_LOCK_TIME = 0x17ac; // 6060
+/** InvalidMigrateConfig: The new amm config must differ from the current one and have the same tick spacing */
+export const AMM_V3_ERROR__INVALID_MIGRATE_CONFIG = 0x17ad; // 6061
+/** NotOpenYet: The pool is not open for swaps yet */
+export const AMM_V3_ERROR__NOT_OPEN_YET = 0x17ae; // 6062
+/** InvalidLaunchFee: The launch fee must not increase and must be within the caps of the amm config */
+export const AMM_V3_ERROR__INVALID_LAUNCH_FEE = 0x17af; // 6063
+/** InvalidExtensionPolicy: The extension policy of a support mint must allow at least one extension */
+export const AMM_V3_ERROR__INVALID_EXTENSION_POLICY = 0x17b0; // 6064
+/** ConfigUpdateTimelocked: Amm config updates are timelocked, schedule the update instead */
+export const AMM_V3_ERROR__CONFIG_UPDATE_TIMELOCKED = 0x17b1; // 6065
+/** ConfigUpdateNotReady: The scheduled config update can not be executed yet */
+export const AMM_V3_ERROR__CONFIG_UPDATE_NOT_READY = 0x17b2; // 6066
+/** InvalidTwapGuard: The twap guard needs a tick deviation and a known mode when it is enabled */
+export const AMM_V3_ERROR__INVALID_TWAP_GUARD = 0x17b3; // 6067
+/** TwapDeviationExceeded: The swap moves the price too far from the time-weighted average price */
+export const AMM_V3_ERROR__TWAP_DEVIATION_EXCEEDED = 0x17b4; // 6068
+/** InvalidReferralFeeRate: The referral fee rate exceeds the max referral fee rate of the amm config */
+export const AMM_V3_ERROR__INVALID_REFERRAL_FEE_RATE = 0x17b5; // 6069
+/** InvalidReferrerTokenAccount: The referrer token account must hold the input token of the swap */
+export const AMM_V3_ERROR__INVALID_REFERRER_TOKEN_ACCOUNT = 0x17b6; // 6070
+/** SqrtPriceOutOfRange: The pool price is outside of the accepted price range */
+export const AMM_V3_ERROR__SQRT_PRICE_OUT_OF_RANGE = 0x17b7; // 6071
+/** PoolConfigUpdateTimelocked: Pool config changes must be scheduled while amm config updates are timelocked */
+export const AMM_V3_ERROR__POOL_CONFIG_UPDATE_TIMELOCKED = 0x17b8; // 6072
+/** InvalidOpenTime: The pool open time is too far in the future */
+export const AMM_V3_ERROR__INVALID_OPEN_TIME = 0x17b9; // 6073
+/** InvalidFeeOverride: The fee overrides exceed the fee rate denominator */
+export const AMM_V3_ERROR__INVALID_FEE_OVERRIDE = 0x17ba; // 6074
+/** PoolConfigUpdateMismatch: The pool config change does not match the scheduled update */
+export const AMM_V3_ERROR__POOL_CONFIG_UPDATE_MISMATCH = 0x17bb; // 6075
+/** InvalidReferral: The referral fee rate and the referrer token account must be given together */
+export const AMM_V3_ERROR__INVALID_REFERRAL = 0x17bc; // 6076
+/** NotLegacySupportMint: The mint was neither whitelisted nor a Superstate mint */
+export const AMM_V3_ERROR__NOT_LEGACY_SUPPORT_MINT = 0x17bd; // 6077
 
 export type AmmV3Error =
   | typeof AMM_V3_ERROR__ACCOUNT_LACK
   | typeof AMM_V3_ERROR__CALCULATE_OVERFLOW
   | typeof AMM_V3_ERROR__CLOSE_POSITION_ERR
+  | typeof AMM_V3_ERROR__CONFIG_UPDATE_NOT_READY
+  | typeof AMM_V3_ERROR__CONFIG_UPDATE_TIMELOCKED
   | typeof AMM_V3_ERROR__EXCEPT_REWARD_MINT
+  | typeof AMM_V3_ERROR__FLASH_LOAN_DISABLED
+  | typeof AMM_V3_ERROR__FLASH_LOAN_IN_PROGRESS
+  | typeof AMM_V3_ERROR__FLASH_LOAN_NOT_BORROWED
+  | typeof AMM_V3_ERROR__FLASH_LOAN_NOT_REPAID
+  | typeof AMM_V3_ERROR__FLASH_SWAP_NOT_PAID
   | typeof AMM_V3_ERROR__FORBID_BOTH_ZERO_FOR_SUPPLY_LIQUIDITY
   | typeof AMM_V3_ERROR__FULL_REWARD_INFO
   | typeof AMM_V3_ERROR__INSUFFICIENT_LIQUIDITY_FOR_DIRECTION
+  | typeof AMM_V3_ERROR__INVALID_EXTENSION_POLICY
+  | typeof AMM_V3_ERROR__INVALID_FEE_OVERRIDE
   | typeof AMM_V3_ERROR__INVALID_FIRST_TICK_ARRAY_ACCOUNT
+  | typeof AMM_V3_ERROR__INVALID_FLASH_SWAP_CALLBACK
   | typeof AMM_V3_ERROR__INVALID_INPUT_POOL_VAULT
+  | typeof AMM_V3_ERROR__INVALID_LAUNCH_FEE
   | typeof AMM_V3_ERROR__INVALID_LIQUIDITY
+  | typeof AMM_V3_ERROR__INVALID_LOCK_TIME
+  | typeof AMM_V3_ERROR__INVALID_MIGRATE_CONFIG
+  | typeof AMM_V3_ERROR__INVALID_OBSERVATION_CARDINALITY
+  | typeof AMM_V3_ERROR__INVALID_OBSERVATION_PERIOD
+  | typeof AMM_V3_ERROR__INVALID_OPEN_TIME
+  | typeof AMM_V3_ERROR__INVALID_RANGE_ORDER
+  | typeof AMM_V3_ERROR__INVALID_REFERRAL
+  | typeof AMM_V3_ERROR__INVALID_REFERRAL_FEE_RATE
+  | typeof AMM_V3_ERROR__INVALID_REFERRER_TOKEN_ACCOUNT
   | typeof AMM_V3_ERROR__INVALID_REWARD_DESIRED_AMOUNT
   | typeof AMM_V3_ERROR__INVALID_REWARD_INDEX
   | typeof AMM_V3_ERROR__INVALID_REWARD_INIT_PARAM
@@ -124,6 +210,7 @@ export type AmmV3Error =
   | typeof AMM_V3_ERROR__INVALID_TICK_ARRAY
   | typeof AMM_V3_ERROR__INVALID_TICK_ARRAY_BOUNDARY
   | typeof AMM_V3_ERROR__INVALID_TICK_INDEX
+  | typeof AMM_V3_ERROR__INVALID_TWAP_GUARD
   | typeof AMM_V3_ERROR__INVALID_UPDATE_CONFIG_FLAG
   | typeof AMM_V3_ERROR__LIQUIDITY_ADD_VALUE_ERR
   | typeof AMM_V3_ERROR__LIQUIDITY_INSUFFICIENT
@@ -134,20 +221,32 @@ export type AmmV3Error =
   | typeof AMM_V3_ERROR__NOT_APPROVED
   | typeof AMM_V3_ERROR__NOT_APPROVE_UPDATE_REWARD_EMISSIONES
   | typeof AMM_V3_ERROR__NOT_ENOUGH_TICK_ARRAY_ACCOUNT
+  | typeof AMM_V3_ERROR__NOT_LEGACY_SUPPORT_MINT
+  | typeof AMM_V3_ERROR__NOT_OPEN_YET
+  | typeof AMM_V3_ERROR__NOT_RANGE_ORDER
   | typeof AMM_V3_ERROR__NOT_SUPPORT_MINT
+  | typeof AMM_V3_ERROR__OBSERVATION_NOT_INITIALIZED
+  | typeof AMM_V3_ERROR__OBSERVATION_TOO_OLD
+  | typeof AMM_V3_ERROR__POOL_CONFIG_UPDATE_MISMATCH
+  | typeof AMM_V3_ERROR__POOL_CONFIG_UPDATE_TIMELOCKED
+  | typeof AMM_V3_ERROR__POSITION_LOCKED
   | typeof AMM_V3_ERROR__PRICE_SLIPPAGE_CHECK
+  | typeof AMM_V3_ERROR__RANGE_ORDER_POSITION
   | typeof AMM_V3_ERROR__REWARD_TOKEN_ALREADY_IN_USE
   | typeof AMM_V3_ERROR__SQRT_PRICE_LIMIT_OVERFLOW
+  | typeof AMM_V3_ERROR__SQRT_PRICE_OUT_OF_RANGE
   | typeof AMM_V3_ERROR__SQRT_PRICE_X64
   | typeof AMM_V3_ERROR__TICK_AND_SPACING_NOT_MATCH
   | typeof AMM_V3_ERROR__TICK_INVALID_ORDER
   | typeof AMM_V3_ERROR__TICK_LOWER_OVERFLOW
+  | typeof AMM_V3_ERROR__TICK_NOT_INITIALIZED
   | typeof AMM_V3_ERROR__TICK_UPPER_OVERFLOW
   | typeof AMM_V3_ERROR__TOO_LITTLE_OUTPUT_RECEIVED
   | typeof AMM_V3_ERROR__TOO_MUCH_INPUT_PAID
   | typeof AMM_V3_ERROR__TOO_SMALL_INPUT_OR_OUTPUT_AMOUNT
   | typeof AMM_V3_ERROR__TRANSACTION_TOO_OLD
   | typeof AMM_V3_ERROR__TRANSFER_FEE_CALCULATE_NOT_MATCH
+  | typeof AMM_V3_ERROR__TWAP_DEVIATION_EXCEEDED
   | typeof AMM_V3_ERROR__UN_INITIALIZED_REWARD_INFO
   | typeof AMM_V3_ERROR__ZERO_AMOUNT_SPECIFIED
   | typeof AMM_V3_ERROR__ZERO_MINT_AMOUNT;
@@ -158,13 +257,33 @@ if (process.env.NODE_ENV !== 'production') {
     [AMM_V3_ERROR__ACCOUNT_LACK]: `Account lack`,
     [AMM_V3_ERROR__CALCULATE_OVERFLOW]: `Calculate overflow`,
     [AMM_V3_ERROR__CLOSE_POSITION_ERR]: `Remove liquitity, collect fees owed and reward then you can close position account`,
+    [AMM_V3_ERROR__CONFIG_UPDATE_NOT_READY]: `The scheduled config update can not be executed yet`,
+    [AMM_V3_ERROR__CONFIG_UPDATE_TIMELOCKED]: `Amm config updates are timelocked, schedule the update instead`,
     [AMM_V3_ERROR__EXCEPT_REWARD_MINT]: `The reward tokens must contain one of pool vault mint except the last reward`,
+    [AMM_V3_ERROR__FLASH_LOAN_DISABLED]: `Flash loans are disabled for this config`,
+    [AMM_V3_ERROR__FLASH_LOAN_IN_PROGRESS]: `The pool has a flash loan in progress`,
+    [AMM_V3_ERROR__FLASH_LOAN_NOT_BORROWED]: `The pool has no flash loan to repay`,
+    [AMM_V3_ERROR__FLASH_LOAN_NOT_REPAID]: `The flash loan is not repaid in the same transaction`,
+    [AMM_V3_ERROR__FLASH_SWAP_NOT_PAID]: `The flash swap callback did not pay the input amount`,
     [AMM_V3_ERROR__FORBID_BOTH_ZERO_FOR_SUPPLY_LIQUIDITY]: `Both token amount must not be zero while supply liquidity`,
     [AMM_V3_ERROR__FULL_REWARD_INFO]: `The init reward token reach to the max`,
     [AMM_V3_ERROR__INSUFFICIENT_LIQUIDITY_FOR_DIRECTION]: `Insufficient liquidity for this direction`,
+    [AMM_V3_ERROR__INVALID_EXTENSION_POLICY]: `The extension policy of a support mint must allow at least one extension`,
+    [AMM_V3_ERROR__INVALID_FEE_OVERRIDE]: `The fee overrides exceed the fee rate denominator`,
     [AMM_V3_ERROR__INVALID_FIRST_TICK_ARRAY_ACCOUNT]: `Invalid first tick array account`,
+    [AMM_V3_ERROR__INVALID_FLASH_SWAP_CALLBACK]: `Invalid flash swap callback program`,
     [AMM_V3_ERROR__INVALID_INPUT_POOL_VAULT]: `Input pool vault is invalid`,
+    [AMM_V3_ERROR__INVALID_LAUNCH_FEE]: `The launch fee must not increase and must be within the caps of the amm config`,
     [AMM_V3_ERROR__INVALID_LIQUIDITY]: `Invalid liquidity when update position`,
+    [AMM_V3_ERROR__INVALID_LOCK_TIME]: `The lock must end in the future and can only be extended`,
+    [AMM_V3_ERROR__INVALID_MIGRATE_CONFIG]: `The new amm config must differ from the current one and have the same tick spacing`,
+    [AMM_V3_ERROR__INVALID_OBSERVATION_CARDINALITY]: `Invalid observation cardinality`,
+    [AMM_V3_ERROR__INVALID_OBSERVATION_PERIOD]: `Invalid observation period`,
+    [AMM_V3_ERROR__INVALID_OPEN_TIME]: `The pool open time is too far in the future`,
+    [AMM_V3_ERROR__INVALID_RANGE_ORDER]: `Range order must span one tick spacing on one side of the current price`,
+    [AMM_V3_ERROR__INVALID_REFERRAL]: `The referral fee rate and the referrer token account must be given together`,
+    [AMM_V3_ERROR__INVALID_REFERRAL_FEE_RATE]: `The referral fee rate exceeds the max referral fee rate of the amm config`,
+    [AMM_V3_ERROR__INVALID_REFERRER_TOKEN_ACCOUNT]: `The referrer token account must hold the input token of the swap`,
     [AMM_V3_ERROR__INVALID_REWARD_DESIRED_AMOUNT]: `Invalid collect reward desired amount`,
     [AMM_V3_ERROR__INVALID_REWARD_INDEX]: `Invalid reward index`,
     [AMM_V3_ERROR__INVALID_REWARD_INIT_PARAM]: `Invalid reward init param`,
@@ -173,6 +292,7 @@ if (process.env.NODE_ENV !== 'production') {
     [AMM_V3_ERROR__INVALID_TICK_ARRAY]: `Invalid tick array account`,
     [AMM_V3_ERROR__INVALID_TICK_ARRAY_BOUNDARY]: `Invalid tick array boundary`,
     [AMM_V3_ERROR__INVALID_TICK_INDEX]: `Tick out of range`,
+    [AMM_V3_ERROR__INVALID_TWAP_GUARD]: `The twap guard needs a tick deviation and a known mode when it is enabled`,
     [AMM_V3_ERROR__INVALID_UPDATE_CONFIG_FLAG]: `invalid update clmm config flag`,
     [AMM_V3_ERROR__LIQUIDITY_ADD_VALUE_ERR]: `Liquidity add delta L must be greater, or equal to before`,
     [AMM_V3_ERROR__LIQUIDITY_INSUFFICIENT]: `Liquidity insufficient`,
@@ -183,20 +303,32 @@ if (process.env.NODE_ENV !== 'production') {
     [AMM_V3_ERROR__NOT_APPROVED]: `Not approved`,
     [AMM_V3_ERROR__NOT_APPROVE_UPDATE_REWARD_EMISSIONES]: `Modification of emissiones is allowed within 72 hours from the end of the previous cycle`,
     [AMM_V3_ERROR__NOT_ENOUGH_TICK_ARRAY_ACCOUNT]: `Not enought tick array account`,
+    [AMM_V3_ERROR__NOT_LEGACY_SUPPORT_MINT]: `The mint was neither whitelisted nor a Superstate mint`,
+    [AMM_V3_ERROR__NOT_OPEN_YET]: `The pool is not open for swaps yet`,
+    [AMM_V3_ERROR__NOT_RANGE_ORDER]: `Position is not a range order`,
     [AMM_V3_ERROR__NOT_SUPPORT_MINT]: `Not support token_2022 mint extension`,
+    [AMM_V3_ERROR__OBSERVATION_NOT_INITIALIZED]: `Observation state is not initialized`,
+    [AMM_V3_ERROR__OBSERVATION_TOO_OLD]: `Observation target is older than the oldest observation`,
+    [AMM_V3_ERROR__POOL_CONFIG_UPDATE_MISMATCH]: `The pool config change does not match the scheduled update`,
+    [AMM_V3_ERROR__POOL_CONFIG_UPDATE_TIMELOCKED]: `Pool config changes must be scheduled while amm config updates are timelocked`,
+    [AMM_V3_ERROR__POSITION_LOCKED]: `The position is locked`,
     [AMM_V3_ERROR__PRICE_SLIPPAGE_CHECK]: `Price slippage check`,
+    [AMM_V3_ERROR__RANGE_ORDER_POSITION]: `Not allowed on a range order position`,
     [AMM_V3_ERROR__REWARD_TOKEN_ALREADY_IN_USE]: `The init reward token already in use`,
     [AMM_V3_ERROR__SQRT_PRICE_LIMIT_OVERFLOW]: `Square root price limit overflow`,
+    [AMM_V3_ERROR__SQRT_PRICE_OUT_OF_RANGE]: `The pool price is outside of the accepted price range`,
     [AMM_V3_ERROR__SQRT_PRICE_X64]: `sqrt_price_x64 out of range`,
     [AMM_V3_ERROR__TICK_AND_SPACING_NOT_MATCH]: `tick % tick_spacing must be zero`,
     [AMM_V3_ERROR__TICK_INVALID_ORDER]: `The lower tick must be below the upper tick`,
     [AMM_V3_ERROR__TICK_LOWER_OVERFLOW]: `The tick must be greater, or equal to the minimum tick(-443636)`,
+    [AMM_V3_ERROR__TICK_NOT_INITIALIZED]: `Tick is not initialized`,
     [AMM_V3_ERROR__TICK_UPPER_OVERFLOW]: `The tick must be lesser than, or equal to the maximum tick(443636)`,
     [AMM_V3_ERROR__TOO_LITTLE_OUTPUT_RECEIVED]: `Too little output received`,
     [AMM_V3_ERROR__TOO_MUCH_INPUT_PAID]: `Too much input paid`,
     [AMM_V3_ERROR__TOO_SMALL_INPUT_OR_OUTPUT_AMOUNT]: `Swap input or output amount is too small`,
     [AMM_V3_ERROR__TRANSACTION_TOO_OLD]: `Transaction too old`,
     [AMM_V3_ERROR__TRANSFER_FEE_CALCULATE_NOT_MATCH]: `TransferFee calculate not match`,
+    [AMM_V3_ERROR__TWAP_DEVIATION_EXCEEDED]: `The swap moves the price too far from the time-weighted average price`,
     [AMM_V3_ERROR__UN_INITIALIZED_REWARD_INFO]: `uninitialized reward info`,
     [AMM_V3_ERROR__ZERO_AMOUNT_SPECIFIED]: `Swap special amount can not be zero`,
     [AMM_V3_ERROR__ZERO_MINT_AMOUNT]: `Minting amount should be greater than 0`,
@@ -12,6 +12,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -24,6 +25,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -44,9 +46,8 @@ export function getCloseProtocolPositionDiscriminatorBytes() {
 
 export type CloseProtocolPositionInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountProtocolPosition extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
@@ -57,6 +58,9 @@ export type CloseProtocolPositionInstruction<
         ? WritableSignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountProtocolPosition extends string
         ? WritableAccount<TAccountProtocolPosition>
         : TAccountProtocolPosition,
@@ -96,24 +100,112 @@ export function getCloseProtocolPositionInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type CloseProtocolPositionAsyncInput<
+  TAccountAdmin extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountProtocolPosition extends string = string,
+> = {
+  admin: TransactionSigner<TAccountAdmin>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  protocolPosition: Address<TAccountProtocolPosition>;
+};
+
+export async function getCloseProtocolPositionInstructionAsync<
+  TAccountAdmin extends string,
+  TAccountGlobalConfig extends string,
+  TAccountProtocolPosition extends string,
+  TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
+>(
+  input: CloseProtocolPositionAsyncInput<
+    TAccountAdmin,
+    TAccountGlobalConfig,
+    TAccountProtocolPosition
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  CloseProtocolPositionInstruction<
+    TProgramAddress,
+    TAccountAdmin,
+    TAccountGlobalConfig,
+    TAccountProtocolPosition
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? AMM_V3_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    protocolPosition: {
+      value: input.protocolPosition ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.protocolPosition),
+    ],
+    data: getCloseProtocolPositionInstructionDataEncoder().encode({}),
+    programAddress,
+  } as CloseProtocolPositionInstruction<
+    TProgramAddress,
+    TAccountAdmin,
+    TAccountGlobalConfig,
+    TAccountProtocolPosition
+  >);
+}
+
 export type CloseProtocolPositionInput<
   TAccountAdmin extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountProtocolPosition extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   protocolPosition: Address<TAccountProtocolPosition>;
 };
 
 export function getCloseProtocolPositionInstruction<
   TAccountAdmin extends string,
+  TAccountGlobalConfig extends string,
   TAccountProtocolPosition extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
-  input: CloseProtocolPositionInput<TAccountAdmin, TAccountProtocolPosition>,
+  input: CloseProtocolPositionInput<
+    TAccountAdmin,
+    TAccountGlobalConfig,
+    TAccountProtocolPosition
+  >,
   config?: { programAddress?: TProgramAddress }
 ): CloseProtocolPositionInstruction<
   TProgramAddress,
   TAccountAdmin,
+  TAccountGlobalConfig,
   TAccountProtocolPosition
 > {
   // Program address.
@@ -122,6 +214,7 @@ export function getCloseProtocolPositionInstruction<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     protocolPosition: {
       value: input.protocolPosition ?? null,
       isWritable: true,
@@ -132,16 +225,11 @@ export function getCloseProtocolPositionInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.protocolPosition),
     ],
     data: getCloseProtocolPositionInstructionDataEncoder().encode({}),
@@ -149,6 +237,7 @@ export function getCloseProtocolPositionInstruction<
   } as CloseProtocolPositionInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountGlobalConfig,
     TAccountProtocolPosition
   >);
 }
@@ -160,7 +249,9 @@ export type ParsedCloseProtocolPositionInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     admin: TAccountMetas[0];
-    protocolPosition: TAccountMetas[1];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
+    protocolPosition: TAccountMetas[2];
   };
   data: CloseProtocolPositionInstructionData;
 };
@@ -173,7 +264,7 @@ export function parseCloseProtocolPositionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseProtocolPositionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -185,7 +276,11 @@ export function parseCloseProtocolPositionInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { admin: getNextAccount(), protocolPosition: getNextAccount() },
+    accounts: {
+      admin: getNextAccount(),
+      globalConfig: getNextAccount(),
+      protocolPosition: getNextAccount(),
+    },
     data: getCloseProtocolPositionInstructionDataDecoder().decode(
       instruction.data
     ),
@@ -12,6 +12,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -48,6 +49,7 @@ export function getCollectProtocolFeeDiscriminatorBytes() {
 export type CollectProtocolFeeInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
   TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountPoolState extends string | AccountMeta<string> = string,
   TAccountAmmConfig extends string | AccountMeta<string> = string,
   TAccountTokenVault0 extends string | AccountMeta<string> = string,
@@ -71,6 +73,9 @@ export type CollectProtocolFeeInstruction<
         ? ReadonlySignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountPoolState extends string
         ? WritableAccount<TAccountPoolState>
         : TAccountPoolState,
@@ -145,8 +150,192 @@ export function getCollectProtocolFeeInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type CollectProtocolFeeAsyncInput<
+  TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountPoolState extends string = string,
+  TAccountAmmConfig extends string = string,
+  TAccountTokenVault0 extends string = string,
+  TAccountTokenVault1 extends string = string,
+  TAccountVault0Mint extends string = string,
+  TAccountVault1Mint extends string = string,
+  TAccountRecipientTokenAccount0 extends string = string,
+  TAccountRecipientTokenAccount1 extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountTokenProgram2022 extends string = string,
+> = {
+  /** Only admin or config owner can collect fee now */
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** Pool state stores accumulated protocol fee amount */
+  poolState: Address<TAccountPoolState>;
+  /** Amm config account stores owner */
+  ammConfig: Address<TAccountAmmConfig>;
+  /** The address that holds pool tokens for token_0 */
+  tokenVault0: Address<TAccountTokenVault0>;
+  /** The address that holds pool tokens for token_1 */
+  tokenVault1: Address<TAccountTokenVault1>;
+  /** The mint of token vault 0 */
+  vault0Mint: Address<TAccountVault0Mint>;
+  /** The mint of token vault 1 */
+  vault1Mint: Address<TAccountVault1Mint>;
+  /** The address that receives the collected token_0 protocol fees */
+  recipientTokenAccount0: Address<TAccountRecipientTokenAccount0>;
+  /** The address that receives the collected token_1 protocol fees */
+  recipientTokenAccount1: Address<TAccountRecipientTokenAccount1>;
+  /** The SPL program to perform token transfers */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** The SPL program 2022 to perform token transfers */
+  tokenProgram2022?: Address<TAccountTokenProgram2022>;
+  amount0Requested: CollectProtocolFeeInstructionDataArgs['amount0Requested'];
+  amount1Requested: CollectProtocolFeeInstructionDataArgs['amount1Requested'];
+};
+
+export async function getCollectProtocolFeeInstructionAsync<
+  TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
+  TAccountPoolState extends string,
+  TAccountAmmConfig extends string,
+  TAccountTokenVault0 extends string,
+  TAccountTokenVault1 extends string,
+  TAccountVault0Mint extends string,
+  TAccountVault1Mint extends string,
+  TAccountRecipientTokenAccount0 extends string,
+  TAccountRecipientTokenAccount1 extends string,
+  TAccountTokenProgram extends string,
+  TAccountTokenProgram2022 extends string,
+  TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
+>(
+  input: CollectProtocolFeeAsyncInput<
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountPoolState,
+    TAccountAmmConfig,
+    TAccountTokenVault0,
+    TAccountTokenVault1,
+    TAccountVault0Mint,
+    TAccountVault1Mint,
+    TAccountRecipientTokenAccount0,
+    TAccountRecipientTokenAccount1,
+    TAccountTokenProgram,
+    TAccountTokenProgram2022
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  CollectProtocolFeeInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountPoolState,
+    TAccountAmmConfig,
+    TAccountTokenVault0,
+    TAccountTokenVault1,
+    TAccountVault0Mint,
+    TAccountVault1Mint,
+    TAccountRecipientTokenAccount0,
+    TAccountRecipientTokenAccount1,
+    TAccountTokenProgram,
+    TAccountTokenProgram2022
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? AMM_V3_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    poolState: { value: input.poolState ?? null, isWritable: true },
+    ammConfig: { value: input.ammConfig ?? null, isWritable: false },
+    tokenVault0: { value: input.tokenVault0 ?? null, isWritable: true },
+    tokenVault1: { value: input.tokenVault1 ?? null, isWritable: true },
+    vault0Mint: { value: input.vault0Mint ?? null, isWritable: false },
+    vault1Mint: { value: input.vault1Mint ?? null, isWritable: false },
+    recipientTokenAccount0: {
+      value: input.recipientTokenAccount0 ?? null,
+      isWritable: true,
+    },
+    recipientTokenAccount1: {
+      value: input.recipientTokenAccount1 ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    tokenProgram2022: {
+      value: input.tokenProgram2022 ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.tokenProgram2022.value) {
+    accounts.tokenProgram2022.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.poolState),
+      getAccountMeta(accounts.ammConfig),
+      getAccountMeta(accounts.tokenVault0),
+      getAccountMeta(accounts.tokenVault1),
+      getAccountMeta(accounts.vault0Mint),
+      getAccountMeta(accounts.vault1Mint),
+      getAccountMeta(accounts.recipientTokenAccount0),
+      getAccountMeta(accounts.recipientTokenAccount1),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.tokenProgram2022),
+    ],
+    data: getCollectProtocolFeeInstructionDataEncoder().encode(
+      args as CollectProtocolFeeInstructionDataArgs
+    ),
+    programAddress,
+  } as CollectProtocolFeeInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountPoolState,
+    TAccountAmmConfig,
+    TAccountTokenVault0,
+    TAccountTokenVault1,
+    TAccountVault0Mint,
+    TAccountVault1Mint,
+    TAccountRecipientTokenAccount0,
+    TAccountRecipientTokenAccount1,
+    TAccountTokenProgram,
+    TAccountTokenProgram2022
+  >);
+}
+
 export type CollectProtocolFeeInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountPoolState extends string = string,
   TAccountAmmConfig extends string = string,
   TAccountTokenVault0 extends string = string,
@@ -160,6 +349,8 @@ export type CollectProtocolFeeInput<
 > = {
   /** Only admin or config owner can collect fee now */
   owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Pool state stores accumulated protocol fee amount */
   poolState: Address<TAccountPoolState>;
   /** Amm config account stores owner */
@@ -186,6 +377,7 @@ export type CollectProtocolFeeInput<
 
 export function getCollectProtocolFeeInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountPoolState extends string,
   TAccountAmmConfig extends string,
   TAccountTokenVault0 extends string,
@@ -200,6 +392,7 @@ export function getCollectProtocolFeeInstruction<
 >(
   input: CollectProtocolFeeInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountPoolState,
     TAccountAmmConfig,
     TAccountTokenVault0,
@@ -215,6 +408,7 @@ export function getCollectProtocolFeeInstruction<
 ): CollectProtocolFeeInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountPoolState,
   TAccountAmmConfig,
   TAccountTokenVault0,
@@ -232,6 +426,7 @@ export function getCollectProtocolFeeInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
     ammConfig: { value: input.ammConfig ?? null, isWritable: false },
     tokenVault0: { value: input.tokenVault0 ?? null, isWritable: true },
@@ -274,6 +469,7 @@ export function getCollectProtocolFeeInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.poolState),
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.tokenVault0),
@@ -292,6 +488,7 @@ export function getCollectProtocolFeeInstruction<
   } as CollectProtocolFeeInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountPoolState,
     TAccountAmmConfig,
     TAccountTokenVault0,
@@ -313,26 +510,28 @@ export type ParsedCollectProtocolFeeInstruction<
   accounts: {
     /** Only admin or config owner can collect fee now */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Pool state stores accumulated protocol fee amount */
-    poolState: TAccountMetas[1];
+    poolState: TAccountMetas[2];
     /** Amm config account stores owner */
-    ammConfig: TAccountMetas[2];
+    ammConfig: TAccountMetas[3];
     /** The address that holds pool tokens for token_0 */
-    tokenVault0: TAccountMetas[3];
+    tokenVault0: TAccountMetas[4];
     /** The address that holds pool tokens for token_1 */
-    tokenVault1: TAccountMetas[4];
+    tokenVault1: TAccountMetas[5];
     /** The mint of token vault 0 */
-    vault0Mint: TAccountMetas[5];
+    vault0Mint: TAccountMetas[6];
     /** The mint of token vault 1 */
-    vault1Mint: TAccountMetas[6];
+    vault1Mint: TAccountMetas[7];
     /** The address that receives the collected token_0 protocol fees */
-    recipientTokenAccount0: TAccountMetas[7];
+    recipientTokenAccount0: TAccountMetas[8];
     /** The address that receives the collected token_1 protocol fees */
-    recipientTokenAccount1: TAccountMetas[8];
+    recipientTokenAccount1: TAccountMetas[9];
     /** The SPL program to perform token transfers */
-    tokenProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
     /** The SPL program 2022 to perform token transfers */
-    tokenProgram2022: TAccountMetas[10];
+    tokenProgram2022: TAccountMetas[11];
   };
   data: CollectProtocolFeeInstructionData;
 };
@@ -345,7 +544,7 @@ export function parseCollectProtocolFeeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCollectProtocolFeeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -359,6 +558,7 @@ export function parseCollectProtocolFeeInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      globalConfig: getNextAccount(),
       poolState: getNextAccount(),
       ammConfig: getNextAccount(),
       tokenVault0: getNextAccount(),
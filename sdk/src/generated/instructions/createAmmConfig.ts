@@ -54,9 +54,8 @@ export function getCreateAmmConfigDiscriminatorBytes() {
 
 export type CreateAmmConfigInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountOwner extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountAmmConfig extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -70,6 +69,9 @@ export type CreateAmmConfigInstruction<
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountAmmConfig extends string
         ? WritableAccount<TAccountAmmConfig>
         : TAccountAmmConfig,
@@ -134,11 +136,14 @@ export function getCreateAmmConfigInstructionDataCodec(): FixedSizeCodec<
 
 export type CreateAmmConfigAsyncInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountAmmConfig extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as protocol owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Initialize config state account to store protocol owner address and fee rates. */
   ammConfig?: Address<TAccountAmmConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -151,12 +156,14 @@ export type CreateAmmConfigAsyncInput<
 
 export async function getCreateAmmConfigInstructionAsync<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountAmmConfig extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: CreateAmmConfigAsyncInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig,
     TAccountSystemProgram
   >,
@@ -165,6 +172,7 @@ export async function getCreateAmmConfigInstructionAsync<
   CreateAmmConfigInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig,
     TAccountSystemProgram
   >
@@ -175,6 +183,7 @@ export async function getCreateAmmConfigInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     ammConfig: { value: input.ammConfig ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -187,9 +196,17 @@ export async function getCreateAmmConfigInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
   }
   if (!accounts.ammConfig.value) {
     accounts.ammConfig.value = await getProgramDerivedAddress({
@@ -211,6 +228,7 @@ export async function getCreateAmmConfigInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -221,6 +239,7 @@ export async function getCreateAmmConfigInstructionAsync<
   } as CreateAmmConfigInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig,
     TAccountSystemProgram
   >);
@@ -228,11 +247,14 @@ export async function getCreateAmmConfigInstructionAsync<
 
 export type CreateAmmConfigInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountAmmConfig extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as protocol owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Initialize config state account to store protocol owner address and fee rates. */
   ammConfig: Address<TAccountAmmConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -245,12 +267,14 @@ export type CreateAmmConfigInput<
 
 export function getCreateAmmConfigInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountAmmConfig extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: CreateAmmConfigInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig,
     TAccountSystemProgram
   >,
@@ -258,6 +282,7 @@ export function getCreateAmmConfigInstruction<
 ): CreateAmmConfigInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountAmmConfig,
   TAccountSystemProgram
 > {
@@ -267,6 +292,7 @@ export function getCreateAmmConfigInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     ammConfig: { value: input.ammConfig ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -279,10 +305,6 @@ export function getCreateAmmConfigInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -292,6 +314,7 @@ export function getCreateAmmConfigInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -302,6 +325,7 @@ export function getCreateAmmConfigInstruction<
   } as CreateAmmConfigInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig,
     TAccountSystemProgram
   >);
@@ -315,9 +339,11 @@ export type ParsedCreateAmmConfigInstruction<
   accounts: {
     /** Address to be set as protocol owner. */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Initialize config state account to store protocol owner address and fee rates. */
-    ammConfig: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    ammConfig: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: CreateAmmConfigInstructionData;
 };
@@ -330,7 +356,7 @@ export function parseCreateAmmConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateAmmConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -344,6 +370,7 @@ export function parseCreateAmmConfigInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      globalConfig: getNextAccount(),
       ammConfig: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -46,9 +46,8 @@ export function getCreateOperationAccountDiscriminatorBytes() {
 
 export type CreateOperationAccountInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountOwner extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountOperationState extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -62,6 +61,9 @@ export type CreateOperationAccountInstruction<
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountOperationState extends string
         ? WritableAccount<TAccountOperationState>
         : TAccountOperationState,
@@ -106,11 +108,14 @@ export function getCreateOperationAccountInstructionDataCodec(): FixedSizeCodec<
 
 export type CreateOperationAccountAsyncInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountOperationState extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as operation account owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Initialize operation state account to store operation owner address and white list mint. */
   operationState?: Address<TAccountOperationState>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -118,12 +123,14 @@ export type CreateOperationAccountAsyncInput<
 
 export async function getCreateOperationAccountInstructionAsync<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountOperationState extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: CreateOperationAccountAsyncInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >,
@@ -132,6 +139,7 @@ export async function getCreateOperationAccountInstructionAsync<
   CreateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >
@@ -142,6 +150,7 @@ export async function getCreateOperationAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     operationState: { value: input.operationState ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -151,9 +160,17 @@ export async function getCreateOperationAccountInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
   }
   if (!accounts.operationState.value) {
     accounts.operationState.value = await getProgramDerivedAddress({
@@ -174,6 +191,7 @@ export async function getCreateOperationAccountInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.operationState),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -182,6 +200,7 @@ export async function getCreateOperationAccountInstructionAsync<
   } as CreateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >);
@@ -189,11 +208,14 @@ export async function getCreateOperationAccountInstructionAsync<
 
 export type CreateOperationAccountInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountOperationState extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as operation account owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Initialize operation state account to store operation owner address and white list mint. */
   operationState: Address<TAccountOperationState>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -201,12 +223,14 @@ export type CreateOperationAccountInput<
 
 export function getCreateOperationAccountInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountOperationState extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: CreateOperationAccountInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >,
@@ -214,6 +238,7 @@ export function getCreateOperationAccountInstruction<
 ): CreateOperationAccountInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountOperationState,
   TAccountSystemProgram
 > {
@@ -223,6 +248,7 @@ export function getCreateOperationAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     operationState: { value: input.operationState ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -232,10 +258,6 @@ export function getCreateOperationAccountInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -245,6 +267,7 @@ export function getCreateOperationAccountInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.operationState),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -253,6 +276,7 @@ export function getCreateOperationAccountInstruction<
   } as CreateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >);
@@ -266,9 +290,11 @@ export type ParsedCreateOperationAccountInstruction<
   accounts: {
     /** Address to be set as operation account owner. */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Initialize operation state account to store operation owner address and white list mint. */
-    operationState: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    operationState: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: CreateOperationAccountInstructionData;
 };
@@ -281,7 +307,7 @@ export function parseCreateOperationAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateOperationAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -295,6 +321,7 @@ export function parseCreateOperationAccountInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      globalConfig: getNextAccount(),
       operationState: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -11,11 +11,15 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -52,6 +56,7 @@ export function getCreateSupportMintAssociatedDiscriminatorBytes() {
 export type CreateSupportMintAssociatedInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
   TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountSupportMintAssociated extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
@@ -66,6 +71,9 @@ export type CreateSupportMintAssociatedInstruction<
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountTokenMint extends string
         ? ReadonlyAccount<TAccountTokenMint>
         : TAccountTokenMint,
@@ -81,13 +89,22 @@ export type CreateSupportMintAssociatedInstruction<
 
 export type CreateSupportMintAssociatedInstructionData = {
   discriminator: ReadonlyUint8Array;
+  allowedExtensions: bigint;
+  vaultImmutableOwner: boolean;
 };
 
-export type CreateSupportMintAssociatedInstructionDataArgs = {};
+export type CreateSupportMintAssociatedInstructionDataArgs = {
+  allowedExtensions: number | bigint;
+  vaultImmutableOwner: boolean;
+};
 
 export function getCreateSupportMintAssociatedInstructionDataEncoder(): FixedSizeEncoder<CreateSupportMintAssociatedInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['allowedExtensions', getU64Encoder()],
+      ['vaultImmutableOwner', getBooleanEncoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: CREATE_SUPPORT_MINT_ASSOCIATED_DISCRIMINATOR,
@@ -98,6 +115,8 @@ export function getCreateSupportMintAssociatedInstructionDataEncoder(): FixedSiz
 export function getCreateSupportMintAssociatedInstructionDataDecoder(): FixedSizeDecoder<CreateSupportMintAssociatedInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['allowedExtensions', getU64Decoder()],
+    ['vaultImmutableOwner', getBooleanDecoder()],
   ]);
 }
 
@@ -113,21 +132,27 @@ export function getCreateSupportMintAssociatedInstructionDataCodec(): FixedSizeC
 
 export type CreateSupportMintAssociatedAsyncInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSupportMintAssociated extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as protocol owner. */
   owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Support token mint */
   tokenMint: Address<TAccountTokenMint>;
   /** Initialize support mint state account to store support mint address and bump. */
   supportMintAssociated?: Address<TAccountSupportMintAssociated>;
   systemProgram?: Address<TAccountSystemProgram>;
+  allowedExtensions: CreateSupportMintAssociatedInstructionDataArgs['allowedExtensions'];
+  vaultImmutableOwner: CreateSupportMintAssociatedInstructionDataArgs['vaultImmutableOwner'];
 };
 
 export async function getCreateSupportMintAssociatedInstructionAsync<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountTokenMint extends string,
   TAccountSupportMintAssociated extends string,
   TAccountSystemProgram extends string,
@@ -135,6 +160,7 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
 >(
   input: CreateSupportMintAssociatedAsyncInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountTokenMint,
     TAccountSupportMintAssociated,
     TAccountSystemProgram
@@ -144,6 +170,7 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
   CreateSupportMintAssociatedInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountTokenMint,
     TAccountSupportMintAssociated,
     TAccountSystemProgram
@@ -155,6 +182,7 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     supportMintAssociated: {
       value: input.supportMintAssociated ?? null,
@@ -167,7 +195,22 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.supportMintAssociated.value) {
     accounts.supportMintAssociated.value = await getProgramDerivedAddress({
       programAddress,
@@ -190,15 +233,19 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.supportMintAssociated),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getCreateSupportMintAssociatedInstructionDataEncoder().encode({}),
+    data: getCreateSupportMintAssociatedInstructionDataEncoder().encode(
+      args as CreateSupportMintAssociatedInstructionDataArgs
+    ),
     programAddress,
   } as CreateSupportMintAssociatedInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountTokenMint,
     TAccountSupportMintAssociated,
     TAccountSystemProgram
@@ -207,21 +254,27 @@ export async function getCreateSupportMintAssociatedInstructionAsync<
 
 export type CreateSupportMintAssociatedInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSupportMintAssociated extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as protocol owner. */
   owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Support token mint */
   tokenMint: Address<TAccountTokenMint>;
   /** Initialize support mint state account to store support mint address and bump. */
   supportMintAssociated: Address<TAccountSupportMintAssociated>;
   systemProgram?: Address<TAccountSystemProgram>;
+  allowedExtensions: CreateSupportMintAssociatedInstructionDataArgs['allowedExtensions'];
+  vaultImmutableOwner: CreateSupportMintAssociatedInstructionDataArgs['vaultImmutableOwner'];
 };
 
 export function getCreateSupportMintAssociatedInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountTokenMint extends string,
   TAccountSupportMintAssociated extends string,
   TAccountSystemProgram extends string,
@@ -229,6 +282,7 @@ export function getCreateSupportMintAssociatedInstruction<
 >(
   input: CreateSupportMintAssociatedInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountTokenMint,
     TAccountSupportMintAssociated,
     TAccountSystemProgram
@@ -237,6 +291,7 @@ export function getCreateSupportMintAssociatedInstruction<
 ): CreateSupportMintAssociatedInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountTokenMint,
   TAccountSupportMintAssociated,
   TAccountSystemProgram
@@ -247,6 +302,7 @@ export function getCreateSupportMintAssociatedInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     supportMintAssociated: {
       value: input.supportMintAssociated ?? null,
@@ -259,6 +315,9 @@ export function getCreateSupportMintAssociatedInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -269,15 +328,19 @@ export function getCreateSupportMintAssociatedInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.supportMintAssociated),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getCreateSupportMintAssociatedInstructionDataEncoder().encode({}),
+    data: getCreateSupportMintAssociatedInstructionDataEncoder().encode(
+      args as CreateSupportMintAssociatedInstructionDataArgs
+    ),
     programAddress,
   } as CreateSupportMintAssociatedInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountTokenMint,
     TAccountSupportMintAssociated,
     TAccountSystemProgram
@@ -292,11 +355,13 @@ export type ParsedCreateSupportMintAssociatedInstruction<
   accounts: {
     /** Address to be set as protocol owner. */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Support token mint */
-    tokenMint: TAccountMetas[1];
+    tokenMint: TAccountMetas[2];
     /** Initialize support mint state account to store support mint address and bump. */
-    supportMintAssociated: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
+    supportMintAssociated: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
   };
   data: CreateSupportMintAssociatedInstructionData;
 };
@@ -309,7 +374,7 @@ export function parseCreateSupportMintAssociatedInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateSupportMintAssociatedInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -323,6 +388,7 @@ export function parseCreateSupportMintAssociatedInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      globalConfig: getNextAccount(),
       tokenMint: getNextAccount(),
       supportMintAssociated: getNextAccount(),
       systemProgram: getNextAccount(),
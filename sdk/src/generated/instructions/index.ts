@@ -6,24 +6,64 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAdmin';
+export * from './acceptAmmConfigOwner';
+export * from './cancelAmmConfigUpdate';
+export * from './cancelPoolConfigUpdate';
+export * from './claimRangeOrder';
 export * from './closePosition';
 export * from './closeProtocolPosition';
+export * from './collectFeesAndRewards';
 export * from './collectFundFee';
 export * from './collectProtocolFee';
 export * from './collectRemainingRewards';
+export * from './compoundPosition';
 export * from './createAmmConfig';
+export * from './createGlobalConfig';
 export * from './createOperationAccount';
 export * from './createPool';
+export * from './createPoolWithLaunchFee';
 export * from './createSupportMintAssociated';
 export * from './decreaseLiquidityV2';
+export * from './decreaseLiquidityV2WithOptions';
+export * from './executeAmmConfigUpdate';
+export * from './flashLoanBorrow';
+export * from './flashLoanRepay';
+export * from './flashSwap';
 export * from './increaseLiquidityV2';
+export * from './increaseLiquidityV2WithOptions';
+export * from './increaseObservationCardinality';
 export * from './initializeReward';
+export * from './lockPosition';
+export * from './migratePoolConfig';
+export * from './migrateSupportMintAssociated';
+export * from './observe';
 export * from './openPositionWithToken22Nft';
+export * from './openPositionWithToken22NftWithOptions';
+export * from './pauseAmmConfig';
+export * from './pausePool';
+export * from './placeRangeOrder';
+export * from './proposeAdmin';
+export * from './proposeAmmConfigOwner';
+export * from './scheduleAmmConfigUpdate';
+export * from './schedulePoolConfigMigration';
+export * from './schedulePoolFeeOverrides';
+export * from './setConfigUpdateDelay';
+export * from './setPauseGuardian';
+export * from './setPoolFeeOverrides';
 export * from './setRewardParams';
+export * from './setTwapGuard';
+export * from './snapshotCumulativesInside';
 export * from './swapRouterBaseIn';
+export * from './swapRouterBaseInWithOptions';
+export * from './swapRouterBaseOut';
+export * from './swapRouterBaseOutWithOptions';
 export * from './swapV2';
+export * from './swapV2WithOptions';
 export * from './transferRewardOwner';
 export * from './updateAmmConfig';
+export * from './updateAmmConfigStatus';
 export * from './updateOperationAccount';
 export * from './updatePoolStatus';
 export * from './updateRewardInfos';
+export * from './updateSupportMintAssociated';
@@ -60,6 +60,7 @@ export type InitializeRewardInstruction<
   TAccountAmmConfig extends string | AccountMeta<string> = string,
   TAccountPoolState extends string | AccountMeta<string> = string,
   TAccountOperationState extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardTokenVault extends string | AccountMeta<string> = string,
   TAccountRewardTokenProgram extends string | AccountMeta<string> = string,
@@ -90,6 +91,9 @@ export type InitializeRewardInstruction<
       TAccountOperationState extends string
         ? ReadonlyAccount<TAccountOperationState>
         : TAccountOperationState,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountRewardTokenMint extends string
         ? ReadonlyAccount<TAccountRewardTokenMint>
         : TAccountRewardTokenMint,
@@ -165,6 +169,7 @@ export type InitializeRewardAsyncInput<
   TAccountAmmConfig extends string = string,
   TAccountPoolState extends string = string,
   TAccountOperationState extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenVault extends string = string,
   TAccountRewardTokenProgram extends string = string,
@@ -180,6 +185,8 @@ export type InitializeRewardAsyncInput<
   poolState: Address<TAccountPoolState>;
   /** load info from the account to judge reward permission */
   operationState?: Address<TAccountOperationState>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Reward mint */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   rewardTokenVault?: Address<TAccountRewardTokenVault>;
@@ -197,6 +204,7 @@ export async function getInitializeRewardInstructionAsync<
   TAccountAmmConfig extends string,
   TAccountPoolState extends string,
   TAccountOperationState extends string,
+  TAccountGlobalConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountRewardTokenVault extends string,
   TAccountRewardTokenProgram extends string,
@@ -210,6 +218,7 @@ export async function getInitializeRewardInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountRewardTokenMint,
     TAccountRewardTokenVault,
     TAccountRewardTokenProgram,
@@ -225,6 +234,7 @@ export async function getInitializeRewardInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountRewardTokenMint,
     TAccountRewardTokenVault,
     TAccountRewardTokenProgram,
@@ -245,6 +255,7 @@ export async function getInitializeRewardInstructionAsync<
     ammConfig: { value: input.ammConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
     operationState: { value: input.operationState ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     rewardTokenMint: {
       value: input.rewardTokenMint ?? null,
       isWritable: false,
@@ -279,6 +290,18 @@ export async function getInitializeRewardInstructionAsync<
       ],
     });
   }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.rewardTokenVault.value) {
     accounts.rewardTokenVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -313,6 +336,7 @@ export async function getInitializeRewardInstructionAsync<
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.poolState),
       getAccountMeta(accounts.operationState),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.rewardTokenVault),
       getAccountMeta(accounts.rewardTokenProgram),
@@ -330,6 +354,7 @@ export async function getInitializeRewardInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountRewardTokenMint,
     TAccountRewardTokenVault,
     TAccountRewardTokenProgram,
@@ -344,6 +369,7 @@ export type InitializeRewardInput<
   TAccountAmmConfig extends string = string,
   TAccountPoolState extends string = string,
   TAccountOperationState extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenVault extends string = string,
   TAccountRewardTokenProgram extends string = string,
@@ -359,6 +385,8 @@ export type InitializeRewardInput<
   poolState: Address<TAccountPoolState>;
   /** load info from the account to judge reward permission */
   operationState: Address<TAccountOperationState>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Reward mint */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   rewardTokenVault: Address<TAccountRewardTokenVault>;
@@ -376,6 +404,7 @@ export function getInitializeRewardInstruction<
   TAccountAmmConfig extends string,
   TAccountPoolState extends string,
   TAccountOperationState extends string,
+  TAccountGlobalConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountRewardTokenVault extends string,
   TAccountRewardTokenProgram extends string,
@@ -389,6 +418,7 @@ export function getInitializeRewardInstruction<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountRewardTokenMint,
     TAccountRewardTokenVault,
     TAccountRewardTokenProgram,
@@ -403,6 +433,7 @@ export function getInitializeRewardInstruction<
   TAccountAmmConfig,
   TAccountPoolState,
   TAccountOperationState,
+  TAccountGlobalConfig,
   TAccountRewardTokenMint,
   TAccountRewardTokenVault,
   TAccountRewardTokenProgram,
@@ -422,6 +453,7 @@ export function getInitializeRewardInstruction<
     ammConfig: { value: input.ammConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
     operationState: { value: input.operationState ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     rewardTokenMint: {
       value: input.rewardTokenMint ?? null,
       isWritable: false,
@@ -463,6 +495,7 @@ export function getInitializeRewardInstruction<
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.poolState),
       getAccountMeta(accounts.operationState),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.rewardTokenVault),
       getAccountMeta(accounts.rewardTokenProgram),
@@ -480,6 +513,7 @@ export function getInitializeRewardInstruction<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountRewardTokenMint,
     TAccountRewardTokenVault,
     TAccountRewardTokenProgram,
@@ -503,12 +537,14 @@ export type ParsedInitializeRewardInstruction<
     poolState: TAccountMetas[3];
     /** load info from the account to judge reward permission */
     operationState: TAccountMetas[4];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[5];
     /** Reward mint */
-    rewardTokenMint: TAccountMetas[5];
-    rewardTokenVault: TAccountMetas[6];
-    rewardTokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
-    rent: TAccountMetas[9];
+    rewardTokenMint: TAccountMetas[6];
+    rewardTokenVault: TAccountMetas[7];
+    rewardTokenProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+    rent: TAccountMetas[10];
   };
   data: InitializeRewardInstructionData;
 };
@@ -521,7 +557,7 @@ export function parseInitializeRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -539,6 +575,7 @@ export function parseInitializeRewardInstruction<
       ammConfig: getNextAccount(),
       poolState: getNextAccount(),
       operationState: getNextAccount(),
+      globalConfig: getNextAccount(),
       rewardTokenMint: getNextAccount(),
       rewardTokenVault: getNextAccount(),
       rewardTokenProgram: getNextAccount(),
@@ -56,6 +56,7 @@ export type SetRewardParamsInstruction<
   TAccountAmmConfig extends string | AccountMeta<string> = string,
   TAccountPoolState extends string | AccountMeta<string> = string,
   TAccountOperationState extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -80,6 +81,9 @@ export type SetRewardParamsInstruction<
       TAccountOperationState extends string
         ? ReadonlyAccount<TAccountOperationState>
         : TAccountOperationState,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -143,6 +147,7 @@ export type SetRewardParamsAsyncInput<
   TAccountAmmConfig extends string = string,
   TAccountPoolState extends string = string,
   TAccountOperationState extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountTokenProgram2022 extends string = string,
 > = {
@@ -152,6 +157,8 @@ export type SetRewardParamsAsyncInput<
   poolState: Address<TAccountPoolState>;
   /** load info from the account to judge reward permission */
   operationState?: Address<TAccountOperationState>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Token program */
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Token program 2022 */
@@ -167,6 +174,7 @@ export async function getSetRewardParamsInstructionAsync<
   TAccountAmmConfig extends string,
   TAccountPoolState extends string,
   TAccountOperationState extends string,
+  TAccountGlobalConfig extends string,
   TAccountTokenProgram extends string,
   TAccountTokenProgram2022 extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
@@ -176,6 +184,7 @@ export async function getSetRewardParamsInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountTokenProgram,
     TAccountTokenProgram2022
   >,
@@ -187,6 +196,7 @@ export async function getSetRewardParamsInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountTokenProgram,
     TAccountTokenProgram2022
   >
@@ -200,6 +210,7 @@ export async function getSetRewardParamsInstructionAsync<
     ammConfig: { value: input.ammConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
     operationState: { value: input.operationState ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     tokenProgram2022: {
       value: input.tokenProgram2022 ?? null,
@@ -225,6 +236,18 @@ export async function getSetRewardParamsInstructionAsync<
       ],
     });
   }
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -241,6 +264,7 @@ export async function getSetRewardParamsInstructionAsync<
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.poolState),
       getAccountMeta(accounts.operationState),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.tokenProgram2022),
     ],
@@ -254,6 +278,7 @@ export async function getSetRewardParamsInstructionAsync<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountTokenProgram,
     TAccountTokenProgram2022
   >);
@@ -264,6 +289,7 @@ export type SetRewardParamsInput<
   TAccountAmmConfig extends string = string,
   TAccountPoolState extends string = string,
   TAccountOperationState extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountTokenProgram2022 extends string = string,
 > = {
@@ -273,6 +299,8 @@ export type SetRewardParamsInput<
   poolState: Address<TAccountPoolState>;
   /** load info from the account to judge reward permission */
   operationState: Address<TAccountOperationState>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Token program */
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Token program 2022 */
@@ -288,6 +316,7 @@ export function getSetRewardParamsInstruction<
   TAccountAmmConfig extends string,
   TAccountPoolState extends string,
   TAccountOperationState extends string,
+  TAccountGlobalConfig extends string,
   TAccountTokenProgram extends string,
   TAccountTokenProgram2022 extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
@@ -297,6 +326,7 @@ export function getSetRewardParamsInstruction<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountTokenProgram,
     TAccountTokenProgram2022
   >,
@@ -307,6 +337,7 @@ export function getSetRewardParamsInstruction<
   TAccountAmmConfig,
   TAccountPoolState,
   TAccountOperationState,
+  TAccountGlobalConfig,
   TAccountTokenProgram,
   TAccountTokenProgram2022
 > {
@@ -319,6 +350,7 @@ export function getSetRewardParamsInstruction<
     ammConfig: { value: input.ammConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
     operationState: { value: input.operationState ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     tokenProgram2022: {
       value: input.tokenProgram2022 ?? null,
@@ -350,6 +382,7 @@ export function getSetRewardParamsInstruction<
       getAccountMeta(accounts.ammConfig),
       getAccountMeta(accounts.poolState),
       getAccountMeta(accounts.operationState),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.tokenProgram2022),
     ],
@@ -363,6 +396,7 @@ export function getSetRewardParamsInstruction<
     TAccountAmmConfig,
     TAccountPoolState,
     TAccountOperationState,
+    TAccountGlobalConfig,
     TAccountTokenProgram,
     TAccountTokenProgram2022
   >);
@@ -380,10 +414,12 @@ export type ParsedSetRewardParamsInstruction<
     poolState: TAccountMetas[2];
     /** load info from the account to judge reward permission */
     operationState: TAccountMetas[3];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[4];
     /** Token program */
-    tokenProgram: TAccountMetas[4];
+    tokenProgram: TAccountMetas[5];
     /** Token program 2022 */
-    tokenProgram2022: TAccountMetas[5];
+    tokenProgram2022: TAccountMetas[6];
   };
   data: SetRewardParamsInstructionData;
 };
@@ -396,7 +432,7 @@ export function parseSetRewardParamsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetRewardParamsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -413,6 +449,7 @@ export function parseSetRewardParamsInstruction<
       ammConfig: getNextAccount(),
       poolState: getNextAccount(),
       operationState: getNextAccount(),
+      globalConfig: getNextAccount(),
       tokenProgram: getNextAccount(),
       tokenProgram2022: getNextAccount(),
     },
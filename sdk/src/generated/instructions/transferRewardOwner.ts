@@ -14,6 +14,7 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -26,6 +27,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -46,9 +48,8 @@ export function getTransferRewardOwnerDiscriminatorBytes() {
 
 export type TransferRewardOwnerInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountAuthority extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountPoolState extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
@@ -59,6 +60,9 @@ export type TransferRewardOwnerInstruction<
         ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountPoolState extends string
         ? WritableAccount<TAccountPoolState>
         : TAccountPoolState,
@@ -103,26 +107,118 @@ export function getTransferRewardOwnerInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type TransferRewardOwnerAsyncInput<
+  TAccountAuthority extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountPoolState extends string = string,
+> = {
+  /** Address to be set as operation account owner. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  poolState: Address<TAccountPoolState>;
+  newOwner: TransferRewardOwnerInstructionDataArgs['newOwner'];
+};
+
+export async function getTransferRewardOwnerInstructionAsync<
+  TAccountAuthority extends string,
+  TAccountGlobalConfig extends string,
+  TAccountPoolState extends string,
+  TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
+>(
+  input: TransferRewardOwnerAsyncInput<
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  TransferRewardOwnerInstruction<
+    TProgramAddress,
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? AMM_V3_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    authority: { value: input.authority ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    poolState: { value: input.poolState ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.poolState),
+    ],
+    data: getTransferRewardOwnerInstructionDataEncoder().encode(
+      args as TransferRewardOwnerInstructionDataArgs
+    ),
+    programAddress,
+  } as TransferRewardOwnerInstruction<
+    TProgramAddress,
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >);
+}
+
 export type TransferRewardOwnerInput<
   TAccountAuthority extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountPoolState extends string = string,
 > = {
   /** Address to be set as operation account owner. */
-  authority?: TransactionSigner<TAccountAuthority>;
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   poolState: Address<TAccountPoolState>;
   newOwner: TransferRewardOwnerInstructionDataArgs['newOwner'];
 };
 
 export function getTransferRewardOwnerInstruction<
   TAccountAuthority extends string,
+  TAccountGlobalConfig extends string,
   TAccountPoolState extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
-  input: TransferRewardOwnerInput<TAccountAuthority, TAccountPoolState>,
+  input: TransferRewardOwnerInput<
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >,
   config?: { programAddress?: TProgramAddress }
 ): TransferRewardOwnerInstruction<
   TProgramAddress,
   TAccountAuthority,
+  TAccountGlobalConfig,
   TAccountPoolState
 > {
   // Program address.
@@ -131,6 +227,7 @@ export function getTransferRewardOwnerInstruction<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
@@ -141,16 +238,11 @@ export function getTransferRewardOwnerInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.authority.value) {
-    accounts.authority.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.poolState),
     ],
     data: getTransferRewardOwnerInstructionDataEncoder().encode(
@@ -160,6 +252,7 @@ export function getTransferRewardOwnerInstruction<
   } as TransferRewardOwnerInstruction<
     TProgramAddress,
     TAccountAuthority,
+    TAccountGlobalConfig,
     TAccountPoolState
   >);
 }
@@ -172,7 +265,9 @@ export type ParsedTransferRewardOwnerInstruction<
   accounts: {
     /** Address to be set as operation account owner. */
     authority: TAccountMetas[0];
-    poolState: TAccountMetas[1];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
+    poolState: TAccountMetas[2];
   };
   data: TransferRewardOwnerInstructionData;
 };
@@ -185,7 +280,7 @@ export function parseTransferRewardOwnerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransferRewardOwnerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -197,7 +292,11 @@ export function parseTransferRewardOwnerInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { authority: getNextAccount(), poolState: getNextAccount() },
+    accounts: {
+      authority: getNextAccount(),
+      globalConfig: getNextAccount(),
+      poolState: getNextAccount(),
+    },
     data: getTransferRewardOwnerInstructionDataDecoder().decode(
       instruction.data
     ),
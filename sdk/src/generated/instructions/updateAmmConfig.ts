@@ -12,6 +12,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -28,6 +29,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -48,9 +50,8 @@ export function getUpdateAmmConfigDiscriminatorBytes() {
 
 export type UpdateAmmConfigInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountOwner extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountAmmConfig extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
@@ -61,6 +62,9 @@ export type UpdateAmmConfigInstruction<
         ? ReadonlySignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountAmmConfig extends string
         ? WritableAccount<TAccountAmmConfig>
         : TAccountAmmConfig,
@@ -108,12 +112,100 @@ export function getUpdateAmmConfigInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type UpdateAmmConfigAsyncInput<
+  TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountAmmConfig extends string = string,
+> = {
+  /** The admin */
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  /** Amm config account to be changed */
+  ammConfig: Address<TAccountAmmConfig>;
+  param: UpdateAmmConfigInstructionDataArgs['param'];
+  value: UpdateAmmConfigInstructionDataArgs['value'];
+};
+
+export async function getUpdateAmmConfigInstructionAsync<
+  TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
+  TAccountAmmConfig extends string,
+  TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
+>(
+  input: UpdateAmmConfigAsyncInput<
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountAmmConfig
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  UpdateAmmConfigInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountAmmConfig
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? AMM_V3_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    ammConfig: { value: input.ammConfig ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.ammConfig),
+    ],
+    data: getUpdateAmmConfigInstructionDataEncoder().encode(
+      args as UpdateAmmConfigInstructionDataArgs
+    ),
+    programAddress,
+  } as UpdateAmmConfigInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountAmmConfig
+  >);
+}
+
 export type UpdateAmmConfigInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountAmmConfig extends string = string,
 > = {
-  /** The clmm config owner or admin */
-  owner?: TransactionSigner<TAccountOwner>;
+  /** The admin */
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Amm config account to be changed */
   ammConfig: Address<TAccountAmmConfig>;
   param: UpdateAmmConfigInstructionDataArgs['param'];
@@ -122,14 +214,20 @@ export type UpdateAmmConfigInput<
 
 export function getUpdateAmmConfigInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountAmmConfig extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
-  input: UpdateAmmConfigInput<TAccountOwner, TAccountAmmConfig>,
+  input: UpdateAmmConfigInput<
+    TAccountOwner,
+    TAccountGlobalConfig,
+    TAccountAmmConfig
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateAmmConfigInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountAmmConfig
 > {
   // Program address.
@@ -138,6 +236,7 @@ export function getUpdateAmmConfigInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     ammConfig: { value: input.ammConfig ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
@@ -148,16 +247,11 @@ export function getUpdateAmmConfigInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.ammConfig),
     ],
     data: getUpdateAmmConfigInstructionDataEncoder().encode(
@@ -167,6 +261,7 @@ export function getUpdateAmmConfigInstruction<
   } as UpdateAmmConfigInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountAmmConfig
   >);
 }
@@ -177,10 +272,12 @@ export type ParsedUpdateAmmConfigInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The clmm config owner or admin */
+    /** The admin */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Amm config account to be changed */
-    ammConfig: TAccountMetas[1];
+    ammConfig: TAccountMetas[2];
   };
   data: UpdateAmmConfigInstructionData;
 };
@@ -193,7 +290,7 @@ export function parseUpdateAmmConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateAmmConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -205,7 +302,11 @@ export function parseUpdateAmmConfigInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { owner: getNextAccount(), ammConfig: getNextAccount() },
+    accounts: {
+      owner: getNextAccount(),
+      globalConfig: getNextAccount(),
+      ammConfig: getNextAccount(),
+    },
     data: getUpdateAmmConfigInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -52,9 +52,8 @@ export function getUpdateOperationAccountDiscriminatorBytes() {
 
 export type UpdateOperationAccountInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountOwner extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountOperationState extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -68,6 +67,9 @@ export type UpdateOperationAccountInstruction<
         ? ReadonlySignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountOperationState extends string
         ? WritableAccount<TAccountOperationState>
         : TAccountOperationState,
@@ -123,11 +125,14 @@ export function getUpdateOperationAccountInstructionDataCodec(): Codec<
 
 export type UpdateOperationAccountAsyncInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountOperationState extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as operation account owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
   /** Initialize operation state account to store operation owner address and white list mint. */
   operationState?: Address<TAccountOperationState>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -137,12 +142,14 @@ export type UpdateOperationAccountAsyncInput<
 
 export async function getUpdateOperationAccountInstructionAsync<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountOperationState extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: UpdateOperationAccountAsyncInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >,
@@ -151,6 +158,7 @@ export async function getUpdateOperationAccountInstructionAsync<
   UpdateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >
@@ -161,6 +169,7 @@ export async function getUpdateOperationAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     operationState: { value: input.operationState ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -173,9 +182,17 @@ export async function getUpdateOperationAccountInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
   }
   if (!accounts.operationState.value) {
     accounts.operationState.value = await getProgramDerivedAddress({
@@ -196,6 +213,7 @@ export async function getUpdateOperationAccountInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.operationState),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -206,6 +224,7 @@ export async function getUpdateOperationAccountInstructionAsync<
   } as UpdateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >);
@@ -213,11 +232,14 @@ export async function getUpdateOperationAccountInstructionAsync<
 
 export type UpdateOperationAccountInput<
   TAccountOwner extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountOperationState extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Address to be set as operation account owner. */
-  owner?: TransactionSigner<TAccountOwner>;
+  owner: TransactionSigner<TAccountOwner>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   /** Initialize operation state account to store operation owner address and white list mint. */
   operationState: Address<TAccountOperationState>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -227,12 +249,14 @@ export type UpdateOperationAccountInput<
 
 export function getUpdateOperationAccountInstruction<
   TAccountOwner extends string,
+  TAccountGlobalConfig extends string,
   TAccountOperationState extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
   input: UpdateOperationAccountInput<
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >,
@@ -240,6 +264,7 @@ export function getUpdateOperationAccountInstruction<
 ): UpdateOperationAccountInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountGlobalConfig,
   TAccountOperationState,
   TAccountSystemProgram
 > {
@@ -249,6 +274,7 @@ export function getUpdateOperationAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     operationState: { value: input.operationState ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -261,10 +287,6 @@ export function getUpdateOperationAccountInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.owner.value) {
-    accounts.owner.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -274,6 +296,7 @@ export function getUpdateOperationAccountInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.operationState),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -284,6 +307,7 @@ export function getUpdateOperationAccountInstruction<
   } as UpdateOperationAccountInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountGlobalConfig,
     TAccountOperationState,
     TAccountSystemProgram
   >);
@@ -297,9 +321,11 @@ export type ParsedUpdateOperationAccountInstruction<
   accounts: {
     /** Address to be set as operation account owner. */
     owner: TAccountMetas[0];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
     /** Initialize operation state account to store operation owner address and white list mint. */
-    operationState: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    operationState: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: UpdateOperationAccountInstructionData;
 };
@@ -312,7 +338,7 @@ export function parseUpdateOperationAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateOperationAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -326,6 +352,7 @@ export function parseUpdateOperationAccountInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      globalConfig: getNextAccount(),
       operationState: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -12,6 +12,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -26,6 +27,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -46,9 +48,8 @@ export function getUpdatePoolStatusDiscriminatorBytes() {
 
 export type UpdatePoolStatusInstruction<
   TProgram extends string = typeof AMM_V3_PROGRAM_ADDRESS,
-  TAccountAuthority extends
-    | string
-    | AccountMeta<string> = '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2',
+  TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountGlobalConfig extends string | AccountMeta<string> = string,
   TAccountPoolState extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
@@ -59,6 +60,9 @@ export type UpdatePoolStatusInstruction<
         ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountGlobalConfig extends string
+        ? ReadonlyAccount<TAccountGlobalConfig>
+        : TAccountGlobalConfig,
       TAccountPoolState extends string
         ? WritableAccount<TAccountPoolState>
         : TAccountPoolState,
@@ -100,25 +104,116 @@ export function getUpdatePoolStatusInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type UpdatePoolStatusAsyncInput<
+  TAccountAuthority extends string = string,
+  TAccountGlobalConfig extends string = string,
+  TAccountPoolState extends string = string,
+> = {
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The global config holding the admin */
+  globalConfig?: Address<TAccountGlobalConfig>;
+  poolState: Address<TAccountPoolState>;
+  status: UpdatePoolStatusInstructionDataArgs['status'];
+};
+
+export async function getUpdatePoolStatusInstructionAsync<
+  TAccountAuthority extends string,
+  TAccountGlobalConfig extends string,
+  TAccountPoolState extends string,
+  TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
+>(
+  input: UpdatePoolStatusAsyncInput<
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  UpdatePoolStatusInstruction<
+    TProgramAddress,
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? AMM_V3_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    authority: { value: input.authority ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
+    poolState: { value: input.poolState ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.globalConfig.value) {
+    accounts.globalConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            103, 108, 111, 98, 97, 108, 95, 99, 111, 110, 102, 105, 103,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.globalConfig),
+      getAccountMeta(accounts.poolState),
+    ],
+    data: getUpdatePoolStatusInstructionDataEncoder().encode(
+      args as UpdatePoolStatusInstructionDataArgs
+    ),
+    programAddress,
+  } as UpdatePoolStatusInstruction<
+    TProgramAddress,
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >);
+}
+
 export type UpdatePoolStatusInput<
   TAccountAuthority extends string = string,
+  TAccountGlobalConfig extends string = string,
   TAccountPoolState extends string = string,
 > = {
-  authority?: TransactionSigner<TAccountAuthority>;
+  authority: TransactionSigner<TAccountAuthority>;
+  /** The global config holding the admin */
+  globalConfig: Address<TAccountGlobalConfig>;
   poolState: Address<TAccountPoolState>;
   status: UpdatePoolStatusInstructionDataArgs['status'];
 };
 
 export function getUpdatePoolStatusInstruction<
   TAccountAuthority extends string,
+  TAccountGlobalConfig extends string,
   TAccountPoolState extends string,
   TProgramAddress extends Address = typeof AMM_V3_PROGRAM_ADDRESS,
 >(
-  input: UpdatePoolStatusInput<TAccountAuthority, TAccountPoolState>,
+  input: UpdatePoolStatusInput<
+    TAccountAuthority,
+    TAccountGlobalConfig,
+    TAccountPoolState
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdatePoolStatusInstruction<
   TProgramAddress,
   TAccountAuthority,
+  TAccountGlobalConfig,
   TAccountPoolState
 > {
   // Program address.
@@ -127,6 +222,7 @@ export function getUpdatePoolStatusInstruction<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: false },
+    globalConfig: { value: input.globalConfig ?? null, isWritable: false },
     poolState: { value: input.poolState ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
@@ -137,16 +233,11 @@ export function getUpdatePoolStatusInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.authority.value) {
-    accounts.authority.value =
-      '3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2' as Address<'3kXrf8w8Z6EjLJU4S8dAkpRL2von8z7Eh3kJnFrmo7Z2'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.globalConfig),
       getAccountMeta(accounts.poolState),
     ],
     data: getUpdatePoolStatusInstructionDataEncoder().encode(
@@ -156,6 +247,7 @@ export function getUpdatePoolStatusInstruction<
   } as UpdatePoolStatusInstruction<
     TProgramAddress,
     TAccountAuthority,
+    TAccountGlobalConfig,
     TAccountPoolState
   >);
 }
@@ -167,7 +259,9 @@ export type ParsedUpdatePoolStatusInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     authority: TAccountMetas[0];
-    poolState: TAccountMetas[1];
+    /** The global config holding the admin */
+    globalConfig: TAccountMetas[1];
+    poolState: TAccountMetas[2];
   };
   data: UpdatePoolStatusInstructionData;
 };
@@ -180,7 +274,7 @@ export function parseUpdatePoolStatusInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdatePoolStatusInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -192,7 +286,11 @@ export function parseUpdatePoolStatusInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { authority: getNextAccount(), poolState: getNextAccount() },
+    accounts: {
+      authority: getNextAccount(),
+      globalConfig: getNextAccount(),
+      poolState: getNextAccount(),
+    },
     data: getUpdatePoolStatusInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -14,27 +14,67 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAdminInstruction,
+  type ParsedAcceptAmmConfigOwnerInstruction,
+  type ParsedCancelAmmConfigUpdateInstruction,
+  type ParsedCancelPoolConfigUpdateInstruction,
+  type ParsedClaimRangeOrderInstruction,
   type ParsedClosePositionInstruction,
   type ParsedCloseProtocolPositionInstruction,
+  type ParsedCollectFeesAndRewardsInstruction,
   type ParsedCollectFundFeeInstruction,
   type ParsedCollectProtocolFeeInstruction,
   type ParsedCollectRemainingRewardsInstruction,
+  type ParsedCompoundPositionInstruction,
   type ParsedCreateAmmConfigInstruction,
+  type ParsedCreateGlobalConfigInstruction,
   type ParsedCreateOperationAccountInstruction,
   type ParsedCreatePoolInstruction,
+  type ParsedCreatePoolWithLaunchFeeInstruction,
   type ParsedCreateSupportMintAssociatedInstruction,
   type ParsedDecreaseLiquidityV2Instruction,
+  type ParsedDecreaseLiquidityV2WithOptionsInstruction,
+  type ParsedExecuteAmmConfigUpdateInstruction,
+  type ParsedFlashLoanBorrowInstruction,
+  type ParsedFlashLoanRepayInstruction,
+  type ParsedFlashSwapInstruction,
   type ParsedIncreaseLiquidityV2Instruction,
+  type ParsedIncreaseLiquidityV2WithOptionsInstruction,
+  type ParsedIncreaseObservationCardinalityInstruction,
   type ParsedInitializeRewardInstruction,
+  type ParsedLockPositionInstruction,
+  type ParsedMigratePoolConfigInstruction,
+  type ParsedMigrateSupportMintAssociatedInstruction,
+  type ParsedObserveInstruction,
   type ParsedOpenPositionWithToken22NftInstruction,
+  type ParsedOpenPositionWithToken22NftWithOptionsInstruction,
+  type ParsedPauseAmmConfigInstruction,
+  type ParsedPausePoolInstruction,
+  type ParsedPlaceRangeOrderInstruction,
+  type ParsedProposeAdminInstruction,
+  type ParsedProposeAmmConfigOwnerInstruction,
+  type ParsedScheduleAmmConfigUpdateInstruction,
+  type ParsedSchedulePoolConfigMigrationInstruction,
+  type ParsedSchedulePoolFeeOverridesInstruction,
+  type ParsedSetConfigUpdateDelayInstruction,
+  type ParsedSetPauseGuardianInstruction,
+  type ParsedSetPoolFeeOverridesInstruction,
   type ParsedSetRewardParamsInstruction,
+  type ParsedSetTwapGuardInstruction,
+  type ParsedSnapshotCumulativesInsideInstruction,
   type ParsedSwapRouterBaseInInstruction,
+  type ParsedSwapRouterBaseInWithOptionsInstruction,
+  type ParsedSwapRouterBaseOutInstruction,
+  type ParsedSwapRouterBaseOutWithOptionsInstruction,
   type ParsedSwapV2Instruction,
+  type ParsedSwapV2WithOptionsInstruction,
   type ParsedTransferRewardOwnerInstruction,
   type ParsedUpdateAmmConfigInstruction,
+  type ParsedUpdateAmmConfigStatusInstruction,
   type ParsedUpdateOperationAccountInstruction,
   type ParsedUpdatePoolStatusInstruction,
   type ParsedUpdateRewardInfosInstruction,
+  type ParsedUpdateSupportMintAssociatedInstruction,
 } from '../instructions';
 
 export const AMM_V3_PROGRAM_ADDRESS =
@@ -42,9 +82,13 @@ export const AMM_V3_PROGRAM_ADDRESS =
 
 export enum AmmV3Account {
   AmmConfig,
+  AmmConfigPendingOwner,
+  AmmConfigPendingUpdate,
+  GlobalConfig,
   ObservationState,
   OperationState,
   PersonalPositionState,
+  PoolConfigPendingUpdate,
   PoolState,
   ProtocolPositionState,
   SupportMintAssociated,
@@ -67,6 +111,39 @@ export function identifyAmmV3Account(
   ) {
     return AmmV3Account.AmmConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([209, 40, 220, 233, 152, 79, 21, 214])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Account.AmmConfigPendingOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 122, 96, 1, 15, 175, 156, 71])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Account.AmmConfigPendingUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([149, 8, 156, 202, 160, 252, 176, 217])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Account.GlobalConfig;
+  }
   if (
     containsBytes(
       data,
@@ -100,6 +177,17 @@ export function identifyAmmV3Account(
   ) {
     return AmmV3Account.PersonalPositionState;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([109, 61, 177, 45, 102, 77, 216, 85])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Account.PoolConfigPendingUpdate;
+  }
   if (
     containsBytes(
       data,
@@ -161,33 +249,128 @@ export function identifyAmmV3Account(
 }
 
 export enum AmmV3Instruction {
+  AcceptAdmin,
+  AcceptAmmConfigOwner,
+  CancelAmmConfigUpdate,
+  CancelPoolConfigUpdate,
+  ClaimRangeOrder,
   ClosePosition,
   CloseProtocolPosition,
+  CollectFeesAndRewards,
   CollectFundFee,
   CollectProtocolFee,
   CollectRemainingRewards,
+  CompoundPosition,
   CreateAmmConfig,
+  CreateGlobalConfig,
   CreateOperationAccount,
   CreatePool,
+  CreatePoolWithLaunchFee,
   CreateSupportMintAssociated,
   DecreaseLiquidityV2,
+  DecreaseLiquidityV2WithOptions,
+  ExecuteAmmConfigUpdate,
+  FlashLoanBorrow,
+  FlashLoanRepay,
+  FlashSwap,
   IncreaseLiquidityV2,
+  IncreaseLiquidityV2WithOptions,
+  IncreaseObservationCardinality,
   InitializeReward,
+  LockPosition,
+  MigratePoolConfig,
+  MigrateSupportMintAssociated,
+  Observe,
   OpenPositionWithToken22Nft,
+  OpenPositionWithToken22NftWithOptions,
+  PauseAmmConfig,
+  PausePool,
+  PlaceRangeOrder,
+  ProposeAdmin,
+  ProposeAmmConfigOwner,
+  ScheduleAmmConfigUpdate,
+  SchedulePoolConfigMigration,
+  SchedulePoolFeeOverrides,
+  SetConfigUpdateDelay,
+  SetPauseGuardian,
+  SetPoolFeeOverrides,
   SetRewardParams,
+  SetTwapGuard,
+  SnapshotCumulativesInside,
   SwapRouterBaseIn,
+  SwapRouterBaseInWithOptions,
+  SwapRouterBaseOut,
+  SwapRouterBaseOutWithOptions,
   SwapV2,
+  SwapV2WithOptions,
   TransferRewardOwner,
   UpdateAmmConfig,
+  UpdateAmmConfigStatus,
   UpdateOperationAccount,
   UpdatePoolStatus,
   UpdateRewardInfos,
+  UpdateSupportMintAssociated,
 }
 
 export function identifyAmmV3Instruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AmmV3Instruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 42, 45, 90, 116, 181, 13, 170])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.AcceptAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 73, 234, 127, 139, 101, 51, 11])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.AcceptAmmConfigOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([255, 66, 66, 116, 20, 169, 219, 81])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CancelAmmConfigUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([202, 36, 136, 214, 125, 204, 126, 240])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CancelPoolConfigUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([186, 138, 216, 154, 158, 167, 95, 117])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.ClaimRangeOrder;
+  }
   if (
     containsBytes(
       data,
@@ -210,6 +393,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.CloseProtocolPosition;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 18, 75, 8, 182, 31, 105, 186])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CollectFeesAndRewards;
+  }
   if (
     containsBytes(
       data,
@@ -243,6 +437,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.CollectRemainingRewards;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 52, 92, 180, 77, 42, 25, 150])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CompoundPosition;
+  }
   if (
     containsBytes(
       data,
@@ -254,6 +459,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.CreateAmmConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([47, 208, 62, 51, 32, 34, 119, 132])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CreateGlobalConfig;
+  }
   if (
     containsBytes(
       data,
@@ -276,6 +492,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.CreatePool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 44, 168, 68, 69, 213, 173, 27])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.CreatePoolWithLaunchFee;
+  }
   if (
     containsBytes(
       data,
@@ -298,6 +525,61 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.DecreaseLiquidityV2;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([77, 211, 56, 177, 111, 223, 175, 132])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.DecreaseLiquidityV2WithOptions;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([95, 181, 55, 245, 224, 139, 8, 93])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.ExecuteAmmConfigUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([144, 198, 75, 24, 3, 65, 54, 175])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.FlashLoanBorrow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([235, 183, 194, 198, 162, 181, 5, 152])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.FlashLoanRepay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([44, 12, 29, 97, 110, 2, 61, 146])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.FlashSwap;
+  }
   if (
     containsBytes(
       data,
@@ -309,6 +591,28 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.IncreaseLiquidityV2;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 181, 168, 150, 10, 124, 206, 77])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.IncreaseLiquidityV2WithOptions;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 239, 231, 170, 117, 163, 238, 178])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.IncreaseObservationCardinality;
+  }
   if (
     containsBytes(
       data,
@@ -320,6 +624,50 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.InitializeReward;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 62, 2, 252, 247, 10, 171, 185])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.LockPosition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([240, 220, 251, 238, 21, 44, 129, 39])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.MigratePoolConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 173, 177, 50, 57, 72, 167, 45])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.MigrateSupportMintAssociated;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([204, 78, 178, 115, 194, 147, 65, 74])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.Observe;
+  }
   if (
     containsBytes(
       data,
@@ -331,6 +679,138 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.OpenPositionWithToken22Nft;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 192, 221, 66, 37, 229, 40, 34])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.OpenPositionWithToken22NftWithOptions;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 254, 158, 24, 47, 171, 215, 138])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.PauseAmmConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([160, 15, 12, 189, 160, 0, 243, 245])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.PausePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([145, 49, 47, 178, 130, 122, 105, 155])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.PlaceRangeOrder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 214, 199, 212, 87, 39, 117, 234])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.ProposeAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 142, 54, 198, 156, 22, 60, 239])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.ProposeAmmConfigOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([194, 135, 182, 37, 48, 155, 58, 211])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.ScheduleAmmConfigUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 122, 51, 120, 201, 111, 126, 211])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SchedulePoolConfigMigration;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([47, 142, 194, 151, 33, 2, 165, 187])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SchedulePoolFeeOverrides;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([140, 63, 1, 50, 55, 90, 27, 82])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SetConfigUpdateDelay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([192, 82, 193, 216, 26, 22, 10, 117])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SetPauseGuardian;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 32, 190, 1, 65, 33, 105, 221])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SetPoolFeeOverrides;
+  }
   if (
     containsBytes(
       data,
@@ -342,6 +822,28 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.SetRewardParams;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 38, 155, 12, 24, 206, 2, 119])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SetTwapGuard;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([102, 139, 238, 2, 117, 38, 175, 34])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SnapshotCumulativesInside;
+  }
   if (
     containsBytes(
       data,
@@ -353,6 +855,39 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.SwapRouterBaseIn;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([3, 134, 125, 202, 43, 169, 173, 77])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SwapRouterBaseInWithOptions;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([6, 20, 209, 125, 29, 141, 5, 1])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SwapRouterBaseOut;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([116, 237, 149, 150, 58, 26, 245, 58])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SwapRouterBaseOutWithOptions;
+  }
   if (
     containsBytes(
       data,
@@ -364,6 +899,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.SwapV2;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 199, 66, 236, 129, 99, 128, 187])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.SwapV2WithOptions;
+  }
   if (
     containsBytes(
       data,
@@ -386,6 +932,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.UpdateAmmConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 162, 225, 56, 149, 202, 10, 78])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.UpdateAmmConfigStatus;
+  }
   if (
     containsBytes(
       data,
@@ -419,6 +976,17 @@ export function identifyAmmV3Instruction(
   ) {
     return AmmV3Instruction.UpdateRewardInfos;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([44, 113, 122, 105, 41, 73, 165, 27])
+      ),
+      0
+    )
+  ) {
+    return AmmV3Instruction.UpdateSupportMintAssociated;
+  }
   throw new Error(
     'The provided instruction could not be identified as a ammV3 instruction.'
   );
@@ -427,12 +995,30 @@ export function identifyAmmV3Instruction(
 export type ParsedAmmV3Instruction<
   TProgram extends string = '6dMXqGZ3ga2dikrYS9ovDXgHGh5RUsb2RTUj6hrQXhk6',
 > =
+  | ({
+      instructionType: AmmV3Instruction.AcceptAdmin;
+    } & ParsedAcceptAdminInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.AcceptAmmConfigOwner;
+    } & ParsedAcceptAmmConfigOwnerInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CancelAmmConfigUpdate;
+    } & ParsedCancelAmmConfigUpdateInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CancelPoolConfigUpdate;
+    } & ParsedCancelPoolConfigUpdateInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.ClaimRangeOrder;
+    } & ParsedClaimRangeOrderInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.ClosePosition;
     } & ParsedClosePositionInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CloseProtocolPosition;
     } & ParsedCloseProtocolPositionInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CollectFeesAndRewards;
+    } & ParsedCollectFeesAndRewardsInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CollectFundFee;
     } & ParsedCollectFundFeeInstruction<TProgram>)
@@ -442,45 +1028,144 @@ export type ParsedAmmV3Instruction<
   | ({
       instructionType: AmmV3Instruction.CollectRemainingRewards;
     } & ParsedCollectRemainingRewardsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CompoundPosition;
+    } & ParsedCompoundPositionInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CreateAmmConfig;
     } & ParsedCreateAmmConfigInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CreateGlobalConfig;
+    } & ParsedCreateGlobalConfigInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CreateOperationAccount;
     } & ParsedCreateOperationAccountInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CreatePool;
     } & ParsedCreatePoolInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.CreatePoolWithLaunchFee;
+    } & ParsedCreatePoolWithLaunchFeeInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.CreateSupportMintAssociated;
     } & ParsedCreateSupportMintAssociatedInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.DecreaseLiquidityV2;
     } & ParsedDecreaseLiquidityV2Instruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.DecreaseLiquidityV2WithOptions;
+    } & ParsedDecreaseLiquidityV2WithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.ExecuteAmmConfigUpdate;
+    } & ParsedExecuteAmmConfigUpdateInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.FlashLoanBorrow;
+    } & ParsedFlashLoanBorrowInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.FlashLoanRepay;
+    } & ParsedFlashLoanRepayInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.FlashSwap;
+    } & ParsedFlashSwapInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.IncreaseLiquidityV2;
     } & ParsedIncreaseLiquidityV2Instruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.IncreaseLiquidityV2WithOptions;
+    } & ParsedIncreaseLiquidityV2WithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.IncreaseObservationCardinality;
+    } & ParsedIncreaseObservationCardinalityInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.InitializeReward;
     } & ParsedInitializeRewardInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.LockPosition;
+    } & ParsedLockPositionInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.MigratePoolConfig;
+    } & ParsedMigratePoolConfigInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.MigrateSupportMintAssociated;
+    } & ParsedMigrateSupportMintAssociatedInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.Observe;
+    } & ParsedObserveInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.OpenPositionWithToken22Nft;
     } & ParsedOpenPositionWithToken22NftInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.OpenPositionWithToken22NftWithOptions;
+    } & ParsedOpenPositionWithToken22NftWithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.PauseAmmConfig;
+    } & ParsedPauseAmmConfigInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.PausePool;
+    } & ParsedPausePoolInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.PlaceRangeOrder;
+    } & ParsedPlaceRangeOrderInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.ProposeAdmin;
+    } & ParsedProposeAdminInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.ProposeAmmConfigOwner;
+    } & ParsedProposeAmmConfigOwnerInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.ScheduleAmmConfigUpdate;
+    } & ParsedScheduleAmmConfigUpdateInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SchedulePoolConfigMigration;
+    } & ParsedSchedulePoolConfigMigrationInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SchedulePoolFeeOverrides;
+    } & ParsedSchedulePoolFeeOverridesInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SetConfigUpdateDelay;
+    } & ParsedSetConfigUpdateDelayInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SetPauseGuardian;
+    } & ParsedSetPauseGuardianInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SetPoolFeeOverrides;
+    } & ParsedSetPoolFeeOverridesInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.SetRewardParams;
     } & ParsedSetRewardParamsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SetTwapGuard;
+    } & ParsedSetTwapGuardInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SnapshotCumulativesInside;
+    } & ParsedSnapshotCumulativesInsideInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.SwapRouterBaseIn;
     } & ParsedSwapRouterBaseInInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SwapRouterBaseInWithOptions;
+    } & ParsedSwapRouterBaseInWithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SwapRouterBaseOut;
+    } & ParsedSwapRouterBaseOutInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SwapRouterBaseOutWithOptions;
+    } & ParsedSwapRouterBaseOutWithOptionsInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.SwapV2;
     } & ParsedSwapV2Instruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.SwapV2WithOptions;
+    } & ParsedSwapV2WithOptionsInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.TransferRewardOwner;
     } & ParsedTransferRewardOwnerInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.UpdateAmmConfig;
     } & ParsedUpdateAmmConfigInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.UpdateAmmConfigStatus;
+    } & ParsedUpdateAmmConfigStatusInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.UpdateOperationAccount;
     } & ParsedUpdateOperationAccountInstruction<TProgram>)
@@ -489,4 +1174,7 @@ export type ParsedAmmV3Instruction<
     } & ParsedUpdatePoolStatusInstruction<TProgram>)
   | ({
       instructionType: AmmV3Instruction.UpdateRewardInfos;
-    } & ParsedUpdateRewardInfosInstruction<TProgram>);
+    } & ParsedUpdateRewardInfosInstruction<TProgram>)
+  | ({
+      instructionType: AmmV3Instruction.UpdateSupportMintAssociated;
+    } & ParsedUpdateSupportMintAssociatedInstruction<TProgram>);
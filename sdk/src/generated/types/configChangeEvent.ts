@@ -10,12 +10,18 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -31,9 +37,47 @@ export type ConfigChangeEvent = {
   tickSpacing: number;
   fundFeeRate: number;
   fundOwner: Address;
+  dynamicFeeMinRate: number;
+  dynamicFeeMaxRate: number;
+  flashLoanFeeRate: number;
+  status: number;
+  maxReferralFeeRate: number;
+  maxLaunchFeeStartRate: number;
+  maxLaunchFeeDuration: number;
+  /** The param of the scheduled update, only set when the update is scheduled */
+  scheduledParam: number;
+  /** The value of the scheduled update, only set when the update is scheduled */
+  scheduledValue: number;
+  /** The timestamp the scheduled update can be executed at, zero when the change is applied */
+  executeAfter: bigint;
+  /** Whether the scheduled update was canceled, the config is unchanged then */
+  canceled: boolean;
 };
 
-export type ConfigChangeEventArgs = ConfigChangeEvent;
+export type ConfigChangeEventArgs = {
+  index: number;
+  owner: Address;
+  protocolFeeRate: number;
+  tradeFeeRate: number;
+  tickSpacing: number;
+  fundFeeRate: number;
+  fundOwner: Address;
+  dynamicFeeMinRate: number;
+  dynamicFeeMaxRate: number;
+  flashLoanFeeRate: number;
+  status: number;
+  maxReferralFeeRate: number;
+  maxLaunchFeeStartRate: number;
+  maxLaunchFeeDuration: number;
+  /** The param of the scheduled update, only set when the update is scheduled */
+  scheduledParam: number;
+  /** The value of the scheduled update, only set when the update is scheduled */
+  scheduledValue: number;
+  /** The timestamp the scheduled update can be executed at, zero when the change is applied */
+  executeAfter: number | bigint;
+  /** Whether the scheduled update was canceled, the config is unchanged then */
+  canceled: boolean;
+};
 
 export function getConfigChangeEventEncoder(): FixedSizeEncoder<ConfigChangeEventArgs> {
   return getStructEncoder([
@@ -44,6 +88,17 @@ export function getConfigChangeEventEncoder(): FixedSizeEncoder<ConfigChangeEven
     ['tickSpacing', getU16Encoder()],
     ['fundFeeRate', getU32Encoder()],
     ['fundOwner', getAddressEncoder()],
+    ['dynamicFeeMinRate', getU32Encoder()],
+    ['dynamicFeeMaxRate', getU32Encoder()],
+    ['flashLoanFeeRate', getU32Encoder()],
+    ['status', getU8Encoder()],
+    ['maxReferralFeeRate', getU32Encoder()],
+    ['maxLaunchFeeStartRate', getU32Encoder()],
+    ['maxLaunchFeeDuration', getU32Encoder()],
+    ['scheduledParam', getU8Encoder()],
+    ['scheduledValue', getU32Encoder()],
+    ['executeAfter', getU64Encoder()],
+    ['canceled', getBooleanEncoder()],
   ]);
 }
 
@@ -56,6 +111,17 @@ export function getConfigChangeEventDecoder(): FixedSizeDecoder<ConfigChangeEven
     ['tickSpacing', getU16Decoder()],
     ['fundFeeRate', getU32Decoder()],
     ['fundOwner', getAddressDecoder()],
+    ['dynamicFeeMinRate', getU32Decoder()],
+    ['dynamicFeeMaxRate', getU32Decoder()],
+    ['flashLoanFeeRate', getU32Decoder()],
+    ['status', getU8Decoder()],
+    ['maxReferralFeeRate', getU32Decoder()],
+    ['maxLaunchFeeStartRate', getU32Decoder()],
+    ['maxLaunchFeeDuration', getU32Decoder()],
+    ['scheduledParam', getU8Decoder()],
+    ['scheduledValue', getU32Decoder()],
+    ['executeAfter', getU64Decoder()],
+    ['canceled', getBooleanDecoder()],
   ]);
 }
 
@@ -6,19 +6,32 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adminChangeEvent';
+export * from './claimRangeOrderEvent';
 export * from './closePositionEvent';
 export * from './collectPersonalFeeEvent';
 export * from './collectProtocolFeeEvent';
+export * from './compoundPositionEvent';
 export * from './configChangeEvent';
+export * from './configUpdateDelayChangeEvent';
 export * from './createPersonalPositionEvent';
 export * from './decreaseLiquidityEvent';
+export * from './flashLoanEvent';
 export * from './increaseLiquidityEvent';
+export * from './increaseObservationCardinalityEvent';
 export * from './liquidityCalculateEvent';
 export * from './liquidityChangeEvent';
+export * from './lockPositionEvent';
+export * from './migratePoolConfigEvent';
 export * from './observation';
+export * from './placeRangeOrderEvent';
+export * from './poolConfigChangeEvent';
+export * from './poolConfigPendingUpdateEvent';
 export * from './poolCreatedEvent';
+export * from './poolStatusChangeEvent';
 export * from './positionRewardInfo';
 export * from './rewardInfo';
 export * from './swapEvent';
 export * from './tickState';
+export * from './twapGuardChangeEvent';
 export * from './updateRewardInfosEvent';
@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -29,6 +31,8 @@ export type Observation = {
   blockTimestamp: number;
   /** the cumulative of tick during the duration time */
   tickCumulative: bigint;
+  /** the seconds per unit of in range liquidity of the pool as of the block timestamp, as a Q64.64 */
+  secondsPerLiquidityCumulativeX64: bigint;
   /** padding for feature update */
   padding: Array<bigint>;
 };
@@ -38,6 +42,8 @@ export type ObservationArgs = {
   blockTimestamp: number;
   /** the cumulative of tick during the duration time */
   tickCumulative: number | bigint;
+  /** the seconds per unit of in range liquidity of the pool as of the block timestamp, as a Q64.64 */
+  secondsPerLiquidityCumulativeX64: number | bigint;
   /** padding for feature update */
   padding: Array<number | bigint>;
 };
@@ -46,7 +52,8 @@ export function getObservationEncoder(): FixedSizeEncoder<ObservationArgs> {
   return getStructEncoder([
     ['blockTimestamp', getU32Encoder()],
     ['tickCumulative', getI64Encoder()],
-    ['padding', getArrayEncoder(getU64Encoder(), { size: 4 })],
+    ['secondsPerLiquidityCumulativeX64', getU128Encoder()],
+    ['padding', getArrayEncoder(getU64Encoder(), { size: 2 })],
   ]);
 }
 
@@ -54,7 +61,8 @@ export function getObservationDecoder(): FixedSizeDecoder<Observation> {
   return getStructDecoder([
     ['blockTimestamp', getU32Decoder()],
     ['tickCumulative', getI64Decoder()],
-    ['padding', getArrayDecoder(getU64Decoder(), { size: 4 })],
+    ['secondsPerLiquidityCumulativeX64', getU128Decoder()],
+    ['padding', getArrayDecoder(getU64Decoder(), { size: 2 })],
   ]);
 }
 
@@ -58,6 +58,8 @@ export type SwapEvent = {
   liquidity: bigint;
   /** The log base 1.0001 of price of the pool after the swap */
   tick: number;
+  /** The part of the input token fee paid to the referrer */
+  referralFee: bigint;
 };
 
 export type SwapEventArgs = {
@@ -91,6 +93,8 @@ export type SwapEventArgs = {
   liquidity: number | bigint;
   /** The log base 1.0001 of price of the pool after the swap */
   tick: number;
+  /** The part of the input token fee paid to the referrer */
+  referralFee: number | bigint;
 };
 
 export function getSwapEventEncoder(): FixedSizeEncoder<SwapEventArgs> {
@@ -107,6 +111,7 @@ export function getSwapEventEncoder(): FixedSizeEncoder<SwapEventArgs> {
     ['sqrtPriceX64', getU128Encoder()],
     ['liquidity', getU128Encoder()],
     ['tick', getI32Encoder()],
+    ['referralFee', getU64Encoder()],
   ]);
 }
 
@@ -124,6 +129,7 @@ export function getSwapEventDecoder(): FixedSizeDecoder<SwapEvent> {
     ['sqrtPriceX64', getU128Decoder()],
     ['liquidity', getU128Decoder()],
     ['tick', getI32Decoder()],
+    ['referralFee', getU64Decoder()],
   ]);
 }
 
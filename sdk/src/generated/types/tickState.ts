@@ -38,6 +38,15 @@ export type TickState = {
   feeGrowthOutside0X64: bigint;
   feeGrowthOutside1X64: bigint;
   rewardGrowthsOutsideX64: Array<bigint>;
+  /**
+   * Seconds per unit of liquidity on the _other_ side of this tick (relative to the current tick) as a Q64.64,
+   * only has relative meaning like the fee growth outside
+   */
+  secondsPerLiquidityOutsideX64: bigint;
+  /** The liquidity of the range orders that are filled when the price crosses this tick */
+  rangeOrderLiquidity: bigint;
+  /** The number of times this tick has been crossed, the range orders waiting on it are filled by the next crossing */
+  rangeOrderFillCount: number;
   padding: Array<number>;
 };
 
@@ -54,6 +63,15 @@ export type TickStateArgs = {
   feeGrowthOutside0X64: number | bigint;
   feeGrowthOutside1X64: number | bigint;
   rewardGrowthsOutsideX64: Array<number | bigint>;
+  /**
+   * Seconds per unit of liquidity on the _other_ side of this tick (relative to the current tick) as a Q64.64,
+   * only has relative meaning like the fee growth outside
+   */
+  secondsPerLiquidityOutsideX64: number | bigint;
+  /** The liquidity of the range orders that are filled when the price crosses this tick */
+  rangeOrderLiquidity: number | bigint;
+  /** The number of times this tick has been crossed, the range orders waiting on it are filled by the next crossing */
+  rangeOrderFillCount: number;
   padding: Array<number>;
 };
 
@@ -65,7 +83,10 @@ export function getTickStateEncoder(): FixedSizeEncoder<TickStateArgs> {
     ['feeGrowthOutside0X64', getU128Encoder()],
     ['feeGrowthOutside1X64', getU128Encoder()],
     ['rewardGrowthsOutsideX64', getArrayEncoder(getU128Encoder(), { size: 3 })],
-    ['padding', getArrayEncoder(getU32Encoder(), { size: 13 })],
+    ['secondsPerLiquidityOutsideX64', getU128Encoder()],
+    ['rangeOrderLiquidity', getU128Encoder()],
+    ['rangeOrderFillCount', getU32Encoder()],
+    ['padding', getArrayEncoder(getU32Encoder(), { size: 4 })],
   ]);
 }
 
@@ -77,7 +98,10 @@ export function getTickStateDecoder(): FixedSizeDecoder<TickState> {
     ['feeGrowthOutside0X64', getU128Decoder()],
     ['feeGrowthOutside1X64', getU128Decoder()],
     ['rewardGrowthsOutsideX64', getArrayDecoder(getU128Decoder(), { size: 3 })],
-    ['padding', getArrayDecoder(getU32Decoder(), { size: 13 })],
+    ['secondsPerLiquidityOutsideX64', getU128Decoder()],
+    ['rangeOrderLiquidity', getU128Decoder()],
+    ['rangeOrderFillCount', getU32Decoder()],
+    ['padding', getArrayDecoder(getU32Decoder(), { size: 4 })],
   ]);
 }
 
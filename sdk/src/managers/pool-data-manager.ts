@@ -310,9 +310,15 @@ export class PoolDataManager {
             tradeFeeRate: apiConfig.tradeFeeRate,
             tickSpacing: apiConfig.tickSpacing,
             fundFeeRate: apiConfig.fundFeeRate,
-            paddingU32: 0,
+            flashLoanFeeRate: 0,
             fundOwner: address(apiConfig.fundOwner),
-            padding: [0n, 0n, 0n],
+            dynamicFeeMinRate: 0,
+            dynamicFeeMaxRate: 0,
+            status: 0,
+            padding1: new Uint8Array(3),
+            maxReferralFeeRate: 0,
+            maxLaunchFeeStartRate: 0,
+            maxLaunchFeeDuration: 0,
           };
         } else {
           // Fallback to RPC if API client not configured
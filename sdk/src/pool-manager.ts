@@ -9,7 +9,7 @@ import {
   SolanaRpcApiTestnet,
 } from "@solana/kit";
 import {
-  getCreateAmmConfigInstruction,
+  getCreateAmmConfigInstructionAsync,
   fetchMaybePoolState,
   getCreatePoolInstructionAsync,
   fetchAllPoolState,
@@ -173,7 +173,7 @@ export class PoolManager {
     // Derive AMM config PDA
     const ammConfigPda = await PdaUtils.getAmmConfigPda(index);
 
-    const instruction = getCreateAmmConfigInstruction({
+    const instruction = await getCreateAmmConfigInstructionAsync({
       owner,
       ammConfig: ammConfigPda[0],
       systemProgram: SYSTEM_PROGRAM_ID,